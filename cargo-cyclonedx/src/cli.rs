@@ -1,15 +1,23 @@
 use cargo_cyclonedx::{
     config::{
-        CdxExtension, CustomPrefix, Features, IncludedDependencies, LicenseParserOptions,
-        OutputOptions, ParseMode, Pattern, PlatformSuffix, Prefix, PrefixError, SbomConfig, Target,
+        CdxExtension, ComponentDetail, CrateTags, CustomPrefix, DefaultScope, DevDependencies,
+        ExistingFiles, Features, GitMetadata, IncludedDependencies, LicenseDetection,
+        LicenseParserOptions, MixedPackageType, OutputDestination, OutputOptions, ParseMode,
+        Pattern, PlatformSuffix, Prefix, PrefixError, ProcMacros, PurlQualifiers, Reproducible,
+        SbomConfig, SerialNumber, SeverityThreshold, Target, WorkspaceOutput,
     },
     format::Format,
     platform::host_platform,
 };
 use clap::{ArgAction, ArgGroup, Parser};
+use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::models::lifecycle::{Lifecycle, Lifecycles, Phase};
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::path;
+use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Parser, Debug)]
@@ -29,9 +37,20 @@ pub struct Args {
     #[clap(long = "manifest-path", value_name = "PATH")]
     pub manifest_path: Option<path::PathBuf>,
 
-    /// Output BOM format: json, xml
-    #[clap(long = "format", short = 'f', value_name = "FORMAT")]
-    pub format: Option<Format>,
+    /// Output BOM format: json, xml. Several can be given as a comma-separated list,
+    /// e.g. 'xml,json', to write a file in each of them
+    #[clap(
+        long = "format",
+        short = 'f',
+        value_name = "FORMAT",
+        value_delimiter = ','
+    )]
+    pub format: Vec<Format>,
+
+    /// Version of the CycloneDX specification to write the SBOM in: 1.3, 1.4, 1.5, 1.6.
+    /// Defaults to 1.3, or 1.5 if lifecycles are given
+    #[clap(long = "spec-version", value_name = "VERSION")]
+    pub spec_version: Option<SpecVersion>,
 
     /// Use verbose output (-vv very verbose/build.rs output)
     #[clap(long = "verbose", short = 'v', action = clap::ArgAction::Count)]
@@ -98,6 +117,14 @@ Defaults to the host target, as printed by 'rustc -vV'"
     )]
     pub output_prefix: Option<String>,
 
+    /// Write the SBOM to stdout instead of a file. Only one SBOM can be written this way,
+    /// so a workspace with several members needs --aggregate-workspace
+    #[clap(
+        long = "stdout",
+        conflicts_with_all = ["output_cdx", "output-pattern", "output-prefix", "target_in_filename", "no_clobber"]
+    )]
+    pub stdout: bool,
+
     /// Reject the deprecated '/' separator for licenses, treating 'MIT/Apache-2.0' as an error
     #[clap(long = "license-strict")]
     pub license_strict: bool,
@@ -105,6 +132,122 @@ Defaults to the host target, as printed by 'rustc -vV'"
     /// Add license names which will not be warned about when parsing them as a SPDX expression fails
     #[clap(long = "license-accept-named", action=ArgAction::Append)]
     pub license_accept_named: Vec<String>,
+
+    /// Override the name of the root component, e.g. when it differs from the product name
+    #[clap(long = "rename-root", value_name = "NAME")]
+    pub rename_root: Option<String>,
+
+    /// Scope of components that are not classified otherwise: required, optional
+    #[clap(long = "default-scope", value_name = "SCOPE")]
+    pub default_scope: Option<DefaultScope>,
+
+    /// Record the current git commit and dirty state as properties of the root component
+    #[clap(long = "git-metadata")]
+    pub git_metadata: bool,
+
+    /// Attach a tag to the component of a crate, e.g. 'serde=serialization'.
+    /// Tags are only written for CycloneDX 1.6 and newer
+    #[clap(long = "tag-crate", value_name = "CRATE=TAG", action=ArgAction::Append)]
+    pub tag_crate: Vec<String>,
+
+    /// Instead of generating an SBOM, read an existing BOM and print it in canonical form:
+    /// sorted, with content derived bom-refs and without empty collections
+    #[clap(long = "normalize", value_name = "BOM")]
+    pub normalize: Option<path::PathBuf>,
+
+    /// Instead of generating an SBOM, read an existing BOM in either format and print every problem
+    /// that makes it invalid, exiting with an error if there are any
+    #[clap(long = "validate", value_name = "BOM")]
+    pub validate: Option<path::PathBuf>,
+
+    /// Exit with an error if the SBOM lists a vulnerability of at least this severity:
+    /// critical, high, medium, low. Vulnerabilities analysed as not affecting a component are ignored
+    #[clap(long = "fail-on-vuln", value_name = "SEVERITY")]
+    pub fail_on_vuln: Option<SeverityThreshold>,
+
+    /// Add the vulnerabilities of a BOM or VEX document, e.g. exported from a vulnerability feed,
+    /// to the SBOM. Their targets refer to components by bom-ref or purl
+    #[clap(long = "vulnerabilities", value_name = "BOM")]
+    pub vulnerabilities: Option<path::PathBuf>,
+
+    /// Produce byte-identical output for identical sources: components are sorted and referenced by purl,
+    /// the timestamp is taken from SOURCE_DATE_EPOCH (and omitted if unset),
+    /// and the serial number is derived from the components instead of being random
+    #[clap(long = "reproducible")]
+    pub reproducible: bool,
+
+    /// How to choose the serial number of the SBOM: random, content.
+    /// 'content' derives it from the components, so that the same inputs yield the same serial number
+    #[clap(long = "serial-number", value_name = "SOURCE")]
+    pub serial_number: Option<SerialNumber>,
+
+    /// List the dev-dependencies declared by the package with the 'optional' scope,
+    /// but not the crates that are only pulled in by them
+    #[clap(long = "exclude-dev-transitive")]
+    pub exclude_dev_transitive: bool,
+
+    /// The Cargo profile the artifacts described by the SBOM are built with, e.g. 'release' (default) or 'dev'
+    #[clap(long = "profile", value_name = "PROFILE")]
+    pub profile: Option<String>,
+
+    /// Instead of writing the SBOM, send a HEAD request to each of its external reference URLs
+    /// and report the ones that do not respond with 200 OK. Requires network access
+    #[clap(long = "check-links")]
+    pub check_links: bool,
+
+    /// Only record the type, name, version and purl of each component, and the dependency graph,
+    /// for consumers that limit the size of an SBOM
+    #[clap(long = "minimal")]
+    pub minimal: bool,
+
+    /// Refuse to overwrite an SBOM file that already exists and exit with an error instead
+    #[clap(long = "no-clobber")]
+    pub no_clobber: bool,
+
+    /// Where to take the licenses of each component from: manifest (default), detected, both.
+    /// 'detected' identifies the license files in the package directory by their text,
+    /// 'both' lists the declared licenses of the manifest and the concluded detected ones
+    #[clap(long = "license-detection", value_name = "SOURCE")]
+    pub license_detection: Option<LicenseDetection>,
+
+    /// Component type of a workspace member with both library and binary targets:
+    /// application (default), library.
+    /// Members with only library targets are always libraries, those with only binaries applications
+    #[clap(long = "mixed-package-type", value_name = "TYPE")]
+    pub mixed_package_type: Option<MixedPackageType>,
+
+    /// URL of the build that produced the SBOM, e.g. a CI job,
+    /// recorded as a 'build-system' external reference of the SBOM
+    #[clap(long = "build-url", value_name = "URL")]
+    pub build_url: Option<String>,
+
+    /// URL of the metadata of the build that produced the SBOM, e.g. its logs,
+    /// recorded as a 'build-meta' external reference of the SBOM
+    #[clap(long = "build-meta-url", value_name = "URL")]
+    pub build_meta_url: Option<String>,
+
+    /// Generate a single SBOM for the whole workspace next to its root manifest,
+    /// with each member nested in the root component and the dependencies of all members listed once
+    #[clap(long = "aggregate-workspace")]
+    pub aggregate_workspace: bool,
+
+    /// Stage of the product lifecycle the SBOM describes, recorded in its metadata:
+    /// design, pre-build, build, post-build, operations, discovery, decommission,
+    /// or a custom one as 'NAME:DESCRIPTION'. Can be repeated.
+    /// Lifecycles require CycloneDX 1.5, which the SBOM is then written in by default
+    #[clap(long = "lifecycle", value_name = "PHASE", action=ArgAction::Append)]
+    pub lifecycle: Vec<String>,
+
+    /// Add a qualifier to the purl of every component, e.g. 'distro=internal'.
+    /// Can be repeated. The qualifiers derived from the package source cannot be set
+    #[clap(long = "purl-qualifiers", value_name = "KEY=VALUE", action=ArgAction::Append)]
+    pub purl_qualifiers: Vec<String>,
+
+    /// List proc-macro crates like any other dependency.
+    /// By default they are listed with the 'excluded' scope and a 'cdx:cargo:proc_macro' property,
+    /// as they only run at build time
+    #[clap(long = "include-proc-macros")]
+    pub include_proc_macros: bool,
 }
 
 impl Args {
@@ -196,26 +339,169 @@ impl Args {
             accept_named: HashSet::from_iter(self.license_accept_named.clone()),
         });
 
+        let build_url = self
+            .build_url
+            .as_ref()
+            .map(|url| parse_url("--build-url", url))
+            .transpose()?;
+        let build_meta_url = self
+            .build_meta_url
+            .as_ref()
+            .map(|url| parse_url("--build-meta-url", url))
+            .transpose()?;
+
+        let lifecycles = if self.lifecycle.is_empty() {
+            None
+        } else {
+            let lifecycles = self
+                .lifecycle
+                .iter()
+                .map(|lifecycle| parse_lifecycle(lifecycle))
+                .collect::<Result<_, _>>()?;
+            Some(Lifecycles(lifecycles))
+        };
+        if self.stdout && self.format.iter().any(|format| *format != self.format[0]) {
+            return Err(ArgsError::MultipleFormatsToStdout);
+        }
+
+        if let Some(version) = self.spec_version {
+            if lifecycles.is_some() && version < SpecVersion::V1_5 {
+                return Err(ArgsError::UnsupportedSpecVersion(format!(
+                    "lifecycles require version 1.5 or later, but {} was requested",
+                    version
+                )));
+            }
+        }
+
+        let purl_qualifiers = if self.purl_qualifiers.is_empty() {
+            None
+        } else {
+            let mut purl_qualifiers = PurlQualifiers::default();
+            for qualifier in &self.purl_qualifiers {
+                let qualifier =
+                    PurlQualifiers::from_str(qualifier).map_err(ArgsError::PurlQualifierError)?;
+                purl_qualifiers = purl_qualifiers
+                    .merge(qualifier)
+                    .map_err(ArgsError::PurlQualifierError)?;
+            }
+            Some(purl_qualifiers)
+        };
+
+        let crate_tags = if self.tag_crate.is_empty() {
+            None
+        } else {
+            let mut crate_tags = CrateTags::default();
+            for tag in &self.tag_crate {
+                let tag = CrateTags::from_str(tag).map_err(ArgsError::CrateTagError)?;
+                crate_tags = crate_tags.merge(tag);
+            }
+            Some(crate_tags)
+        };
+
         Ok(SbomConfig {
-            format: self.format,
+            formats: match self.format.is_empty() {
+                true => None,
+                false => Some(self.format.clone()),
+            },
+            spec_version: self.spec_version,
             included_dependencies,
             output_options,
             features,
             target,
             license_parser,
+            rename_root: self.rename_root.clone(),
+            default_scope: self.default_scope,
+            git_metadata: match self.git_metadata {
+                true => Some(GitMetadata::Included),
+                false => None,
+            },
+            crate_tags,
+            fail_on_vuln: self.fail_on_vuln,
+            reproducible: match self.reproducible {
+                true => Some(Reproducible::Enabled),
+                false => None,
+            },
+            serial_number: self.serial_number,
+            dev_dependencies: match self.exclude_dev_transitive {
+                true => Some(DevDependencies::DirectOnly),
+                false => None,
+            },
+            profile: self.profile.clone(),
+            component_detail: match self.minimal {
+                true => Some(ComponentDetail::Minimal),
+                false => None,
+            },
+            existing_files: match self.no_clobber {
+                true => Some(ExistingFiles::Keep),
+                false => None,
+            },
+            output_destination: match self.stdout {
+                true => Some(OutputDestination::Stdout),
+                false => None,
+            },
+            license_detection: self.license_detection,
+            mixed_package_type: self.mixed_package_type,
+            build_url,
+            build_meta_url,
+            workspace_output: match self.aggregate_workspace {
+                true => Some(WorkspaceOutput::Aggregate),
+                false => None,
+            },
+            lifecycles,
+            purl_qualifiers,
+            proc_macros: match self.include_proc_macros {
+                true => Some(ProcMacros::Included),
+                false => None,
+            },
         })
     }
 }
 
+fn parse_url(flag: &str, url: &str) -> Result<Uri, ArgsError> {
+    Uri::try_from(url.to_string()).map_err(|_| ArgsError::InvalidUrl(format!("{} {}", flag, url)))
+}
+
+/// Parses one of the pre-defined phases, or a custom lifecycle given as `name:description`
+fn parse_lifecycle(lifecycle: &str) -> Result<Lifecycle, ArgsError> {
+    match lifecycle.split_once(':') {
+        Some((name, _)) if name.trim().is_empty() => {
+            Err(ArgsError::InvalidLifecycle(lifecycle.to_string()))
+        }
+        Some((name, description)) => Ok(Lifecycle::Description {
+            name: NormalizedString::new(name),
+            description: match description.trim() {
+                "" => None,
+                description => Some(NormalizedString::new(description)),
+            },
+        }),
+        None => Phase::from_str(lifecycle)
+            .map(Lifecycle::Phase)
+            .map_err(|_| ArgsError::InvalidLifecycle(lifecycle.to_string())),
+    }
+}
+
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ArgsError {
     #[error("Invalid prefix from CLI")]
     CustomPrefixError(#[from] PrefixError),
+    #[error("Invalid crate tag from CLI: {0}")]
+    CrateTagError(String),
+    #[error("Invalid URL from CLI: {0}")]
+    InvalidUrl(String),
+    #[error("Invalid purl qualifier from CLI: {0}")]
+    PurlQualifierError(String),
+    #[error("Invalid lifecycle from CLI: {0}, expected a pre-defined phase or NAME:DESCRIPTION")]
+    InvalidLifecycle(String),
+    #[error("Unsupported spec version from CLI: {0}")]
+    UnsupportedSpecVersion(String),
+    #[error("Only one format can be written to stdout")]
+    MultipleFormatsToStdout,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     #[test]
     fn parse_features() {
@@ -245,6 +531,140 @@ mod tests {
         assert!(!contains_feature(&config, ""));
     }
 
+    #[test]
+    fn parse_crate_tags() {
+        let args = vec![
+            "cyclonedx",
+            "--tag-crate=serde=serialization",
+            "--tag-crate",
+            "serde=data",
+            "--tag-crate=log=logging",
+        ];
+        let config = parse_to_config(&args);
+        let tags = config.crate_tags();
+        assert_eq!(
+            tags.tags_for("serde"),
+            Some(&vec!["serialization".to_string(), "data".to_string()])
+        );
+        assert_eq!(tags.tags_for("log"), Some(&vec!["logging".to_string()]));
+        assert_eq!(tags.tags_for("rand"), None);
+
+        let result = Args::parse_from(["cyclonedx", "--tag-crate=serde"]).as_config();
+        assert!(matches!(result, Err(ArgsError::CrateTagError(_))));
+    }
+
+    #[test]
+    fn parse_build_urls() {
+        let args = vec!["cyclonedx", "--build-url=https://ci.example.com/jobs/42"];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.build_url.map(|url| url.to_string()),
+            Some("https://ci.example.com/jobs/42".to_string())
+        );
+        assert!(config.build_meta_url.is_none());
+
+        let result = Args::parse_from(["cyclonedx", "--build-meta-url=not a url"]).as_config();
+        assert!(matches!(result, Err(ArgsError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn parse_formats() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert_eq!(config.formats(), vec![Format::Xml]);
+
+        let config = parse_to_config(&["cyclonedx", "--format=json"]);
+        assert_eq!(config.formats(), vec![Format::Json]);
+
+        let config = parse_to_config(&["cyclonedx", "--format=json,xml", "-f", "json"]);
+        assert_eq!(config.formats(), vec![Format::Json, Format::Xml]);
+
+        let config = parse_to_config(&["cyclonedx", "--stdout", "--format=json,json"]);
+        assert_eq!(config.formats(), vec![Format::Json]);
+
+        let result = Args::parse_from(["cyclonedx", "--stdout", "--format=xml,json"]).as_config();
+        assert_eq!(result, Err(ArgsError::MultipleFormatsToStdout));
+    }
+
+    #[test]
+    fn parse_stdout() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert_eq!(config.output_destination(), OutputDestination::File);
+
+        let config = parse_to_config(&["cyclonedx", "--stdout"]);
+        assert_eq!(config.output_destination(), OutputDestination::Stdout);
+
+        let result = Args::try_parse_from(["cyclonedx", "--stdout", "--output-cdx"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_spec_version() {
+        let config = parse_to_config(&["cyclonedx"]);
+        assert_eq!(config.spec_version(), SpecVersion::V1_3);
+
+        let config = parse_to_config(&["cyclonedx", "--lifecycle=build"]);
+        assert_eq!(config.spec_version(), SpecVersion::V1_5);
+
+        let config = parse_to_config(&["cyclonedx", "--spec-version=1.6", "--lifecycle=build"]);
+        assert_eq!(config.spec_version(), SpecVersion::V1_6);
+
+        let result =
+            Args::parse_from(["cyclonedx", "--spec-version=1.4", "--lifecycle=build"]).as_config();
+        assert!(matches!(result, Err(ArgsError::UnsupportedSpecVersion(_))));
+    }
+
+    #[test]
+    fn parse_lifecycles() {
+        let args = vec![
+            "cyclonedx",
+            "--lifecycle=build",
+            "--lifecycle=fuzzing:Fuzz testing of the parsers",
+        ];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.lifecycles,
+            Some(Lifecycles(vec![
+                Lifecycle::Phase(Phase::Build),
+                Lifecycle::Description {
+                    name: NormalizedString::new("fuzzing"),
+                    description: Some(NormalizedString::new("Fuzz testing of the parsers")),
+                },
+            ]))
+        );
+
+        let result = Args::parse_from(["cyclonedx", "--lifecycle=testing"]).as_config();
+        assert_eq!(
+            result,
+            Err(ArgsError::InvalidLifecycle("testing".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_purl_qualifiers() {
+        let args = vec![
+            "cyclonedx",
+            "--purl-qualifiers=distro=internal",
+            "--purl-qualifiers=Channel=stable",
+        ];
+        let config = parse_to_config(&args);
+        assert_eq!(
+            config.purl_qualifiers,
+            Some(PurlQualifiers(BTreeMap::from([
+                ("channel".to_string(), "stable".to_string()),
+                ("distro".to_string(), "internal".to_string()),
+            ])))
+        );
+
+        for conflicting in [
+            ["--purl-qualifiers=distro=a", "--purl-qualifiers=distro=b"],
+            ["--purl-qualifiers=vcs_url=a", "--purl-qualifiers=distro=b"],
+        ] {
+            let result =
+                Args::parse_from(["cyclonedx", conflicting[0], conflicting[1]]).as_config();
+            assert!(matches!(result, Err(ArgsError::PurlQualifierError(_))));
+        }
+    }
+
     fn parse_to_config(args: &[&str]) -> SbomConfig {
         Args::parse_from(args.iter()).as_config().unwrap()
     }
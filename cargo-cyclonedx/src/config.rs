@@ -1,5 +1,5 @@
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::str::FromStr;
 use thiserror::Error;
 
@@ -21,15 +21,39 @@ use thiserror::Error;
  * SPDX-License-Identifier: Apache-2.0
  */
 use crate::format::Format;
+use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::models::bom::SpecVersion;
+use cyclonedx_bom::models::lifecycle::Lifecycles;
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct SbomConfig {
-    pub format: Option<Format>,
+    pub formats: Option<Vec<Format>>,
+    pub spec_version: Option<SpecVersion>,
     pub included_dependencies: Option<IncludedDependencies>,
     pub output_options: Option<OutputOptions>,
     pub features: Option<Features>,
     pub target: Option<Target>,
     pub license_parser: Option<LicenseParserOptions>,
+    pub rename_root: Option<String>,
+    pub default_scope: Option<DefaultScope>,
+    pub git_metadata: Option<GitMetadata>,
+    pub crate_tags: Option<CrateTags>,
+    pub fail_on_vuln: Option<SeverityThreshold>,
+    pub reproducible: Option<Reproducible>,
+    pub serial_number: Option<SerialNumber>,
+    pub dev_dependencies: Option<DevDependencies>,
+    pub profile: Option<String>,
+    pub component_detail: Option<ComponentDetail>,
+    pub existing_files: Option<ExistingFiles>,
+    pub output_destination: Option<OutputDestination>,
+    pub license_detection: Option<LicenseDetection>,
+    pub mixed_package_type: Option<MixedPackageType>,
+    pub build_url: Option<Uri>,
+    pub build_meta_url: Option<Uri>,
+    pub workspace_output: Option<WorkspaceOutput>,
+    pub lifecycles: Option<Lifecycles>,
+    pub purl_qualifiers: Option<PurlQualifiers>,
+    pub proc_macros: Option<ProcMacros>,
 }
 
 impl SbomConfig {
@@ -39,7 +63,8 @@ impl SbomConfig {
 
     pub fn merge(&self, other: &SbomConfig) -> SbomConfig {
         SbomConfig {
-            format: other.format.or(self.format),
+            formats: other.formats.clone().or_else(|| self.formats.clone()),
+            spec_version: other.spec_version.or(self.spec_version),
             included_dependencies: other.included_dependencies.or(self.included_dependencies),
             output_options: other
                 .output_options
@@ -52,11 +77,66 @@ impl SbomConfig {
                 .clone()
                 .map(|other| self.license_parser.clone().unwrap_or_default().merge(other))
                 .or_else(|| self.license_parser.clone()),
+            rename_root: other
+                .rename_root
+                .clone()
+                .or_else(|| self.rename_root.clone()),
+            default_scope: other.default_scope.or(self.default_scope),
+            git_metadata: other.git_metadata.or(self.git_metadata),
+            crate_tags: other
+                .crate_tags
+                .clone()
+                .map(|other| self.crate_tags.clone().unwrap_or_default().merge(other))
+                .or_else(|| self.crate_tags.clone()),
+            fail_on_vuln: other.fail_on_vuln.or(self.fail_on_vuln),
+            reproducible: other.reproducible.or(self.reproducible),
+            serial_number: other.serial_number.or(self.serial_number),
+            dev_dependencies: other.dev_dependencies.or(self.dev_dependencies),
+            profile: other.profile.clone().or_else(|| self.profile.clone()),
+            component_detail: other.component_detail.or(self.component_detail),
+            existing_files: other.existing_files.or(self.existing_files),
+            output_destination: other.output_destination.or(self.output_destination),
+            license_detection: other.license_detection.or(self.license_detection),
+            mixed_package_type: other.mixed_package_type.or(self.mixed_package_type),
+            build_url: other.build_url.clone().or_else(|| self.build_url.clone()),
+            build_meta_url: other
+                .build_meta_url
+                .clone()
+                .or_else(|| self.build_meta_url.clone()),
+            workspace_output: other.workspace_output.or(self.workspace_output),
+            lifecycles: other.lifecycles.clone().or_else(|| self.lifecycles.clone()),
+            purl_qualifiers: other
+                .purl_qualifiers
+                .clone()
+                .or_else(|| self.purl_qualifiers.clone()),
+            proc_macros: other.proc_macros.or(self.proc_macros),
         }
     }
 
-    pub fn format(&self) -> Format {
-        self.format.unwrap_or_default()
+    /// The formats to write the SBOM in, each at most once, by default only XML
+    pub fn formats(&self) -> Vec<Format> {
+        match &self.formats {
+            Some(formats) if !formats.is_empty() => {
+                let mut unique = Vec::new();
+                for format in formats {
+                    if !unique.contains(format) {
+                        unique.push(*format);
+                    }
+                }
+                unique
+            }
+            _ => vec![Format::default()],
+        }
+    }
+
+    /// The version of the specification to write the SBOM in, by default 1.3,
+    /// or 1.5 if there are lifecycles, which were added in that version
+    pub fn spec_version(&self) -> SpecVersion {
+        match (self.spec_version, &self.lifecycles) {
+            (Some(version), _) => version,
+            (None, Some(_)) => SpecVersion::V1_5,
+            (None, None) => SpecVersion::V1_3,
+        }
     }
 
     pub fn included_dependencies(&self) -> IncludedDependencies {
@@ -70,6 +150,155 @@ impl SbomConfig {
     pub fn license_parser(&self) -> LicenseParserOptions {
         self.license_parser.clone().unwrap_or_default()
     }
+
+    pub fn default_scope(&self) -> DefaultScope {
+        self.default_scope.unwrap_or_default()
+    }
+
+    pub fn git_metadata(&self) -> GitMetadata {
+        self.git_metadata.unwrap_or_default()
+    }
+
+    pub fn crate_tags(&self) -> CrateTags {
+        self.crate_tags.clone().unwrap_or_default()
+    }
+
+    pub fn reproducible(&self) -> Reproducible {
+        self.reproducible.unwrap_or_default()
+    }
+
+    pub fn serial_number(&self) -> SerialNumber {
+        self.serial_number.unwrap_or_default()
+    }
+
+    pub fn dev_dependencies(&self) -> DevDependencies {
+        self.dev_dependencies.unwrap_or_default()
+    }
+
+    /// The Cargo profile the described artifacts are built with, `release` unless specified
+    pub fn profile(&self) -> &str {
+        self.profile.as_deref().unwrap_or("release")
+    }
+
+    pub fn component_detail(&self) -> ComponentDetail {
+        self.component_detail.unwrap_or_default()
+    }
+
+    pub fn existing_files(&self) -> ExistingFiles {
+        self.existing_files.unwrap_or_default()
+    }
+
+    pub fn output_destination(&self) -> OutputDestination {
+        self.output_destination.unwrap_or_default()
+    }
+
+    pub fn license_detection(&self) -> LicenseDetection {
+        self.license_detection.unwrap_or_default()
+    }
+
+    pub fn mixed_package_type(&self) -> MixedPackageType {
+        self.mixed_package_type.unwrap_or_default()
+    }
+
+    pub fn workspace_output(&self) -> WorkspaceOutput {
+        self.workspace_output.unwrap_or_default()
+    }
+
+    pub fn purl_qualifiers(&self) -> PurlQualifiers {
+        self.purl_qualifiers.clone().unwrap_or_default()
+    }
+
+    pub fn proc_macros(&self) -> ProcMacros {
+        self.proc_macros.unwrap_or_default()
+    }
+}
+
+/// Free-form tags to attach to the components of specific crates, keyed by crate name
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CrateTags(pub BTreeMap<String, Vec<String>>);
+
+impl CrateTags {
+    pub fn merge(mut self, other: Self) -> Self {
+        for (name, tags) in other.0 {
+            let existing = self.0.entry(name).or_default();
+            for tag in tags {
+                if !existing.contains(&tag) {
+                    existing.push(tag);
+                }
+            }
+        }
+        self
+    }
+
+    pub fn tags_for(&self, crate_name: &str) -> Option<&Vec<String>> {
+        self.0.get(crate_name)
+    }
+}
+
+impl FromStr for CrateTags {
+    type Err = String;
+
+    /// Parses a single `<crate>=<tag>` pair
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, tag)) if !name.is_empty() && !tag.is_empty() => {
+                Ok(Self(BTreeMap::from([(
+                    name.to_string(),
+                    vec![tag.to_string()],
+                )])))
+            }
+            _ => Err(format!("Expected <crate>=<tag>, got `{}`", s)),
+        }
+    }
+}
+
+/// Qualifiers to add to the purl of every component, keyed by their name
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PurlQualifiers(pub BTreeMap<String, String>);
+
+impl PurlQualifiers {
+    /// Qualifiers that are derived from the source of a package and cannot be set
+    pub const GENERATED: [&'static str; 3] = ["download_url", "repository_url", "vcs_url"];
+
+    /// Combines the qualifiers, failing if both contain the same one
+    pub fn merge(mut self, other: Self) -> Result<Self, String> {
+        for (key, value) in other.0 {
+            if self.0.contains_key(&key) {
+                return Err(format!("Qualifier `{}` is given more than once", key));
+            }
+            self.0.insert(key, value);
+        }
+        Ok(self)
+    }
+}
+
+impl FromStr for PurlQualifiers {
+    type Err = String;
+
+    /// Parses a single `<key>=<value>` pair
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = match s.split_once('=') {
+            Some((key, value)) if !key.is_empty() && !value.is_empty() => {
+                (key.to_ascii_lowercase(), value)
+            }
+            _ => return Err(format!("Expected <key>=<value>, got `{}`", s)),
+        };
+        // Rules of the purl specification for qualifier keys
+        if key.starts_with(|c: char| c.is_ascii_digit())
+            || !key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        {
+            return Err(format!("Invalid qualifier key `{}`", key));
+        }
+        if Self::GENERATED.contains(&key.as_str()) {
+            return Err(format!(
+                "Qualifier `{}` conflicts with the one derived from the package source",
+                key
+            ));
+        }
+        Ok(Self(BTreeMap::from([(key, value.to_string())])))
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -91,6 +320,193 @@ impl FromStr for IncludedDependencies {
     }
 }
 
+/// Scope assigned to components that are not classified otherwise
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultScope {
+    #[default]
+    Required,
+    Optional,
+}
+
+impl FromStr for DefaultScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "required" => Ok(Self::Required),
+            "optional" => Ok(Self::Optional),
+            _ => Err(format!("Expected required or optional, got `{}`", s)),
+        }
+    }
+}
+
+/// Whether to record the git revision of the sources in the SBOM
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GitMetadata {
+    Included,
+    #[default]
+    NotIncluded,
+}
+
+/// Minimum severity of a vulnerability in the SBOM that makes the run fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeverityThreshold {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+impl FromStr for SeverityThreshold {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "critical" => Ok(Self::Critical),
+            "high" => Ok(Self::High),
+            "medium" => Ok(Self::Medium),
+            "low" => Ok(Self::Low),
+            _ => Err(format!(
+                "Expected critical, high, medium or low, got `{}`",
+                s
+            )),
+        }
+    }
+}
+
+/// Whether repeated runs on the same sources must produce byte-identical SBOMs
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Reproducible {
+    Enabled,
+    #[default]
+    Disabled,
+}
+
+/// Which of the dev-dependencies to list in the SBOM
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DevDependencies {
+    #[default]
+    Excluded,
+    /// Only the dev-dependencies declared by the package itself, not the crates they depend on
+    DirectOnly,
+}
+
+/// How to list the proc-macro crates, which only run at build time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ProcMacros {
+    /// With the 'excluded' scope and a `cdx:cargo:proc_macro` property
+    #[default]
+    Excluded,
+    /// Like any other dependency
+    Included,
+}
+
+/// How much information to record about each component
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentDetail {
+    #[default]
+    Full,
+    /// Only the type, name, version, purl and bom-ref, for consumers with size limits
+    Minimal,
+}
+
+/// What to do when the SBOM file to write already exists
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExistingFiles {
+    #[default]
+    Overwrite,
+    /// Fail instead of replacing the existing file
+    Keep,
+}
+
+/// Where the SBOM is written to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputDestination {
+    /// A file next to the package manifest, named according to the [OutputOptions]
+    #[default]
+    File,
+    Stdout,
+}
+
+/// Where the licenses of a component come from
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseDetection {
+    /// The `license` and `license-file` of the package manifest
+    #[default]
+    Manifest,
+    /// The licenses identified from the license files in the package directory
+    Detected,
+    /// Both, acknowledging the ones from the manifest as declared and the detected ones as concluded
+    Both,
+}
+
+impl FromStr for LicenseDetection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manifest" => Ok(Self::Manifest),
+            "detected" => Ok(Self::Detected),
+            "both" => Ok(Self::Both),
+            _ => Err(format!("Expected manifest, detected or both, got `{}`", s)),
+        }
+    }
+}
+
+/// The component type of a package with both library and binary targets
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MixedPackageType {
+    /// Consider the package an application, as it can be run
+    #[default]
+    Application,
+    /// Consider the package a library, as it can be depended upon
+    Library,
+}
+
+impl FromStr for MixedPackageType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "application" => Ok(Self::Application),
+            "library" => Ok(Self::Library),
+            _ => Err(format!("Expected application or library, got `{}`", s)),
+        }
+    }
+}
+
+/// How many SBOMs to generate for a workspace
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceOutput {
+    /// One SBOM next to the manifest of each member
+    #[default]
+    PerMember,
+    /// A single SBOM for the whole workspace, with the members nested in its root component
+    Aggregate,
+}
+
+/// How the serial number of the SBOM is chosen
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SerialNumber {
+    /// A random version 4 UUID
+    #[default]
+    Random,
+    /// A version 5 UUID derived from the components, identical for identical inputs
+    Content,
+}
+
+impl FromStr for SerialNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "random" => Ok(Self::Random),
+            "content" => Ok(Self::Content),
+            _ => Err(format!("Expected random or content, got `{}`", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OutputOptions {
     pub cdx_extension: CdxExtension,
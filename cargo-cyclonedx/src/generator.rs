@@ -15,13 +15,28 @@
  *
  * SPDX-License-Identifier: Apache-2.0
  */
+use crate::config::ComponentDetail;
+use crate::config::DefaultScope;
+use crate::config::DevDependencies;
+use crate::config::ExistingFiles;
+use crate::config::Features;
+use crate::config::GitMetadata;
+use crate::config::LicenseDetection;
+use crate::config::MixedPackageType;
 use crate::config::Pattern;
 use crate::config::PlatformSuffix;
 use crate::config::Prefix;
+use crate::config::ProcMacros;
+use crate::config::Reproducible;
 use crate::config::SbomConfig;
+use crate::config::SerialNumber;
+use crate::config::SeverityThreshold;
+use crate::config::WorkspaceOutput;
 use crate::config::{IncludedDependencies, ParseMode};
 use crate::format::Format;
-use crate::purl::get_purl;
+use crate::license_detection::{declared, detect_licenses};
+use crate::platform::{rustc_location, rustc_version, RustcVersion};
+use crate::purl::{get_group, get_purl};
 
 use cargo_metadata;
 use cargo_metadata::DependencyKind;
@@ -31,12 +46,13 @@ use cargo_metadata::NodeDep;
 use cargo_metadata::Package;
 use cargo_metadata::PackageId;
 
-use cargo_metadata::camino::Utf8PathBuf;
+use cargo_metadata::camino::{Utf8Path, Utf8PathBuf};
+use cyclonedx_bom::external_models::date_time::DateTime;
 use cyclonedx_bom::external_models::normalized_string::NormalizedString;
 use cyclonedx_bom::external_models::spdx::SpdxExpression;
-use cyclonedx_bom::external_models::uri::Uri;
+use cyclonedx_bom::external_models::uri::{Purl, PurlComponents, Uri};
 use cyclonedx_bom::models::attached_text::AttachedText;
-use cyclonedx_bom::models::bom::Bom;
+use cyclonedx_bom::models::bom::{Bom, UrnUuid};
 use cyclonedx_bom::models::component::{Classification, Component, Components, Scope};
 use cyclonedx_bom::models::dependency::{Dependencies, Dependency};
 use cyclonedx_bom::models::external_reference::{
@@ -46,19 +62,24 @@ use cyclonedx_bom::models::license::{License, LicenseChoice, Licenses};
 use cyclonedx_bom::models::metadata::Metadata;
 use cyclonedx_bom::models::metadata::MetadataError;
 use cyclonedx_bom::models::organization::OrganizationalContact;
+use cyclonedx_bom::models::property::{Properties, Property};
 use cyclonedx_bom::models::tool::{Tool, Tools};
-use cyclonedx_bom::validation::Validate;
+use cyclonedx_bom::models::vulnerability::Vulnerabilities;
+use cyclonedx_bom::models::vulnerability_rating::Severity;
+use cyclonedx_bom::models::vulnerability_target::VulnerabilityTargets;
 use cyclonedx_bom::validation::ValidationResult;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
 use log::Level;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryFrom;
-use std::fs::File;
+use std::fs::{File, OpenOptions};
 use std::io::BufWriter;
 use std::io::Write;
 use std::path::PathBuf;
+use std::process::Command;
+use std::str::FromStr;
 use thiserror::Error;
 use validator::validate_email;
 
@@ -69,6 +90,7 @@ type ResolveMap = BTreeMap<PackageId, Node>;
 pub struct SbomGenerator {
     config: SbomConfig,
     workspace_root: Utf8PathBuf,
+    rustc_version: Option<RustcVersion>,
 }
 
 impl SbomGenerator {
@@ -81,29 +103,38 @@ impl SbomGenerator {
         let packages = index_packages(meta.packages);
         let resolve = index_resolve(meta.resolve.unwrap().nodes);
 
+        let rustc_version = rustc_version(&rustc_location());
+
+        if config.workspace_output() == WorkspaceOutput::Aggregate {
+            let generator = SbomGenerator {
+                config: config.clone(),
+                workspace_root: meta.workspace_root.to_owned(),
+                rustc_version,
+            };
+            let (name, bom) = generator.create_workspace_bom(&members, &packages, &resolve)?;
+            validate_generated(&bom);
+
+            return Ok(vec![GeneratedSbom {
+                bom,
+                manifest_path: meta.workspace_root.join("Cargo.toml").into_std_path_buf(),
+                package_name: name,
+                sbom_config: generator.config,
+            }]);
+        }
+
         let mut result = Vec::with_capacity(members.len());
         for member in members.iter() {
             log::trace!("Processing the package {}", member);
 
-            let (dependencies, pruned_resolve) =
-                if config.included_dependencies() == IncludedDependencies::AllDependencies {
-                    all_dependencies(member, &packages, &resolve)
-                } else {
-                    top_level_dependencies(member, &packages, &resolve)
-                };
-
             let generator = SbomGenerator {
                 config: config.clone(),
                 workspace_root: meta.workspace_root.to_owned(),
+                rustc_version: rustc_version.clone(),
             };
+            let (dependencies, pruned_resolve) =
+                generator.member_dependencies(member, &packages, &resolve);
             let bom = generator.create_bom(member, &dependencies, &pruned_resolve)?;
-
-            if cfg!(debug_assertions) {
-                let result = bom.validate().unwrap();
-                if let ValidationResult::Failed { reasons } = result {
-                    panic!("The generated SBOM failed validation: {:?}", &reasons);
-                }
-            }
+            validate_generated(&bom);
 
             let generated = GeneratedSbom {
                 bom,
@@ -118,6 +149,31 @@ impl SbomGenerator {
         Ok(result)
     }
 
+    /// The packages and dependency graph to describe in the SBOM of a workspace member
+    fn member_dependencies(
+        &self,
+        member: &PackageId,
+        packages: &PackageMap,
+        resolve: &ResolveMap,
+    ) -> (PackageMap, ResolveMap) {
+        let (mut dependencies, mut pruned_resolve) =
+            if self.config.included_dependencies() == IncludedDependencies::AllDependencies {
+                all_dependencies(member, packages, resolve)
+            } else {
+                top_level_dependencies(member, packages, resolve)
+            };
+        if self.config.dev_dependencies() == DevDependencies::DirectOnly {
+            add_direct_dev_dependencies(
+                member,
+                packages,
+                resolve,
+                &mut dependencies,
+                &mut pruned_resolve,
+            );
+        }
+        (dependencies, pruned_resolve)
+    }
+
     fn create_bom(
         &self,
         package: &PackageId,
@@ -127,10 +183,22 @@ impl SbomGenerator {
         let mut bom = Bom::default();
         let root_package = &packages[package];
 
+        let optional = optional_packages(package, packages, resolve, self.config.features.as_ref());
+        let dev_only = dev_only_packages(resolve);
         let components: Vec<_> = packages
             .values()
             .filter(|p| &p.id != package)
-            .map(|component| self.create_component(component, root_package))
+            .map(|p| {
+                let mut component = self.create_component(p, root_package);
+                if dev_only.contains(&p.id) {
+                    component.scope = Some(Scope::Optional);
+                } else if self.excludes_proc_macro(p) {
+                    exclude_proc_macro(&mut component);
+                } else if optional.contains(&p.id) {
+                    component.scope = Some(Scope::Optional);
+                }
+                component
+            })
             .collect();
 
         bom.components = Some(Components(components));
@@ -139,16 +207,150 @@ impl SbomGenerator {
 
         bom.metadata = Some(metadata);
 
-        bom.dependencies = Some(create_dependencies(resolve));
+        let mut dependencies = create_dependencies(resolve);
+        if let Some(name) = &self.config.rename_root {
+            let root_ref = renamed_bom_ref(root_package, name);
+            rename_dependency_ref(&mut dependencies, &package.to_string(), &root_ref);
+        }
+
+        bom.dependencies = Some(dependencies);
+        self.finish_bom(&mut bom)?;
 
         Ok(bom)
     }
 
+    /// Creates a single SBOM for all workspace members, returning it along with the name of the workspace
+    ///
+    /// The root component stands for the workspace and contains a component for each member.
+    /// The dependencies of all members are listed once, a package only being optional
+    /// if it is optional for every member depending on it.
+    fn create_workspace_bom(
+        &self,
+        members: &[PackageId],
+        packages: &PackageMap,
+        resolve: &ResolveMap,
+    ) -> Result<(String, Bom), GeneratorError> {
+        let mut bom = Bom::default();
+
+        let mut workspace_packages = PackageMap::new();
+        let mut workspace_resolve = ResolveMap::new();
+        let mut required = BTreeSet::new();
+        // The first member pulling in each package, which path dependencies are made relative to
+        let mut dependents: BTreeMap<PackageId, &Package> = BTreeMap::new();
+        for member in members {
+            log::trace!("Processing the package {}", member);
+
+            let (member_packages, member_resolve) =
+                self.member_dependencies(member, packages, resolve);
+            let optional = optional_packages(
+                member,
+                &member_packages,
+                &member_resolve,
+                self.config.features.as_ref(),
+            );
+            for (id, package) in member_packages {
+                if !optional.contains(&id) {
+                    required.insert(id.clone());
+                }
+                dependents.entry(id.clone()).or_insert(&packages[member]);
+                workspace_packages.entry(id).or_insert(package);
+            }
+            for node in member_resolve.into_values() {
+                merge_node(&mut workspace_resolve, node);
+            }
+        }
+
+        let dev_only = dev_only_packages(&workspace_resolve);
+        let components: Vec<_> = workspace_packages
+            .values()
+            .filter(|p| !members.contains(&p.id))
+            .map(|p| {
+                let mut component = self.create_component(p, dependents[&p.id]);
+                if dev_only.contains(&p.id) {
+                    component.scope = Some(Scope::Optional);
+                } else if self.excludes_proc_macro(p) {
+                    exclude_proc_macro(&mut component);
+                } else if !required.contains(&p.id) {
+                    component.scope = Some(Scope::Optional);
+                }
+                component
+            })
+            .collect();
+
+        bom.components = Some(Components(components));
+
+        let name = match &self.config.rename_root {
+            Some(name) => name.clone(),
+            None => self
+                .workspace_root
+                .file_name()
+                .unwrap_or("workspace")
+                .to_string(),
+        };
+        let root_ref = format!("path+file://{}", self.workspace_root);
+        let member_packages: Vec<&Package> = members.iter().map(|id| &packages[id]).collect();
+        bom.metadata = Some(self.create_workspace_metadata(&name, &root_ref, &member_packages)?);
+
+        let mut dependencies = create_dependencies(&workspace_resolve);
+        dependencies.0.push(Dependency {
+            dependency_ref: root_ref,
+            dependencies: members.iter().map(|id| id.to_string()).collect(),
+        });
+
+        bom.dependencies = Some(dependencies);
+        self.finish_bom(&mut bom)?;
+
+        Ok((name, bom))
+    }
+
+    /// Whether the package is a proc-macro crate to be listed as excluded
+    fn excludes_proc_macro(&self, package: &Package) -> bool {
+        self.config.proc_macros() == ProcMacros::Excluded
+            && package
+                .targets
+                .iter()
+                .any(|tgt| tgt.kind.iter().any(|kind| kind == "proc-macro"))
+    }
+
+    /// Applies the settings that concern the SBOM as a whole
+    fn finish_bom(&self, bom: &mut Bom) -> Result<(), GeneratorError> {
+        bom.external_references = self.get_build_references();
+        if let Some(metadata) = &mut bom.metadata {
+            metadata.lifecycles = self.config.lifecycles.clone();
+        }
+        for cycle in bom.dependencies.iter().flat_map(|d| d.find_cycles()) {
+            log::warn!(
+                "The dependency graph of the SBOM contains a cycle: {}",
+                cycle.join(" -> ")
+            );
+        }
+
+        if self.config.component_detail() == ComponentDetail::Minimal {
+            minimize_components(bom);
+        }
+
+        let reproducible = self.config.reproducible() == Reproducible::Enabled;
+        if reproducible {
+            make_reproducible(bom)?;
+        }
+        if reproducible || self.config.serial_number() == SerialNumber::Content {
+            bom.serial_number = Some(content_serial_number(bom));
+        }
+
+        Ok(())
+    }
+
     fn create_component(&self, package: &Package, root_package: &Package) -> Component {
         let name = package.name.to_owned().trim().to_string();
         let version = package.version.to_string();
 
-        let purl = match get_purl(package, root_package, &self.workspace_root, None) {
+        let purl = match get_purl(
+            package,
+            root_package,
+            &self.workspace_root,
+            None,
+            &self.config.purl_qualifiers(),
+        ) {
             Ok(purl) => Some(purl),
             Err(e) => {
                 log::warn!("Package {} has an invalid Purl: {} ", package.name, e);
@@ -164,22 +366,49 @@ impl SbomGenerator {
         );
 
         component.purl = purl;
-        component.scope = Some(Scope::Required);
+        component.group = get_group(package).map(|g| NormalizedString::new(&g));
+        component.scope = Some(match self.config.default_scope() {
+            DefaultScope::Required => Scope::Required,
+            DefaultScope::Optional => Scope::Optional,
+        });
         component.external_references = Self::get_external_references(package);
         component.licenses = self.get_licenses(package);
 
-        component.description = package
-            .description
+        component.description = package.description.as_ref().map(NormalizedString::new);
+
+        if let Some(tags) = self
+            .config
+            .crate_tags
             .as_ref()
-            .map(|s| NormalizedString::new(s));
+            .and_then(|t| t.tags_for(&package.name))
+        {
+            component.tags = Some(tags.clone());
+        }
 
         component
     }
 
     /// Same as [Self::create_component] but also includes information
-    /// on binaries and libraries comprising it as subcomponents
-    fn create_toplevel_component(&self, package: &Package) -> Component {
+    /// on binaries and libraries comprising it as subcomponents.
+    /// A `name` overrides the package name, and the bom-refs derived from it.
+    fn create_toplevel_component(&self, package: &Package, name: Option<&str>) -> Component {
         let mut top_component = self.create_component(package, package);
+        if let Some(name) = name {
+            top_component.name = NormalizedString::new(name);
+            top_component.bom_ref = Some(renamed_bom_ref(package, name));
+        }
+        if self.config.git_metadata() == GitMetadata::Included {
+            Self::add_git_metadata(package, &mut top_component);
+        }
+        let properties = &mut top_component
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()))
+            .0;
+        properties.push(Property::new("cdx:cargo:profile", self.config.profile()));
+        if let Some(rustc) = &self.rustc_version {
+            properties.push(Property::new("cdx:cargo:rustc_version", &rustc.release));
+            properties.push(Property::new("cdx:cargo:rustc_host", &rustc.host));
+        }
         let mut subcomponents: Vec<Component> = Vec::new();
         let mut subcomp_count: u32 = 0;
         for tgt in &package.targets {
@@ -234,8 +463,14 @@ impl SbomGenerator {
                     .parent()
                     .expect("manifest_path in `cargo metadata` output is not a file!");
                 if let Ok(relative_path) = tgt.src_path.strip_prefix(package_dir) {
-                    subcomponent.purl =
-                        get_purl(package, package, &self.workspace_root, Some(relative_path)).ok();
+                    subcomponent.purl = get_purl(
+                        package,
+                        package,
+                        &self.workspace_root,
+                        Some(relative_path),
+                        &self.config.purl_qualifiers(),
+                    )
+                    .ok();
                 } else {
                     log::warn!(
                         "Source path \"{}\" is not a subpath of workspace root \"{}\"",
@@ -251,20 +486,96 @@ impl SbomGenerator {
         top_component
     }
 
-    fn get_classification(pkg: &Package) -> Classification {
+    /// Records the git revision the package is built from on the component
+    fn add_git_metadata(package: &Package, component: &mut Component) {
+        let package_dir = package
+            .manifest_path
+            .parent()
+            .expect("manifest_path in `cargo metadata` output is not a file!");
+
+        let revision = match GitRevision::read(package_dir) {
+            Ok(revision) => revision,
+            Err(e) => {
+                log::warn!(
+                    "Could not read git metadata for package {}: {}",
+                    package.name,
+                    e
+                );
+                return;
+            }
+        };
+
+        let properties = component
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()));
+        properties
+            .0
+            .push(Property::new("cdx:cargo:vcs_revision", &revision.sha));
+        properties.0.push(Property::new(
+            "cdx:cargo:vcs_dirty",
+            &revision.dirty.to_string(),
+        ));
+
+        if let Some(repository) = &package.repository {
+            match Uri::try_from(format!("{}#{}", repository, revision.sha)) {
+                Ok(uri) => component
+                    .external_references
+                    .get_or_insert_with(|| ExternalReferences(Vec::new()))
+                    .0
+                    .push(ExternalReference::new(ExternalReferenceType::Vcs, uri)),
+                Err(e) => log::warn!(
+                    "Package {} has an invalid repository URI ({}): {} ",
+                    package.name,
+                    repository,
+                    e
+                ),
+            }
+        }
+    }
+
+    fn get_classification(&self, pkg: &Package) -> Classification {
         // Transitive dependencies that contain both libraries and binaries
         // get surfaces only as a library by `cargo metadata`.
         //
         // Both "bin" and "lib" can only occur together in the toplevel package,
         // and we record its constituent parts in detail.
         //
-        // We have to make a judgement call how to summarise having both bin and lib targets,
-        // and that call is "consider it a binary".
-        if pkg.targets.iter().any(|tgt| tgt.is_bin()) {
-            return Classification::Application;
+        // How to summarise having both bin and lib targets is configurable,
+        // and by default we consider it a binary.
+        let has_bin = pkg.targets.iter().any(|tgt| tgt.is_bin());
+        let has_lib = pkg.targets.iter().any(|tgt| {
+            tgt.kind
+                .iter()
+                .any(|kind| kind.contains("lib") || kind == "proc-macro")
+        });
+        match (has_bin, has_lib, self.config.mixed_package_type()) {
+            (true, true, MixedPackageType::Library) => Classification::Library,
+            (true, _, _) => Classification::Application,
+            (false, _, _) => Classification::Library,
         }
+    }
+
+    /// References to the build that produced the SBOM
+    fn get_build_references(&self) -> Option<ExternalReferences> {
+        let references: Vec<_> = [
+            (ExternalReferenceType::BuildSystem, &self.config.build_url),
+            (
+                ExternalReferenceType::BuildMeta,
+                &self.config.build_meta_url,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(reference_type, url)| {
+            url.clone()
+                .map(|url| ExternalReference::new(reference_type, url))
+        })
+        .collect();
 
-        Classification::Library
+        if references.is_empty() {
+            None
+        } else {
+            Some(ExternalReferences(references))
+        }
     }
 
     fn get_external_references(package: &Package) -> Option<ExternalReferences> {
@@ -333,6 +644,39 @@ impl SbomGenerator {
     }
 
     fn get_licenses(&self, package: &Package) -> Option<Licenses> {
+        let licenses = match self.config.license_detection() {
+            LicenseDetection::Manifest => self.get_manifest_licenses(package),
+            LicenseDetection::Detected => Self::get_detected_licenses(package),
+            LicenseDetection::Both => {
+                let mut licenses: Vec<LicenseChoice> = self
+                    .get_manifest_licenses(package)
+                    .into_iter()
+                    .map(declared)
+                    .collect();
+                licenses.extend(Self::get_detected_licenses(package));
+                licenses
+            }
+        };
+
+        if licenses.is_empty() {
+            log::trace!(
+                "Package {} has no licenses or license file specified",
+                package.name
+            );
+            return None;
+        }
+
+        Some(Licenses(licenses))
+    }
+
+    fn get_detected_licenses(package: &Package) -> Vec<LicenseChoice> {
+        match package.manifest_path.parent() {
+            Some(package_dir) => detect_licenses(package_dir.as_std_path()),
+            None => Vec::new(),
+        }
+    }
+
+    fn get_manifest_licenses(&self, package: &Package) -> Vec<LicenseChoice> {
         let mut licenses = vec![];
 
         if let Some(license) = &package.license {
@@ -396,15 +740,7 @@ impl SbomGenerator {
             }
         }
 
-        if licenses.is_empty() {
-            log::trace!(
-                "Package {} has no licenses or license file specified",
-                package.name
-            );
-            return None;
-        }
-
-        Some(Licenses(licenses))
+        licenses
     }
 
     fn create_metadata(&self, package: &Package) -> Result<Metadata, GeneratorError> {
@@ -415,9 +751,50 @@ impl SbomGenerator {
             metadata.authors = Some(authors);
         }
 
-        let mut component = self.create_toplevel_component(package);
+        let mut component =
+            self.create_toplevel_component(package, self.config.rename_root.as_deref());
+
+        component.component_type = self.get_classification(package);
+
+        metadata.component = Some(component);
+
+        let tool = Tool::new("CycloneDX", "cargo-cyclonedx", env!("CARGO_PKG_VERSION"));
+
+        metadata.tools = Some(Tools(vec![tool]));
+
+        Ok(metadata)
+    }
+
+    fn create_workspace_metadata(
+        &self,
+        name: &str,
+        bom_ref: &str,
+        members: &[&Package],
+    ) -> Result<Metadata, GeneratorError> {
+        let mut metadata = Metadata::new()?;
 
-        component.component_type = Self::get_classification(package);
+        // The workspace only has a version of its own if all members share it
+        let version = match members.split_first() {
+            Some((first, rest)) if rest.iter().all(|m| m.version == first.version) => {
+                first.version.to_string()
+            }
+            _ => String::new(),
+        };
+        let mut component = Component::new(
+            Classification::Application,
+            name,
+            &version,
+            Some(bom_ref.to_string()),
+        );
+        let member_components = members
+            .iter()
+            .map(|package| {
+                let mut member_component = self.create_toplevel_component(package, None);
+                member_component.component_type = self.get_classification(package);
+                member_component
+            })
+            .collect();
+        component.components = Some(Components(member_components));
 
         metadata.component = Some(component);
 
@@ -510,6 +887,141 @@ pub enum GeneratorError {
 
     #[error("Could not parse author string: {}", .0)]
     AuthorParseError(String),
+
+    #[error("Invalid SOURCE_DATE_EPOCH: {}", .0)]
+    SourceDateEpochError(String),
+}
+
+/// Strips every component down to what identifies it
+fn minimize_components(bom: &mut Bom) {
+    fn minimal_component(component: &Component) -> Component {
+        let mut minimal = Component::new(
+            component.component_type.clone(),
+            component.name.as_ref(),
+            "",
+            component.bom_ref.clone(),
+        );
+        minimal.version = component.version.clone();
+        minimal.purl = component.purl.clone();
+        minimal.components = component
+            .components
+            .as_ref()
+            .map(|c| Components(c.0.iter().map(minimal_component).collect()));
+        minimal
+    }
+
+    if let Some(metadata) = &mut bom.metadata {
+        metadata.component = metadata.component.as_ref().map(minimal_component);
+    }
+    if let Some(components) = &mut bom.components {
+        components.0 = components.0.iter().map(minimal_component).collect();
+    }
+}
+
+/// Namespace of the serial numbers derived from the components of an SBOM
+const SERIAL_NUMBER_NAMESPACE: uuid::Uuid =
+    uuid::Uuid::from_u128(0x4ab0_5f1e_56c1_4f0c_9c3d_2b41_7f7e_1a0d);
+
+/// Removes everything from the SBOM that differs between runs on the same sources
+fn make_reproducible(bom: &mut Bom) -> Result<(), GeneratorError> {
+    bom.normalize();
+
+    let timestamp = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => {
+            let seconds = epoch
+                .trim()
+                .parse::<i64>()
+                .map_err(|e| GeneratorError::SourceDateEpochError(e.to_string()))?;
+            Some(
+                DateTime::from_unix_timestamp(seconds)
+                    .map_err(|e| GeneratorError::SourceDateEpochError(e.to_string()))?,
+            )
+        }
+        Err(_) => None,
+    };
+    if let Some(metadata) = &mut bom.metadata {
+        metadata.timestamp = timestamp;
+    }
+
+    Ok(())
+}
+
+/// Derives the serial number from the sorted purls of all components in the SBOM,
+/// falling back to the bom-ref for components without one
+fn content_serial_number(bom: &Bom) -> UrnUuid {
+    fn collect_refs(components: &[Component], refs: &mut Vec<String>) {
+        for component in components {
+            refs.extend(
+                component
+                    .purl
+                    .as_ref()
+                    .map(|purl| purl.to_string())
+                    .or_else(|| component.bom_ref.clone()),
+            );
+            if let Some(subcomponents) = &component.components {
+                collect_refs(&subcomponents.0, refs);
+            }
+        }
+    }
+
+    let mut refs = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        collect_refs(std::slice::from_ref(component), &mut refs);
+    }
+    if let Some(components) = &bom.components {
+        collect_refs(&components.0, &mut refs);
+    }
+    refs.sort_unstable();
+
+    UrnUuid::v5_from_content(&SERIAL_NUMBER_NAMESPACE, refs.join("\n").as_bytes())
+}
+
+/// Marks the component of a proc-macro crate as only being used at build time
+fn exclude_proc_macro(component: &mut Component) {
+    component.scope = Some(Scope::Excluded);
+    component
+        .properties
+        .get_or_insert_with(|| Properties(Vec::new()))
+        .0
+        .push(Property::new("cdx:cargo:proc_macro", "true"));
+}
+
+/// Whether two purls refer to the same version of a package, whatever their qualifiers and subpath
+fn is_same_package(a: &PurlComponents, b: &PurlComponents) -> bool {
+    a.package_type == b.package_type
+        && a.namespace == b.namespace
+        && a.name == b.name
+        && a.version == b.version
+}
+
+/// Adds a node to a dependency graph, merging its edges into those of the node already present
+fn merge_node(resolve: &mut ResolveMap, node: Node) {
+    let existing = match resolve.get_mut(&node.id) {
+        Some(existing) => existing,
+        None => {
+            resolve.insert(node.id.clone(), node);
+            return;
+        }
+    };
+    for dep in node.deps {
+        match existing.deps.iter_mut().find(|d| d.pkg == dep.pkg) {
+            Some(existing_dep) => existing_dep.dep_kinds.extend(dep.dep_kinds),
+            None => {
+                existing.dependencies.push(dep.pkg.clone());
+                existing.deps.push(dep);
+            }
+        }
+    }
+}
+
+/// Panics if the SBOM fails validation, in debug builds only
+fn validate_generated(bom: &Bom) {
+    if cfg!(debug_assertions) {
+        let result = bom.validate_strict().unwrap();
+        if let ValidationResult::Failed { reasons } = result {
+            panic!("The generated SBOM failed validation: {:?}", &reasons);
+        }
+    }
 }
 
 /// Generates the `Dependencies` field in the final SBOM
@@ -524,6 +1036,210 @@ fn create_dependencies(resolve: &ResolveMap) -> Dependencies {
     Dependencies(deps)
 }
 
+/// The commit checked out in a git repository and whether the working tree has changes
+struct GitRevision {
+    sha: String,
+    dirty: bool,
+}
+
+impl GitRevision {
+    fn read(dir: &Utf8Path) -> Result<Self, String> {
+        let sha = run_git(dir, &["rev-parse", "HEAD"])?;
+        let status = run_git(dir, &["status", "--porcelain"])?;
+
+        Ok(Self {
+            sha,
+            dirty: !status.is_empty(),
+        })
+    }
+}
+
+fn run_git(dir: &Utf8Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Derives the BOM reference of a package that is emitted under a different name
+fn renamed_bom_ref(package: &Package, name: &str) -> String {
+    let id = package.id.to_string();
+    match id.split_once('#') {
+        // Package ID specification, e.g. `path+file:///path/to/pkg#name@0.1.0`
+        Some((source, _)) => format!("{}#{}@{}", source, name, package.version),
+        // Legacy format, e.g. `name 0.1.0 (path+file:///path/to/pkg)`
+        None => id.replacen(package.name.as_str(), name, 1),
+    }
+}
+
+/// Points all dependency edges referring to `old_ref` to `new_ref` instead
+fn rename_dependency_ref(dependencies: &mut Dependencies, old_ref: &str, new_ref: &str) {
+    for dependency in dependencies.0.iter_mut() {
+        if dependency.dependency_ref == old_ref {
+            dependency.dependency_ref = new_ref.to_string();
+        }
+        for sub_dependency in dependency.dependencies.iter_mut() {
+            if sub_dependency == old_ref {
+                *sub_dependency = new_ref.to_string();
+            }
+        }
+    }
+}
+
+/// Finds the packages that are only pulled in through optional dependencies
+/// which the features requested for the root package do not enable
+///
+/// Starting from the features of the root package requested with `--features`,
+/// `--all-features` and `--no-default-features`, or its default features otherwise,
+/// the dependency graph is walked along the edges that are required: non-optional
+/// dependency declarations and optional ones enabled by the features requested so far.
+/// Every package not reached this way is only present because of a feature that was not
+/// requested, e.g. one another workspace member enables.
+fn optional_packages(
+    root: &PackageId,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+    features: Option<&Features>,
+) -> BTreeSet<PackageId> {
+    let root_features = match packages.get(root) {
+        Some(package) => requested_features(package, features),
+        None => BTreeSet::new(),
+    };
+    let mut required_features: BTreeMap<&PackageId, BTreeSet<String>> = BTreeMap::new();
+    required_features.insert(root, root_features);
+    let mut queue = vec![root];
+
+    while let Some(id) = queue.pop() {
+        let (package, node) = match (packages.get(id), resolve.get(id)) {
+            (Some(package), Some(node)) => (package, node),
+            _ => continue,
+        };
+        let enabled = enabled_features(package, &required_features[id]);
+
+        for dep in &node.deps {
+            let dep_package = match packages.get(&dep.pkg) {
+                Some(dep_package) => dep_package,
+                None => continue,
+            };
+            let mut requested = BTreeSet::new();
+            let mut required = false;
+            for declaration in package
+                .dependencies
+                .iter()
+                .filter(|d| d.name == dep_package.name)
+            {
+                let key = declaration.rename.as_ref().unwrap_or(&declaration.name);
+                if declaration.optional && !enabled.dependencies.contains(key) {
+                    continue;
+                }
+                required = true;
+                if declaration.uses_default_features {
+                    requested.insert("default".to_string());
+                }
+                requested.extend(declaration.features.iter().cloned());
+                if let Some(features) = enabled.dependency_features.get(key) {
+                    requested.extend(features.iter().cloned());
+                }
+            }
+            if !required {
+                continue;
+            }
+
+            let is_new = !required_features.contains_key(&dep.pkg);
+            let features = required_features.entry(&dep.pkg).or_default();
+            let known = features.len();
+            features.extend(requested);
+            if is_new || features.len() > known {
+                queue.push(&dep.pkg);
+            }
+        }
+    }
+
+    packages
+        .keys()
+        .filter(|id| !required_features.contains_key(id))
+        .cloned()
+        .collect()
+}
+
+/// The features of the root package requested on the command line
+fn requested_features(package: &Package, features: Option<&Features>) -> BTreeSet<String> {
+    let features = match features {
+        Some(features) => features,
+        None => return BTreeSet::from(["default".to_string()]),
+    };
+
+    let mut requested: BTreeSet<String> = features.features.iter().cloned().collect();
+    if !features.no_default_features {
+        requested.insert("default".to_string());
+    }
+    if features.all_features {
+        requested.extend(package.features.keys().cloned());
+        // Implicit features of the optional dependencies
+        requested.extend(
+            package
+                .dependencies
+                .iter()
+                .filter(|d| d.optional)
+                .map(|d| d.rename.as_ref().unwrap_or(&d.name).clone()),
+        );
+    }
+    requested
+}
+
+/// Dependencies and dependency features switched on by a set of features of a package
+#[derive(Default)]
+struct EnabledFeatures {
+    dependencies: BTreeSet<String>,
+    dependency_features: BTreeMap<String, BTreeSet<String>>,
+}
+
+fn enabled_features(package: &Package, features: &BTreeSet<String>) -> EnabledFeatures {
+    let mut enabled = EnabledFeatures::default();
+    let mut visited = BTreeSet::new();
+    let mut queue: Vec<&str> = features.iter().map(String::as_str).collect();
+
+    while let Some(feature) = queue.pop() {
+        if !visited.insert(feature) {
+            continue;
+        }
+        if let Some(name) = feature.strip_prefix("dep:") {
+            enabled.dependencies.insert(name.to_string());
+        } else if let Some((name, dep_feature)) = feature.split_once('/') {
+            // `name?/feature` only enables the feature if the dependency is enabled otherwise
+            if let Some(name) = name.strip_suffix('?') {
+                enabled
+                    .dependency_features
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(dep_feature.to_string());
+            } else {
+                enabled.dependencies.insert(name.to_string());
+                enabled
+                    .dependency_features
+                    .entry(name.to_string())
+                    .or_default()
+                    .insert(dep_feature.to_string());
+            }
+        } else if let Some(implied) = package.features.get(feature) {
+            queue.extend(implied.iter().map(String::as_str));
+        } else {
+            // Implicit feature of an optional dependency
+            enabled.dependencies.insert(feature.to_string());
+        }
+    }
+
+    enabled
+}
+
 fn top_level_dependencies(
     root: &PackageId,
     packages: &PackageMap,
@@ -597,6 +1313,57 @@ fn all_dependencies(
     (out_packages, out_resolve)
 }
 
+/// Adds the dev-dependencies declared by the root package, but none of the packages they depend on.
+/// Dev-dependencies that are also regular dependencies keep their dependencies.
+fn add_direct_dev_dependencies(
+    root: &PackageId,
+    packages: &PackageMap,
+    resolve: &ResolveMap,
+    out_packages: &mut PackageMap,
+    out_resolve: &mut ResolveMap,
+) {
+    log::trace!("Adding direct dev-dependencies to SBOM");
+
+    let root_node = &resolve[root];
+    for dep in &root_node.deps {
+        if out_resolve.contains_key(&dep.pkg) {
+            continue;
+        }
+        // Clear all dependencies, the crates they pull in are not of interest
+        let mut node = resolve[&dep.pkg].clone();
+        node.deps = Vec::new();
+        node.dependencies = Vec::new();
+        out_resolve.insert(dep.pkg.to_owned(), node);
+        out_packages.insert(dep.pkg.to_owned(), packages[&dep.pkg].to_owned());
+    }
+
+    // Restore the edges to the dev-dependencies
+    out_resolve.insert(root.to_owned(), root_node.to_owned());
+}
+
+/// Packages that are only reachable through dev-dependency edges
+fn dev_only_packages(resolve: &ResolveMap) -> BTreeSet<PackageId> {
+    let mut dev_targets = BTreeSet::new();
+    let mut other_targets = BTreeSet::new();
+    for node in resolve.values() {
+        for dep in &node.deps {
+            if dep
+                .dep_kinds
+                .iter()
+                .any(|kind| kind.kind != DependencyKind::Development)
+            {
+                other_targets.insert(&dep.pkg);
+            } else {
+                dev_targets.insert(&dep.pkg);
+            }
+        }
+    }
+    dev_targets
+        .difference(&other_targets)
+        .map(|id| (*id).to_owned())
+        .collect()
+}
+
 fn strip_dev_dependencies(node: &Node) -> Node {
     let mut node = node.clone();
     node.deps = non_dev_dependencies(&node.deps).cloned().collect();
@@ -628,32 +1395,237 @@ pub struct GeneratedSbom {
 }
 
 impl GeneratedSbom {
-    /// Writes SBOM to either a JSON or XML file in the same folder as `Cargo.toml` manifest
-    pub fn write_to_file(self) -> Result<(), SbomWriterError> {
-        let path = self.manifest_path.with_file_name(self.filename());
-        log::info!("Outputting {}", path.display());
-        let file = File::create(path)?;
-        let mut writer = BufWriter::new(file);
-        match self.sbom_config.format() {
-            Format::Json => {
-                self.bom
-                    .output_as_json_v1_3(&mut writer)
-                    .map_err(SbomWriterError::JsonWriteError)?;
+    /// Adds the vulnerabilities, e.g. of a vulnerability feed or VEX document, that affect
+    /// components of the SBOM, merging them with those already listed.
+    ///
+    /// Targets refer to a component by its bom-ref or by its purl, ignoring qualifiers and
+    /// subpath, which is replaced with the bom-ref. Targets matching no component are dropped,
+    /// as are vulnerabilities left without targets.
+    pub fn add_vulnerabilities(&mut self, vulnerabilities: &Vulnerabilities) {
+        let components: Vec<&Component> = self
+            .bom
+            .metadata
+            .iter()
+            .flat_map(|m| m.component.iter())
+            .chain(self.bom.components.iter().flat_map(|c| c.0.iter()))
+            .collect();
+        let resolve_target = |target: &str| -> Option<String> {
+            let target_purl = Purl::from_str(target)
+                .ok()
+                .and_then(|p| p.components().ok());
+            components
+                .iter()
+                .find(|component| {
+                    component.bom_ref.as_deref() == Some(target)
+                        || match (&target_purl, &component.purl) {
+                            (Some(target_purl), Some(purl)) => purl
+                                .components()
+                                .is_ok_and(|purl| is_same_package(&purl, target_purl)),
+                            _ => false,
+                        }
+                })
+                .and_then(|component| component.bom_ref.clone())
+        };
+
+        let mut added = Vec::new();
+        for vulnerability in &vulnerabilities.0 {
+            let mut vulnerability = vulnerability.clone();
+            let mut targets = Vec::new();
+            for mut target in vulnerability
+                .vulnerability_targets
+                .take()
+                .into_iter()
+                .flat_map(|t| t.0)
+            {
+                if let Some(bom_ref) = resolve_target(&target.bom_ref) {
+                    target.bom_ref = bom_ref;
+                    targets.push(target);
+                }
+            }
+            if !targets.is_empty() {
+                vulnerability.vulnerability_targets = Some(VulnerabilityTargets(targets));
+                added.push(vulnerability);
             }
-            Format::Xml => {
-                self.bom
-                    .output_as_xml_v1_3(&mut writer)
-                    .map_err(SbomWriterError::XmlWriteError)?;
+        }
+        if added.is_empty() {
+            return;
+        }
+
+        let existing = self
+            .bom
+            .vulnerabilities
+            .get_or_insert_with(|| Vulnerabilities(Vec::new()));
+        for conflict in existing.merge(Vulnerabilities(added)) {
+            log::warn!(
+                "{}: vulnerability {} is analysed as {} and as {}, keeping the former",
+                self.package_name,
+                conflict.id,
+                conflict.existing,
+                conflict.incoming
+            );
+        }
+    }
+
+    /// Describes each vulnerability in the SBOM that is at least as severe as `threshold`
+    /// together with the components it affects.
+    /// Vulnerabilities whose analysis concluded that the components are not affected are skipped.
+    pub fn vulnerabilities_at_or_above(&self, threshold: SeverityThreshold) -> Vec<String> {
+        let threshold = match threshold {
+            SeverityThreshold::Critical => Severity::Critical,
+            SeverityThreshold::High => Severity::High,
+            SeverityThreshold::Medium => Severity::Medium,
+            SeverityThreshold::Low => Severity::Low,
+        };
+
+        let component_names: BTreeMap<&str, String> = self
+            .bom
+            .components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .filter_map(|c| {
+                let name = match &c.version {
+                    Some(version) => format!("{}@{}", c.name, version),
+                    None => c.name.to_string(),
+                };
+                c.bom_ref.as_deref().map(|bom_ref| (bom_ref, name))
+            })
+            .collect();
+
+        self.bom
+            .vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .filter(|v| !v.is_not_affected())
+            .filter_map(|v| {
+                let severity = v.max_severity()?;
+                if !severity.is_at_least(&threshold) {
+                    return None;
+                }
+                let id =
+                    v.id.as_ref()
+                        .map(|id| id.to_string())
+                        .or_else(|| v.bom_ref.clone())
+                        .unwrap_or_default();
+                let components: Vec<&str> = v
+                    .vulnerability_targets
+                    .iter()
+                    .flat_map(|t| t.0.iter())
+                    .map(|t| {
+                        component_names
+                            .get(t.bom_ref.as_str())
+                            .map_or(t.bom_ref.as_str(), |name| name.as_str())
+                    })
+                    .collect();
+                Some(format!(
+                    "{}: vulnerability {} ({}) affects {}",
+                    self.package_name,
+                    id,
+                    severity,
+                    components.join(", ")
+                ))
+            })
+            .collect()
+    }
+
+    /// Writes SBOM to a JSON or XML file, or both, in the same folder as `Cargo.toml` manifest
+    ///
+    /// All formats are serialized and all files opened before any of them is written,
+    /// so that refusing to overwrite one of the files leaves the others untouched as well.
+    pub fn write_to_file(self) -> Result<(), SbomWriterError> {
+        let bom = self.convert()?;
+        let mut outputs = Vec::new();
+        for format in self.sbom_config.formats() {
+            let path = self.manifest_path.with_file_name(self.filename(format));
+            let output = self.serialize(&bom, format)?;
+            outputs.push((path, output));
+        }
+
+        let existing_files = self.sbom_config.existing_files();
+        if existing_files == ExistingFiles::Keep {
+            if let Some((path, _)) = outputs.iter().find(|(path, _)| path.exists()) {
+                return Err(SbomWriterError::FileExistsError(path.clone()));
             }
         }
 
-        // Flush the writer explicitly to catch and report any I/O errors
-        writer.flush()?;
+        let mut files = Vec::new();
+        for (path, output) in &outputs {
+            let file = match existing_files {
+                ExistingFiles::Overwrite => File::create(path)?,
+                ExistingFiles::Keep => {
+                    match OpenOptions::new().write(true).create_new(true).open(path) {
+                        Ok(file) => file,
+                        Err(e) => {
+                            // Remove the files created so far, which are still empty
+                            for (created, _, _) in &files {
+                                let _ = std::fs::remove_file(created);
+                            }
+                            return Err(match e.kind() {
+                                std::io::ErrorKind::AlreadyExists => {
+                                    SbomWriterError::FileExistsError(path.clone())
+                                }
+                                _ => SbomWriterError::IoError(e),
+                            });
+                        }
+                    }
+                }
+            };
+            files.push((path, file, output));
+        }
+
+        for (path, file, output) in files {
+            log::info!("Outputting {}", path.display());
+            let mut writer = BufWriter::new(file);
+            writer.write_all(output)?;
+
+            // Flush the writer explicitly to catch and report any I/O errors
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes SBOM to stdout in the first of the configured formats,
+    /// with nothing else written to stdout
+    pub fn write_to_stdout(self) -> Result<(), SbomWriterError> {
+        let bom = self.convert()?;
+        let output = self.serialize(&bom, self.sbom_config.formats()[0])?;
+        log::info!("Outputting the SBOM of {} to stdout", self.package_name);
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(&output)?;
+        stdout.flush()?;
 
         Ok(())
     }
 
-    fn filename(&self) -> String {
+    /// Converts the SBOM to the configured version of the specification,
+    /// logging what that version cannot represent
+    fn convert(&self) -> Result<Bom, SbomWriterError> {
+        let (bom, warnings) = self
+            .bom
+            .convert_to(self.sbom_config.spec_version())
+            .map_err(SbomWriterError::ConversionError)?;
+        for warning in &warnings {
+            log::warn!("{}: {}", self.package_name, warning);
+        }
+        Ok(bom)
+    }
+
+    /// Serializes the converted SBOM in the given format
+    fn serialize(&self, bom: &Bom, format: Format) -> Result<Vec<u8>, SbomWriterError> {
+        let version = self.sbom_config.spec_version();
+        match format {
+            Format::Json => bom
+                .clone()
+                .to_cyclonedx_json_bytes(version)
+                .map_err(SbomWriterError::JsonWriteError),
+            Format::Xml => bom
+                .clone()
+                .to_cyclonedx_xml_bytes(version)
+                .map_err(SbomWriterError::XmlWriteError),
+        }
+    }
+
+    fn filename(&self, format: Format) -> String {
         let output_options = self.sbom_config.output_options();
         let prefix = match output_options.prefix {
             Prefix::Pattern(Pattern::Bom) => "bom".to_string(),
@@ -674,7 +1646,7 @@ impl GeneratedSbom {
             prefix,
             platform_suffix,
             output_options.cdx_extension.extension(),
-            self.sbom_config.format()
+            format
         )
     }
 }
@@ -692,6 +1664,12 @@ pub enum SbomWriterError {
 
     #[error("Error serializing to XML")]
     SerializeXmlError(#[source] std::io::Error),
+
+    #[error("Refusing to overwrite existing file: {}", .0.display())]
+    FileExistsError(PathBuf),
+
+    #[error("Error converting to the requested spec version")]
+    ConversionError(#[source] cyclonedx_bom::errors::BomError),
 }
 
 impl From<std::io::Error> for SbomWriterError {
@@ -742,4 +1720,71 @@ mod test {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_list_vulnerabilities_at_or_above_the_threshold() {
+        use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+        use cyclonedx_bom::models::vulnerability_analysis::{
+            ImpactAnalysisState, VulnerabilityAnalysis,
+        };
+        use cyclonedx_bom::models::vulnerability_rating::{
+            VulnerabilityRating, VulnerabilityRatings,
+        };
+        use cyclonedx_bom::models::vulnerability_target::{
+            VulnerabilityTarget, VulnerabilityTargets,
+        };
+
+        let vulnerability = |id: &str, severity: Severity, state: Option<ImpactAnalysisState>| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new(id));
+            vulnerability.vulnerability_ratings =
+                Some(VulnerabilityRatings(vec![VulnerabilityRating::new(
+                    None,
+                    Some(severity),
+                    None,
+                )]));
+            vulnerability.vulnerability_analysis =
+                state.map(|state| VulnerabilityAnalysis::new(Some(state), None, None));
+            vulnerability.vulnerability_targets =
+                Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                    "serde-ref".to_string(),
+                )]));
+            vulnerability
+        };
+
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "serde",
+                "1.0.0",
+                Some("serde-ref".to_string()),
+            )])),
+            vulnerabilities: Some(Vulnerabilities(vec![
+                vulnerability("HIGH-1", Severity::High, None),
+                vulnerability("LOW-1", Severity::Low, None),
+                vulnerability(
+                    "CRITICAL-1",
+                    Severity::Critical,
+                    Some(ImpactAnalysisState::NotAffected),
+                ),
+            ])),
+            ..Bom::default()
+        };
+        let sbom = GeneratedSbom {
+            bom,
+            manifest_path: PathBuf::from("Cargo.toml"),
+            package_name: "test".to_string(),
+            sbom_config: SbomConfig::empty_config(),
+        };
+
+        assert_eq!(
+            sbom.vulnerabilities_at_or_above(SeverityThreshold::High),
+            vec!["test: vulnerability HIGH-1 (high) affects serde@1.0.0".to_string()]
+        );
+        assert_eq!(
+            sbom.vulnerabilities_at_or_above(SeverityThreshold::Low)
+                .len(),
+            2
+        );
+    }
 }
@@ -19,6 +19,8 @@
 pub mod config;
 pub mod format;
 pub mod generator;
+pub mod license_detection;
+pub mod links;
 pub mod platform;
 pub mod purl;
 pub mod urlencode;
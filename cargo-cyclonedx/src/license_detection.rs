@@ -0,0 +1,214 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::models::license::{License, LicenseAcknowledgement, LicenseChoice};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Phrases that identify the text of a license, checked in order
+const LICENSE_PHRASES: &[(&str, &[&str])] = &[
+    ("Apache-2.0", &["Apache License", "Version 2.0"]),
+    ("MPL-2.0", &["Mozilla Public License Version 2.0"]),
+    ("MIT", &["Permission is hereby granted, free of charge"]),
+    (
+        "ISC",
+        &["Permission to use, copy, modify, and/or distribute this software"],
+    ),
+    ("Unlicense", &["This is free and unencumbered software"]),
+    (
+        "BSD-3-Clause",
+        &[
+            "Redistribution and use in source and binary forms",
+            "Neither the name",
+        ],
+    ),
+    (
+        "BSD-2-Clause",
+        &["Redistribution and use in source and binary forms"],
+    ),
+    (
+        "Zlib",
+        &["This software is provided 'as-is', without any express or implied"],
+    ),
+];
+
+/// Identifies the licenses of the `LICENSE*`, `LICENCE*` and `COPYING*` files in a package directory
+/// by well-known phrases of their text.
+/// Each one is returned as an SPDX license concluded from the sources.
+pub fn detect_licenses(package_dir: &Path) -> Vec<LicenseChoice> {
+    let entries = match std::fs::read_dir(package_dir) {
+        Ok(entries) => entries,
+        Err(error) => {
+            log::warn!(
+                "Failed to list the files of {} to detect licenses: {}",
+                package_dir.display(),
+                error
+            );
+            return Vec::new();
+        }
+    };
+
+    let mut ids = BTreeSet::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_uppercase();
+        if !["LICENSE", "LICENCE", "COPYING"]
+            .iter()
+            .any(|prefix| file_name.starts_with(prefix))
+        {
+            continue;
+        }
+
+        let text = match std::fs::read_to_string(entry.path()) {
+            Ok(text) => text,
+            Err(error) => {
+                log::warn!(
+                    "Failed to read the license file {}: {}",
+                    entry.path().display(),
+                    error
+                );
+                continue;
+            }
+        };
+
+        match identify_license(&text) {
+            Some(id) => {
+                ids.insert(id);
+            }
+            None => log::debug!(
+                "Could not identify the license in {}",
+                entry.path().display()
+            ),
+        }
+    }
+
+    ids.into_iter()
+        .filter_map(|id| License::license_id(id).ok())
+        .map(|mut license| {
+            license.acknowledgement = Some(LicenseAcknowledgement::Concluded);
+            LicenseChoice::License(license)
+        })
+        .collect()
+}
+
+fn identify_license(text: &str) -> Option<&'static str> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    LICENSE_PHRASES
+        .iter()
+        .find(|(_, phrases)| phrases.iter().all(|phrase| text.contains(phrase)))
+        .map(|(id, _)| *id)
+}
+
+/// Marks a license from the package manifest as declared by the authors of the crate.
+/// Only licenses, rather than expressions, can carry an acknowledgement,
+/// so an expression consisting of a single SPDX identifier is turned into a license.
+pub fn declared(license: LicenseChoice) -> LicenseChoice {
+    match license {
+        LicenseChoice::License(mut license) => {
+            license.acknowledgement = Some(LicenseAcknowledgement::Declared);
+            LicenseChoice::License(license)
+        }
+        LicenseChoice::Expression(expression) => {
+            match License::license_id(&expression.to_string()) {
+                Ok(mut license) => {
+                    license.acknowledgement = Some(LicenseAcknowledgement::Declared);
+                    LicenseChoice::License(license)
+                }
+                Err(_) => LicenseChoice::Expression(expression),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_fs::prelude::*;
+    use cyclonedx_bom::external_models::spdx::SpdxExpression;
+    use cyclonedx_bom::models::bom::{Bom, SpecVersion};
+    use cyclonedx_bom::models::component::{Classification, Component, Components};
+    use cyclonedx_bom::models::license::{LicenseIdentifier, Licenses};
+
+    #[test]
+    fn it_should_detect_licenses_by_their_text() {
+        let dir = assert_fs::TempDir::new().unwrap();
+        dir.child("LICENSE-APACHE")
+            .write_str("                                 Apache License\n                           Version 2.0, January 2004\n")
+            .unwrap();
+        dir.child("LICENSE-MIT")
+            .write_str("MIT License\n\nPermission is hereby granted, free of charge, to any person")
+            .unwrap();
+        dir.child("README.md")
+            .write_str("Permission is hereby granted, free of charge")
+            .unwrap();
+
+        let ids: Vec<String> = detect_licenses(dir.path())
+            .into_iter()
+            .map(|license| match license {
+                LicenseChoice::License(License {
+                    license_identifier: LicenseIdentifier::SpdxId(id),
+                    ..
+                }) => id.to_string(),
+                other => panic!("Expected an SPDX license, got {:?}", other),
+            })
+            .collect();
+
+        assert_eq!(ids, vec!["Apache-2.0", "MIT"]);
+    }
+
+    #[test]
+    fn it_should_acknowledge_declared_and_concluded_licenses_in_1_6() {
+        let dir = assert_fs::TempDir::new().unwrap();
+        dir.child("LICENSE")
+            .write_str("Apache License\nVersion 2.0, January 2004")
+            .unwrap();
+
+        let mut licenses = vec![declared(LicenseChoice::Expression(
+            SpdxExpression::try_from("MIT".to_string()).unwrap(),
+        ))];
+        licenses.extend(detect_licenses(dir.path()));
+
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.licenses = Some(Licenses(licenses));
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let output = bom.to_cyclonedx_json_bytes(SpecVersion::V1_6).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let licenses = &json["components"][0]["licenses"];
+        assert_eq!(licenses[0]["license"]["id"], "MIT");
+        assert_eq!(licenses[0]["license"]["acknowledgement"], "declared");
+        assert_eq!(licenses[1]["license"]["id"], "Apache-2.0");
+        assert_eq!(licenses[1]["license"]["acknowledgement"], "concluded");
+    }
+
+    #[test]
+    fn declared_compound_expressions_stay_expressions() {
+        let expression = SpdxExpression::try_from("MIT OR Apache-2.0".to_string()).unwrap();
+
+        assert_eq!(
+            declared(LicenseChoice::Expression(expression.clone())),
+            LicenseChoice::Expression(expression)
+        );
+    }
+}
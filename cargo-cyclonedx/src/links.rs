@@ -0,0 +1,50 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use cyclonedx_bom::models::bom::Bom;
+use std::time::Duration;
+
+/// Sends a HEAD request to the URL of each external reference in the SBOM and describes
+/// every reference that does not respond with `200 OK`, including those that cannot be reached.
+/// References that are not HTTP(S) URLs, e.g. `git+ssh`, are skipped.
+pub fn check_links(bom: &Bom) -> Vec<String> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(10))
+        .build();
+
+    let mut problems = Vec::new();
+    for (owner, reference) in bom.all_external_references() {
+        let url = reference.url.to_string();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            log::debug!("Skipping the non-HTTP link {}", url);
+            continue;
+        }
+
+        log::debug!("Checking the link {}", url);
+        let problem = match agent.head(&url).call() {
+            Ok(response) if response.status() == 200 => continue,
+            Ok(response) | Err(ureq::Error::Status(_, response)) => {
+                format!("{} {}", response.status(), response.status_text())
+            }
+            Err(ureq::Error::Transport(e)) => e.to_string(),
+        };
+
+        let owner = if owner.is_empty() { "the BOM" } else { owner };
+        problems.push(format!("Link {} of {} failed: {}", url, owner, problem));
+    }
+    problems
+}
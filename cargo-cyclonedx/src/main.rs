@@ -46,12 +46,17 @@
 * SOFTWARE.
 */
 use cargo_cyclonedx::{
-    config::{SbomConfig, Target},
+    config::{OutputDestination, SbomConfig, Target},
+    format::Format,
     generator::SbomGenerator,
+    links::check_links,
 };
 
+use cyclonedx_bom::models::bom::{Bom, ParseOptions, ParseWarning, SpecVersion};
+use cyclonedx_bom::validation::Validate;
+
 use std::{
-    io::{self},
+    io::{self, Write},
     path::{Path, PathBuf},
 };
 
@@ -69,6 +74,14 @@ fn main() -> anyhow::Result<()> {
     let Opts::Bom(args) = Opts::parse();
     setup_logging(&args)?;
 
+    if let Some(path) = &args.normalize {
+        return normalize_bom(path);
+    }
+
+    if let Some(path) = &args.validate {
+        return validate_bom(path);
+    }
+
     let cli_config = args.as_config()?;
     let manifest_path = locate_manifest(&args)?;
     log::debug!("Found the Cargo.toml file at {}", manifest_path.display());
@@ -82,11 +95,52 @@ fn main() -> anyhow::Result<()> {
     log::trace!("SBOM generation finished");
 
     log::trace!("SBOM output started");
-    for bom in boms {
-        bom.write_to_file()?;
+    let destination = cli_config.output_destination();
+    if destination == OutputDestination::Stdout && !args.check_links && boms.len() > 1 {
+        anyhow::bail!(
+            "Found {} packages, but only one SBOM can be written to stdout, \
+             see --aggregate-workspace",
+            boms.len()
+        );
+    }
+    let feed = match &args.vulnerabilities {
+        Some(path) => {
+            let (feed, _, _) = read_bom(path)?;
+            feed.vulnerabilities
+        }
+        None => None,
+    };
+    let mut vulnerabilities = Vec::new();
+    for mut bom in boms {
+        if let Some(feed) = &feed {
+            bom.add_vulnerabilities(feed);
+        }
+        if let Some(threshold) = cli_config.fail_on_vuln {
+            vulnerabilities.extend(bom.vulnerabilities_at_or_above(threshold));
+        }
+        if args.check_links {
+            for problem in check_links(&bom.bom) {
+                log::warn!("{}: {}", bom.package_name, problem);
+            }
+            continue;
+        }
+        match destination {
+            OutputDestination::File => bom.write_to_file()?,
+            OutputDestination::Stdout => bom.write_to_stdout()?,
+        }
     }
     log::trace!("SBOM output finished");
 
+    if !vulnerabilities.is_empty() {
+        for vulnerability in &vulnerabilities {
+            log::error!("{}", vulnerability);
+        }
+        anyhow::bail!(
+            "Found {} vulnerabilities at or above the severity threshold",
+            vulnerabilities.len()
+        );
+    }
+
     Ok(())
 }
 
@@ -160,3 +214,70 @@ fn get_metadata(
 
     Ok(cmd.exec()?)
 }
+
+/// Reads an existing BOM and writes it to stdout in canonical form,
+/// keeping its format and specification version
+fn normalize_bom(path: &Path) -> anyhow::Result<()> {
+    log::debug!("Normalizing the BOM at {}", path.display());
+    let (mut bom, version, format) = read_bom(path)?;
+    bom.normalize();
+
+    let output = match format {
+        Format::Xml => bom.to_cyclonedx_xml_bytes(version)?,
+        Format::Json => bom.to_cyclonedx_json_bytes(version)?,
+    };
+
+    io::stdout().write_all(&output)?;
+    Ok(())
+}
+
+fn validate_bom(path: &Path) -> anyhow::Result<()> {
+    log::debug!("Validating the BOM at {}", path.display());
+    let (bom, _, _) = read_bom(path)?;
+
+    if let Err(warnings) = bom.validate_contact_emails().into_result() {
+        for warning in &warnings {
+            log::warn!("{}", warning);
+        }
+    }
+
+    if let Err(reasons) = bom.validate()?.into_result() {
+        for reason in &reasons {
+            println!("{}", reason);
+        }
+        anyhow::bail!(
+            "{} is invalid: found {} problems",
+            path.display(),
+            reasons.len()
+        )
+    }
+    Ok(())
+}
+
+/// Reads a BOM in either format, detecting its format and version of the specification
+fn read_bom(path: &Path) -> anyhow::Result<(Bom, SpecVersion, Format)> {
+    let content = std::fs::read(path)?;
+
+    let is_xml = content
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .is_some_and(|&byte| byte == b'<');
+
+    let (parsed, format) = if is_xml {
+        let parsed = Bom::parse_from_xml_with_options(content.as_slice(), ParseOptions::default())?;
+        (parsed, Format::Xml)
+    } else {
+        let parsed =
+            Bom::parse_from_json_with_options(content.as_slice(), ParseOptions::default())?;
+        (parsed, Format::Json)
+    };
+    log_parse_warnings(path, &parsed.warnings);
+    Ok((parsed.bom, parsed.spec_version, format))
+}
+
+/// Logs what was accepted in a BOM without being quite right, shown with `--verbose`
+fn log_parse_warnings(path: &Path, warnings: &[ParseWarning]) {
+    for warning in warnings {
+        log::info!("{}: {}", path.display(), warning);
+    }
+}
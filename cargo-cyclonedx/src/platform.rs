@@ -15,6 +15,43 @@ pub fn rustc_location() -> OsString {
     std::env::var_os("RUSTC").unwrap_or("rustc".into())
 }
 
+/// The release and host target triple of a rustc, as reported by `rustc -vV`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustcVersion {
+    pub release: String,
+    pub host: String,
+}
+
+/// Describes the rustc at the given location, or returns `None` if it cannot be invoked
+pub fn rustc_version(rustc_path: &OsStr) -> Option<RustcVersion> {
+    let output = match Command::new(rustc_path).arg("-vV").output() {
+        Ok(output) if output.status.success() => output,
+        Ok(output) => {
+            log::warn!(
+                "Failed to determine the rustc version: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            return None;
+        }
+        Err(error) => {
+            log::warn!("Failed to invoke rustc to determine its version: {}", error);
+            return None;
+        }
+    };
+
+    let output = String::from_utf8_lossy(&output.stdout);
+    let field = |prefix: &str| {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(|value| value.trim().to_string())
+    };
+    Some(RustcVersion {
+        release: field("release: ")?,
+        host: field("host: ")?,
+    })
+}
+
 /// Returns the default target triple for the rustc we're running
 pub fn rustc_host_target_triple(rustc_path: &OsStr) -> String {
     // While this feels somewhat insane, this is how `cargo` determines the host platform
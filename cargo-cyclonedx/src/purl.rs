@@ -5,6 +5,7 @@ use cyclonedx_bom::prelude::Purl as CdxPurl;
 use pathdiff::diff_utf8_paths;
 use purl::{PackageError, PackageType, PurlBuilder};
 
+use crate::config::PurlQualifiers;
 use crate::urlencode::urlencode;
 
 pub fn get_purl(
@@ -12,10 +13,15 @@ pub fn get_purl(
     root_package: &Package,
     workspace_root: &Utf8Path,
     subpath: Option<&Utf8Path>,
+    qualifiers: &PurlQualifiers,
 ) -> Result<CdxPurl, PackageError> {
     let mut builder = PurlBuilder::new(PackageType::Cargo, &package.name)
         .with_version(package.version.to_string());
 
+    if let Some(group) = get_group(package) {
+        builder = builder.with_namespace(group);
+    }
+
     if let Some(source) = &package.source {
         if !source.is_crates_io() {
             match source.repr.split_once('+') {
@@ -54,6 +60,10 @@ pub fn get_purl(
         builder = builder.with_qualifier("download_url", urlencode(&manifest_url))?
     }
 
+    for (key, value) in &qualifiers.0 {
+        builder = builder.with_qualifier(key, value)?;
+    }
+
     if let Some(subpath) = subpath {
         builder = builder.with_subpath(to_purl_subpath(subpath));
     }
@@ -63,7 +73,36 @@ pub fn get_purl(
     if cfg!(debug_assertions) {
         assert_validation_passes(&cdx_purl);
     }
-    Ok(CdxPurl::from_str(&purl.to_string()).unwrap())
+    Ok(cdx_purl)
+}
+
+/// Derives the group of a package that is not published on crates.io from the organization
+/// owning its repository, e.g. `rust-secure-code` for `https://github.com/rust-secure-code/cargo-auditable`.
+///
+/// crates.io has a flat namespace, so its packages and local packages never have a group.
+pub fn get_group(package: &Package) -> Option<String> {
+    let source = package.source.as_ref()?;
+    if source.is_crates_io() {
+        return None;
+    }
+
+    let repository = match (&package.repository, source.repr.split_once('+')) {
+        (Some(repository), _) => repository.as_str(),
+        (None, Some(("git", git_url))) => git_url,
+        (None, _) => return None,
+    };
+    repository_organization(repository)
+}
+
+/// Extracts the first path segment of a repository URL with at least two path segments
+fn repository_organization(url: &str) -> Option<String> {
+    let (_scheme, rest) = url.split_once("://")?;
+    let path = rest.split(['?', '#']).next()?;
+    let mut segments = path.split('/').skip(1).filter(|s| !s.is_empty());
+    match (segments.next(), segments.next()) {
+        (Some(organization), Some(_repository)) => Some(organization.to_string()),
+        _ => None,
+    }
 }
 
 /// Converts the `cargo metadata`'s `source` field to a valid PURL `vcs_url`.
@@ -105,6 +144,7 @@ mod tests {
             &crates_io_package,
             Utf8Path::new("/foo/bar"),
             None,
+            &PurlQualifiers::default(),
         )
         .unwrap();
         // Validate that data roundtripped correctly
@@ -119,7 +159,14 @@ mod tests {
     #[test]
     fn git_purl() {
         let git_package: Package = serde_json::from_str(GIT_PACKAGE_JSON).unwrap();
-        let purl = get_purl(&git_package, &git_package, Utf8Path::new("/foo/bar"), None).unwrap();
+        let purl = get_purl(
+            &git_package,
+            &git_package,
+            Utf8Path::new("/foo/bar"),
+            None,
+            &PurlQualifiers::default(),
+        )
+        .unwrap();
         // Validate that data roundtripped correctly
         let parsed_purl = Purl::from_str(&purl.to_string()).unwrap();
         assert_eq!(parsed_purl.name(), "auditable-extract");
@@ -129,7 +176,25 @@ mod tests {
         assert_eq!(qualifier.as_str(), "vcs_url");
         assert_eq!(value, "git+https://github.com/rust-secure-code/cargo-auditable.git@da85607fb1a09435d77288ccf05a92b2e8ec3f71");
         assert!(parsed_purl.subpath().is_none());
-        assert!(parsed_purl.namespace().is_none());
+        assert_eq!(parsed_purl.namespace(), Some("rust-secure-code"));
+    }
+
+    #[test]
+    fn group_is_derived_from_repository() {
+        let git_package: Package = serde_json::from_str(GIT_PACKAGE_JSON).unwrap();
+        assert_eq!(get_group(&git_package).as_deref(), Some("rust-secure-code"));
+
+        let crates_io_package: Package = serde_json::from_str(CRATES_IO_PACKAGE_JSON).unwrap();
+        assert_eq!(get_group(&crates_io_package), None);
+
+        let root_package: Package = serde_json::from_str(ROOT_PACKAGE_JSON).unwrap();
+        assert_eq!(get_group(&root_package), None);
+
+        assert_eq!(
+            repository_organization("https://git.example.com/org/repo.git?branch=main"),
+            Some("org".to_string())
+        );
+        assert_eq!(repository_organization("https://example.com/repo"), None);
     }
 
     #[test]
@@ -140,6 +205,7 @@ mod tests {
             &root_package,
             Utf8Path::new("/home/shnatsel/Code/cargo-cyclonedx/"),
             None,
+            &PurlQualifiers::default(),
         )
         .unwrap();
         // Validate that data roundtripped correctly
@@ -163,6 +229,7 @@ mod tests {
             &root_package,
             Utf8Path::new("/home/shnatsel/Code/cargo-cyclonedx/"),
             Some("src/кириллица/lib.rs".into()),
+            &PurlQualifiers::default(),
         )
         .unwrap();
         // Validate that data roundtripped correctly
@@ -187,6 +254,7 @@ mod tests {
             &root_package,
             Utf8Path::new("/home/shnatsel/Code/cargo-cyclonedx/"),
             None,
+            &PurlQualifiers::default(),
         )
         .unwrap();
         // Validate that data roundtripped correctly
@@ -211,6 +279,7 @@ mod tests {
             &root_package,
             Utf8Path::new("/foo/bar/"),
             None,
+            &PurlQualifiers::default(),
         )
         .unwrap();
         // Validate that data roundtripped correctly
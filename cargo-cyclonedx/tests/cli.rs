@@ -74,6 +74,40 @@ fn find_content_in_bom_files() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn both_formats_are_written_in_one_run() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=xml,json")
+        .arg("--output-prefix=sbom");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir
+        .child("sbom.cdx.xml")
+        .assert(predicate::str::contains("<vendor>CycloneDX</vendor>"));
+    tmp_dir
+        .child("sbom.cdx.json")
+        .assert(predicate::str::contains(r#""vendor": "CycloneDX"#));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=xml,json")
+        .arg("--stdout");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Only one format can be written to stdout",
+    ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 #[test]
 #[ignore]
 fn find_content_in_stderr() -> Result<(), Box<dyn std::error::Error>> {
@@ -162,6 +196,1357 @@ fn bom_file_name_extension_is_prepended_with_cdx() -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+#[test]
+fn root_component_can_be_renamed() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--rename-root")
+        .arg("product");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+    let root_component = &bom["metadata"]["component"];
+    assert_eq!(root_component["name"], "product");
+
+    let root_ref = root_component["bom-ref"].as_str().unwrap();
+    assert!(root_ref.contains("product"));
+
+    let dependencies = bom["dependencies"].as_array().unwrap();
+    assert!(dependencies.iter().any(|d| d["ref"] == root_ref));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn renamed_root_component_derives_the_target_refs_from_its_name(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--rename-root")
+        .arg("product");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+    let root_component = &bom["metadata"]["component"];
+    let root_ref = root_component["bom-ref"].as_str().unwrap();
+
+    let targets = root_component["components"].as_array().unwrap();
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0]["type"], "application");
+    assert_eq!(targets[0]["name"], "pkg");
+    assert_eq!(
+        targets[0]["bom-ref"],
+        format!("{} bin-target-0", root_ref).as_str()
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn default_scope_can_be_set_to_optional() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let pkg_name = "nested-pkg";
+
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies.{0}]
+        path = "{0}"
+        "#,
+        pkg_name,
+    ))?;
+
+    let pkg_dir = tmp_dir.child(pkg_name);
+    pkg_dir.child("src/lib.rs").touch()?;
+    pkg_dir.child("Cargo.toml").write_str(&format!(
+        r#"package = {{ name = "{}", version = "0.0.0" }}"#,
+        pkg_name
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--default-scope")
+        .arg("optional");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+    let component = bom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == pkg_name)
+        .unwrap();
+    assert_eq!(component["scope"], "optional");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn optional_dependencies_enabled_by_requested_features_are_required(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies]
+        default-pkg = { path = "default-pkg", optional = true }
+        extra-pkg = { path = "extra-pkg", optional = true }
+
+        [features]
+        default = ["default-pkg"]
+        extra = ["dep:extra-pkg"]
+        "#,
+    )?;
+
+    for pkg_name in ["default-pkg", "extra-pkg"] {
+        let pkg_dir = tmp_dir.child(pkg_name);
+        pkg_dir.child("src/lib.rs").touch()?;
+        pkg_dir.child("Cargo.toml").write_str(&format!(
+            r#"package = {{ name = "{}", version = "0.0.0" }}"#,
+            pkg_name
+        ))?;
+    }
+
+    for feature_args in [vec!["--features", "extra"], vec!["--all-features"]] {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+        cmd.current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format")
+            .arg("json")
+            .args(&feature_args);
+
+        cmd.assert().success().stdout("");
+
+        let bom: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+        let scope_of = |name: &str| {
+            bom["components"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|c| c["name"] == name)
+                .map(|c| c["scope"].clone())
+                .unwrap()
+        };
+        assert_eq!(scope_of("default-pkg"), "required");
+        assert_eq!(scope_of("extra-pkg"), "required", "with {:?}", feature_args);
+    }
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn optional_dependencies_enabled_by_non_default_features_are_optional(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str(r#"workspace = { members = ["app", "lib"] }"#)?;
+
+    let app_dir = tmp_dir.child("app");
+    app_dir.child("src/main.rs").touch()?;
+    app_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "app"
+        version = "0.0.0"
+
+        [dependencies]
+        lib = { path = "../lib", features = ["extra"] }
+        "#,
+    )?;
+
+    let lib_dir = tmp_dir.child("lib");
+    lib_dir.child("src/lib.rs").touch()?;
+    lib_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "lib"
+        version = "0.0.0"
+
+        [dependencies]
+        extra-pkg = { path = "../extra-pkg", optional = true }
+
+        [features]
+        extra = ["dep:extra-pkg"]
+        "#,
+    )?;
+
+    let extra_dir = tmp_dir.child("extra-pkg");
+    extra_dir.child("src/lib.rs").touch()?;
+    extra_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "extra-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert().success().stdout("");
+
+    // The feature enabling `extra-pkg` is only requested by `app`, not by `lib` itself
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(lib_dir.child("bom.json").path())?)?;
+
+    let extra_pkg = bom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "extra-pkg")
+        .unwrap();
+    assert_eq!(extra_pkg["scope"], "optional");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn direct_dev_dependencies_are_kept_without_their_dependencies(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dev-dependencies]
+        dev-pkg = { path = "dev-pkg" }
+        "#,
+    )?;
+
+    let dev_pkg = tmp_dir.child("dev-pkg");
+    dev_pkg.child("src/lib.rs").touch()?;
+    dev_pkg.child("Cargo.toml").write_str(
+        r#"
+        package = { name = "dev-pkg", version = "0.0.0" }
+        dependencies = { transitive-pkg = { path = "../transitive-pkg" } }
+        "#,
+    )?;
+
+    let transitive_pkg = tmp_dir.child("transitive-pkg");
+    transitive_pkg.child("src/lib.rs").touch()?;
+    transitive_pkg
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "transitive-pkg", version = "0.0.0" }"#)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--all")
+        .arg("--format")
+        .arg("json")
+        .arg("--exclude-dev-transitive");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+    let components = bom["components"].as_array().unwrap();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0]["name"], "dev-pkg");
+    assert_eq!(components[0]["scope"], "optional");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn git_metadata_is_recorded() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .current_dir(tmp_dir.path())
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .assert()
+            .success()
+    };
+    git(&["init", "--quiet"]);
+    git(&["add", "."]);
+    git(&["commit", "--quiet", "--message", "Initial commit"]);
+    let sha = String::from_utf8(git(&["rev-parse", "HEAD"]).get_output().stdout.clone())?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format")
+        .arg("json")
+        .arg("--git-metadata");
+
+    cmd.assert().success().stdout("");
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+    let properties = bom["metadata"]["component"]["properties"]
+        .as_array()
+        .unwrap();
+    assert!(properties
+        .iter()
+        .any(|p| p["name"] == "cdx:cargo:vcs_revision" && p["value"] == sha.trim()));
+    assert!(properties
+        .iter()
+        .any(|p| p["name"] == "cdx:cargo:vcs_dirty"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn build_profile_is_recorded() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let profile_of = |args: &[&str]| -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format=json")
+            .args(args)
+            .assert()
+            .success();
+        let bom: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+        Ok(bom["metadata"]["component"]["properties"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["name"] == "cdx:cargo:profile")
+            .map(|p| p["value"].clone())
+            .unwrap())
+    };
+
+    assert_eq!(profile_of(&[])?, "release");
+    assert_eq!(profile_of(&["--profile", "dev"])?, "dev");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn rustc_version_is_recorded() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .assert()
+        .success();
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let property = |name: &str| {
+        bom["metadata"]["component"]["properties"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["name"] == name)
+            .and_then(|p| p["value"].as_str())
+            .map(|v| v.to_string())
+    };
+
+    let version = property("cdx:cargo:rustc_version").expect("Missing rustc version");
+    let numbers: Vec<&str> = version.split(['.', '-']).take(3).collect();
+    assert_eq!(numbers.len(), 3, "Implausible rustc version {}", version);
+    assert!(numbers.iter().all(|n| n.parse::<u32>().is_ok()));
+    assert!(property("cdx:cargo:rustc_host").is_some_and(|host| !host.is_empty()));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn broken_links_are_reported() -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut request_line = String::new();
+            let mut reader = BufReader::new(&stream);
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let status = match request_line.contains("/missing") {
+                true => "404 Not Found",
+                false => "200 OK",
+            };
+            let _ = write!(
+                &stream,
+                "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                status
+            );
+        }
+    });
+
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(&format!(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+        homepage = "http://{0}/missing"
+        documentation = "http://{0}/docs"
+        "#,
+        address
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--check-links");
+
+    cmd.assert()
+        .success()
+        .stderr(
+            predicate::str::contains(format!("http://{}/missing", address))
+                .and(predicate::str::contains(format!("http://{}/docs", address)).not()),
+        )
+        .stderr(predicate::str::contains("404"));
+
+    tmp_dir.child("bom.xml").assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn minimal_output_keeps_only_identifying_fields() -> Result<(), Box<dyn std::error::Error>> {
+    use cyclonedx_bom::{models::bom::Bom, validation::Validate, validation::ValidationResult};
+
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+        description = "The package"
+
+        [dependencies]
+        dep = { path = "dep" }
+        "#,
+    )?;
+
+    let dep = tmp_dir.child("dep");
+    dep.child("src/lib.rs").touch()?;
+    dep.child("Cargo.toml").write_str(
+        r#"package = { name = "dep", version = "0.1.0", description = "A dependency" }"#,
+    )?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--minimal");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    let component = &json["components"][0];
+    assert_eq!(component["name"], "dep");
+    assert!(component["purl"].is_string());
+    assert!(component.get("description").is_none());
+    assert!(component.get("licenses").is_none());
+    assert!(json["metadata"]["component"].get("description").is_none());
+    assert!(json["dependencies"].is_array());
+
+    let bom = Bom::parse_from_json_v1_3(content.as_slice())?;
+    assert_eq!(bom.validate()?, ValidationResult::Passed);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn normalizing_a_bom_is_idempotent() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("messy.json").write_str(
+        r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.3",
+  "version": 1,
+  "components": [
+    { "type": "library", "bom-ref": "b", "name": "zeta", "version": "1.0.0", "hashes": [] },
+    {
+      "type": "library",
+      "bom-ref": "a",
+      "name": "alpha",
+      "version": "2.0.0",
+      "purl": "pkg:cargo/alpha@2.0.0"
+    }
+  ],
+  "services": [],
+  "dependencies": [
+    { "ref": "b", "dependsOn": [] },
+    { "ref": "a", "dependsOn": ["b"] }
+  ]
+}"#,
+    )?;
+
+    let normalize = |path: &std::path::Path| -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let output = Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .arg("cyclonedx")
+            .arg("--normalize")
+            .arg(path)
+            .output()?;
+        assert!(output.status.success());
+        Ok(output.stdout)
+    };
+
+    let once = normalize(tmp_dir.child("messy.json").path())?;
+    tmp_dir.child("normalized.json").write_binary(&once)?;
+    let twice = normalize(tmp_dir.child("normalized.json").path())?;
+    assert_eq!(
+        String::from_utf8_lossy(&once),
+        String::from_utf8_lossy(&twice)
+    );
+
+    let bom: serde_json::Value = serde_json::from_slice(&once)?;
+    assert_eq!(bom["specVersion"], "1.3");
+    assert_eq!(bom["components"][0]["bom-ref"], "pkg:cargo/alpha@2.0.0");
+    assert_eq!(bom["components"][1]["bom-ref"], "zeta@1.0.0");
+    assert!(bom["components"][1].get("hashes").is_none());
+    assert!(bom.get("services").is_none());
+    assert_eq!(
+        bom["dependencies"][0]["dependsOn"][0],
+        serde_json::json!("zeta@1.0.0")
+    );
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn validating_an_invalid_bom_lists_the_problems() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("invalid.json").write_str(
+        r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "components": [
+    { "type": "library", "name": "bad", "cpe": "not a cpe", "purl": "not a purl" }
+  ]
+}"#,
+    )?;
+    tmp_dir.child("valid.xml").write_str(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1">
+  <components>
+    <component type="library">
+      <name>good</name>
+      <version>1.0.0</version>
+    </component>
+  </components>
+</bom>"#,
+    )?;
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .arg("cyclonedx")
+        .arg("--validate")
+        .arg(tmp_dir.child("invalid.json").path())
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Bom.components[0] > Component.cpe: Cpe does not conform to CPE spec",
+        ))
+        .stdout(predicate::str::contains(
+            "Bom.components[0] > Component.purl: Purl does not conform to Package URL spec",
+        ));
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .arg("cyclonedx")
+        .arg("--validate")
+        .arg(tmp_dir.child("valid.xml").path())
+        .assert()
+        .success()
+        .stdout("");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn validating_an_unreadable_xml_bom_reports_the_parse_error(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+
+    tmp_dir.child("unreadable.xml").write_str(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1">
+  <components>
+    <component type="library">
+      <name>bad</name>
+      <modified>notabool</modified>
+    </component>
+  </components>
+</bom>"#,
+    )?;
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+        .arg("cyclonedx")
+        .arg("--validate")
+        .arg(tmp_dir.child("unreadable.xml").path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Could not parse notabool as xs:boolean",
+        ));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn reproducible_runs_produce_identical_output() -> Result<(), Box<dyn std::error::Error>> {
+    let generate = || -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let tmp_dir = make_temp_rust_project()?;
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format=json")
+            .arg("--reproducible")
+            .env("SOURCE_DATE_EPOCH", "1700000000")
+            .assert()
+            .success();
+        let output = std::fs::read(tmp_dir.child("bom.json").path())?;
+        tmp_dir.close()?;
+        Ok(output)
+    };
+
+    let first = generate()?;
+    let second = generate()?;
+    assert_eq!(
+        String::from_utf8_lossy(&first),
+        String::from_utf8_lossy(&second)
+    );
+
+    let bom: serde_json::Value = serde_json::from_slice(&first)?;
+    assert_eq!(
+        bom["metadata"]["timestamp"],
+        "2023-11-14T22:13:20.000000000Z"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn content_serial_number_is_deterministic() -> Result<(), Box<dyn std::error::Error>> {
+    let generate = || -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let tmp_dir = make_temp_rust_project()?;
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))?
+            .current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format=json")
+            .arg("--serial-number=content")
+            .assert()
+            .success();
+        let bom = serde_json::from_slice(&std::fs::read(tmp_dir.child("bom.json").path())?)?;
+        tmp_dir.close()?;
+        Ok(bom)
+    };
+
+    let first = generate()?;
+    let second = generate()?;
+    assert!(first["serialNumber"].is_string());
+    assert_eq!(first["serialNumber"], second["serialNumber"]);
+
+    Ok(())
+}
+
+#[test]
+fn no_clobber_refuses_to_overwrite_an_existing_sbom() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("bom.json").write_str("{}")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--no-clobber");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Refusing to overwrite existing file",
+    ));
+
+    tmp_dir.child("bom.json").assert("{}");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn no_clobber_writes_no_format_if_one_of_the_files_exists() -> Result<(), Box<dyn std::error::Error>>
+{
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("bom.json").write_str("{}")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=xml,json")
+        .arg("--no-clobber");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Refusing to overwrite existing file",
+    ));
+
+    tmp_dir.child("bom.json").assert("{}");
+    tmp_dir.child("bom.xml").assert(predicate::path::missing());
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+/// A project depending on `dep`, and a VEX document listing vulnerabilities of `dep`
+fn make_temp_rust_project_with_vulnerabilities(
+    vulnerabilities: &str,
+) -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "pkg"
+        version = "0.0.0"
+
+        [dependencies]
+        dep = { path = "dep" }
+        "#,
+    )?;
+    let dep_dir = tmp_dir.child("dep");
+    dep_dir.child("src/lib.rs").touch()?;
+    dep_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "dep", version = "0.0.0" }"#)?;
+
+    tmp_dir.child("vex.json").write_str(&format!(
+        r#"{{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "version": 1,
+            "vulnerabilities": [{}]
+        }}"#,
+        vulnerabilities
+    ))?;
+
+    Ok(tmp_dir)
+}
+
+const HIGH_VULNERABILITY: &str = r#"{
+    "id": "CVE-2023-0001",
+    "ratings": [{ "severity": "high" }],
+    "affects": [{ "ref": "pkg:cargo/dep@0.0.0" }]
+}"#;
+
+const NOT_AFFECTED_VULNERABILITY: &str = r#"{
+    "id": "CVE-2023-0002",
+    "ratings": [{ "severity": "critical" }],
+    "analysis": { "state": "not_affected" },
+    "affects": [{ "ref": "pkg:cargo/dep@0.0.0" }]
+}"#;
+
+#[test]
+fn fail_on_vuln_fails_for_a_vulnerability_of_the_threshold_severity(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project_with_vulnerabilities(&format!(
+        "{},{}",
+        HIGH_VULNERABILITY, NOT_AFFECTED_VULNERABILITY
+    ))?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--spec-version=1.4")
+        .arg("--vulnerabilities=vex.json")
+        .arg("--fail-on-vuln=high");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "vulnerability CVE-2023-0001 (high) affects dep",
+        ))
+        .stderr(predicate::str::contains("CVE-2023-0002").not())
+        .stderr(predicate::str::contains(
+            "Found 1 vulnerabilities at or above the severity threshold",
+        ));
+
+    let bom: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+    let dep_ref = bom["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|c| c["name"] == "dep")
+        .map(|c| c["bom-ref"].clone())
+        .unwrap();
+    assert_eq!(bom["vulnerabilities"].as_array().unwrap().len(), 2);
+    assert_eq!(bom["vulnerabilities"][0]["affects"][0]["ref"], dep_ref);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn fail_on_vuln_ignores_vulnerabilities_not_affecting_the_components(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project_with_vulnerabilities(NOT_AFFECTED_VULNERABILITY)?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--vulnerabilities=vex.json")
+        .arg("--fail-on-vuln=high");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn license_detection_both_lists_manifest_and_detected_licenses(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str(r#"package = { name = "pkg", version = "0.0.0", license = "MIT" }"#)?;
+    tmp_dir
+        .child("LICENSE-APACHE")
+        .write_str("Apache License\nVersion 2.0, January 2004\n")?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--license-detection=both");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    let licenses = &json["metadata"]["component"]["licenses"];
+    assert_eq!(licenses[0]["license"]["id"], "MIT");
+    assert_eq!(licenses[1]["license"]["id"], "Apache-2.0");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn workspace_members_are_typed_by_their_targets() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str("[workspace]\nmembers = [\"lib-member\", \"bin-member\", \"mixed-member\"]\n")?;
+    tmp_dir
+        .child("lib-member/Cargo.toml")
+        .write_str(r#"package = { name = "lib-member", version = "0.0.0" }"#)?;
+    tmp_dir.child("lib-member/src/lib.rs").touch()?;
+    tmp_dir
+        .child("bin-member/Cargo.toml")
+        .write_str(r#"package = { name = "bin-member", version = "0.0.0" }"#)?;
+    tmp_dir.child("bin-member/src/main.rs").touch()?;
+    tmp_dir
+        .child("mixed-member/Cargo.toml")
+        .write_str(r#"package = { name = "mixed-member", version = "0.0.0" }"#)?;
+    tmp_dir.child("mixed-member/src/lib.rs").touch()?;
+    tmp_dir.child("mixed-member/src/main.rs").touch()?;
+
+    let member_type = |member: &str| -> Result<String, Box<dyn std::error::Error>> {
+        let content = std::fs::read(tmp_dir.child(member).child("bom.json").path())?;
+        let json: serde_json::Value = serde_json::from_slice(&content)?;
+        Ok(json["metadata"]["component"]["type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string())
+    };
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json");
+    cmd.assert().success().stdout("");
+
+    assert_eq!(member_type("lib-member")?, "library");
+    assert_eq!(member_type("bin-member")?, "application");
+    assert_eq!(member_type("mixed-member")?, "application");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--mixed-package-type=library");
+    cmd.assert().success().stdout("");
+
+    assert_eq!(member_type("lib-member")?, "library");
+    assert_eq!(member_type("bin-member")?, "application");
+    assert_eq!(member_type("mixed-member")?, "library");
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn build_url_adds_a_build_system_reference() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--build-url=https://ci.example.com/jobs/42")
+        .arg("--build-meta-url=https://ci.example.com/jobs/42/log");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    assert_eq!(
+        json["externalReferences"],
+        serde_json::json!([
+            {"type": "build-system", "url": "https://ci.example.com/jobs/42"},
+            {"type": "build-meta", "url": "https://ci.example.com/jobs/42/log"}
+        ])
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--build-url=not a url");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid URL"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn aggregate_workspace_nests_members_and_lists_shared_dependencies_once(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = assert_fs::TempDir::new()?;
+    // Outside of the workspace directory, so that it does not become a member
+    tmp_dir
+        .child("shared/Cargo.toml")
+        .write_str(r#"package = { name = "shared", version = "0.1.0" }"#)?;
+    tmp_dir.child("shared/src/lib.rs").touch()?;
+    let workspace = tmp_dir.child("ws");
+    workspace
+        .child("Cargo.toml")
+        .write_str("[workspace]\nmembers = [\"app-a\", \"app-b\"]\n")?;
+    for member in ["app-a", "app-b"] {
+        workspace
+            .child(member)
+            .child("Cargo.toml")
+            .write_str(&format!(
+                "package = {{ name = \"{}\", version = \"0.0.0\" }}\n\
+             dependencies = {{ shared = {{ path = \"../../shared\" }} }}\n",
+                member
+            ))?;
+        workspace.child(member).child("src/main.rs").touch()?;
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(workspace.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--aggregate-workspace");
+
+    cmd.assert().success().stdout("");
+
+    workspace
+        .child("app-a/bom.json")
+        .assert(predicate::path::missing());
+    workspace
+        .child("app-b/bom.json")
+        .assert(predicate::path::missing());
+
+    let content = std::fs::read(workspace.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    let root = &json["metadata"]["component"];
+    assert_eq!(root["name"], "ws");
+    let members: Vec<_> = root["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| (c["name"].as_str().unwrap(), c["type"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        members,
+        vec![("app-a", "application"), ("app-b", "application")]
+    );
+
+    let components: Vec<_> = json["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(components, vec!["shared"]);
+
+    let root_dependencies = json["dependencies"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["ref"] == root["bom-ref"])
+        .unwrap();
+    assert_eq!(root_dependencies["dependsOn"].as_array().unwrap().len(), 2);
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn lifecycles_are_recorded_in_a_1_5_bom() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--lifecycle")
+        .arg("build")
+        .arg("--lifecycle")
+        .arg("operations");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    assert_eq!(json["specVersion"], "1.5");
+    assert_eq!(
+        json["metadata"]["lifecycles"],
+        serde_json::json!([{"phase": "build"}, {"phase": "operations"}])
+    );
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--lifecycle=testing");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid lifecycle"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn spec_version_selects_the_version_written() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--spec-version=1.4")
+        .arg("--format=json");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    assert_eq!(json["specVersion"], "1.4");
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--spec-version=1.5");
+
+    cmd.assert().success().stdout("");
+
+    tmp_dir.child("bom.xml").assert(predicate::str::contains(
+        "http://cyclonedx.org/schema/bom/1.5",
+    ));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--spec-version=1.4")
+        .arg("--lifecycle=build");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("lifecycles require version 1.5"));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--spec-version=1.2");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unsupported Spec Version '1.2'"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn sbom_can_be_written_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--stdout")
+        .arg("--format=json")
+        .arg("-vv");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(json["metadata"]["component"]["name"], "pkg");
+    tmp_dir.child("bom.json").assert(predicate::path::missing());
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--stdout")
+        .arg("--quiet");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("<?xml"))
+        .stderr("");
+
+    tmp_dir.close()?;
+
+    let tmp_dir = assert_fs::TempDir::new()?;
+    tmp_dir
+        .child("Cargo.toml")
+        .write_str("[workspace]\nmembers = [\"a\", \"b\"]\n")?;
+    for member in ["a", "b"] {
+        tmp_dir
+            .child(member)
+            .child("Cargo.toml")
+            .write_str(&format!(
+                r#"package = {{ name = "{}", version = "0.0.0" }}"#,
+                member
+            ))?;
+        tmp_dir.child(member).child("src/lib.rs").touch()?;
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--stdout");
+
+    cmd.assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains(
+            "only one SBOM can be written to stdout",
+        ));
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--stdout")
+        .arg("--aggregate-workspace");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("<?xml"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn purl_qualifiers_are_added_to_every_purl() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--format=json")
+        .arg("--purl-qualifiers=distro=internal")
+        .arg("--purl-qualifiers=channel=stable");
+
+    cmd.assert().success().stdout("");
+
+    let content = std::fs::read(tmp_dir.child("bom.json").path())?;
+    let json: serde_json::Value = serde_json::from_slice(&content)?;
+    let root = &json["metadata"]["component"];
+    let purls: Vec<&str> = std::iter::once(root)
+        .chain(root["components"].as_array().unwrap())
+        .map(|c| c["purl"].as_str().unwrap())
+        .collect();
+    assert_eq!(purls.len(), 2);
+    for purl in purls {
+        let query = purl.split_once('?').unwrap().1;
+        let query = query.split('#').next().unwrap();
+        let qualifiers: Vec<&str> = query
+            .split('&')
+            .map(|q| q.split('=').next().unwrap())
+            .collect();
+        assert_eq!(
+            qualifiers,
+            vec!["channel", "distro", "download_url"],
+            "{}",
+            purl
+        );
+        assert!(
+            query.starts_with("channel=stable&distro=internal&"),
+            "{}",
+            purl
+        );
+    }
+
+    let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+    cmd.current_dir(tmp_dir.path())
+        .arg("cyclonedx")
+        .arg("--purl-qualifiers=download_url=https://example.com");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid purl qualifier"));
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn proc_macros_are_excluded_unless_included() -> Result<(), Box<dyn std::error::Error>> {
+    let tmp_dir = make_temp_rust_project()?;
+
+    tmp_dir.child("Cargo.toml").write_str(
+        r#"
+        [package]
+        name = "test"
+        version = "0.0.0"
+
+        [dependencies]
+        macro-pkg = { path = "macro-pkg" }
+        "#,
+    )?;
+
+    let macro_pkg = tmp_dir.child("macro-pkg");
+    macro_pkg.child("src/lib.rs").touch()?;
+    macro_pkg.child("Cargo.toml").write_str(
+        r#"
+        package = { name = "macro-pkg", version = "0.0.0" }
+        lib = { proc-macro = true }
+        "#,
+    )?;
+
+    for (include_proc_macros, scope, properties) in [(false, "excluded", 1), (true, "required", 0)]
+    {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))?;
+
+        cmd.current_dir(tmp_dir.path())
+            .arg("cyclonedx")
+            .arg("--format")
+            .arg("json");
+        if include_proc_macros {
+            cmd.arg("--include-proc-macros");
+        }
+
+        cmd.assert().success().stdout("");
+
+        let bom: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(tmp_dir.child("bom.json").path())?)?;
+
+        let component = &bom["components"][0];
+        assert_eq!(component["name"], "macro-pkg");
+        assert_eq!(component["scope"], scope);
+        let proc_macro_properties: Vec<_> = component["properties"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|p| p["name"] == "cdx:cargo:proc_macro" && p["value"] == "true")
+            .collect();
+        assert_eq!(proc_macro_properties.len(), properties);
+    }
+
+    tmp_dir.close()?;
+
+    Ok(())
+}
+
 fn make_temp_rust_project() -> Result<assert_fs::TempDir, assert_fs::fixture::FixtureError> {
     let tmp_dir = assert_fs::TempDir::new()?;
     tmp_dir.child("src/main.rs").touch()?;
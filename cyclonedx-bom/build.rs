@@ -0,0 +1,55 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::{env, fs, path::Path};
+
+const SPDX_LICENSE_LIST: &str = "spdx-license-list.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", SPDX_LICENSE_LIST);
+
+    if env::var_os("CARGO_FEATURE_SPDX_LICENSE_LIST").is_some() {
+        generate_spdx_license_list();
+    }
+}
+
+/// Writes the identifiers of the input file as a sorted static slice, so that they can be looked
+/// up with a binary search
+fn generate_spdx_license_list() {
+    let input = fs::read_to_string(SPDX_LICENSE_LIST)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", SPDX_LICENSE_LIST, e));
+
+    let mut ids: Vec<&str> = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut output = String::from("pub(crate) static SPDX_LICENSE_IDS: &[&str] = &[\n");
+    for id in ids {
+        output.push_str(&format!("    {:?},\n", id));
+    }
+    output.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    fs::write(Path::new(&out_dir).join("spdx_license_list.rs"), output)
+        .expect("Failed to write the SPDX license list");
+}
@@ -30,8 +30,23 @@ pub enum BomError {
     #[error("Failed to serialize BOM with version {0:?}: {1}")]
     BomSerializationError(SpecVersion, String),
 
-    #[error("Unsupported Spec Version '{0}'")]
+    #[error(
+        "Unsupported Spec Version '{0}', supported are {}",
+        SpecVersion::ALL.map(|version| version.to_string()).join(", ")
+    )]
     UnsupportedSpecVersion(String),
+
+    #[error("Unsupported BOM format '{0}', expected 'CycloneDX'")]
+    UnsupportedBomFormat(String),
+
+    #[error("Serial number '{0}' is not a urn:uuid")]
+    InvalidSerialNumber(String),
+
+    #[error("{0} '{1}' is not an ISO 8601 date-time")]
+    InvalidDateTime(String, String),
+
+    #[error("Unknown field '{0}'")]
+    UnknownField(String),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -78,41 +93,174 @@ pub enum JsonReadError {
         #[from]
         error: BomError,
     },
+    #[error("Components or services are nested {depth} levels deep, more than allowed")]
+    MaxDepthExceeded { depth: usize },
+}
+
+/// Where in an XML document an error was found
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct XmlPosition {
+    /// The line, counting from 1
+    pub line: u64,
+    /// The column, counting from 1
+    pub column: u64,
+}
+
+impl From<xml::common::TextPosition> for XmlPosition {
+    fn from(position: xml::common::TextPosition) -> Self {
+        Self {
+            line: position.row + 1,
+            column: position.column + 1,
+        }
+    }
+}
+
+impl std::fmt::Display for XmlPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Renders the position as a suffix of the error message, if it is known
+fn at(position: &Option<XmlPosition>) -> String {
+    position
+        .map(|position| format!(" at {position}"))
+        .unwrap_or_default()
+}
+
+/// The message of an error of the XML reader, without the position it already includes
+fn reader_error_message(error: &xml::reader::Error) -> String {
+    match error.kind() {
+        xml::reader::ErrorKind::Syntax(message) => message.to_string(),
+        xml::reader::ErrorKind::Io(error) => error.to_string(),
+        xml::reader::ErrorKind::Utf8(error) => error.to_string(),
+        xml::reader::ErrorKind::UnexpectedEof => "Unexpected EOF".to_string(),
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
 pub enum XmlReadError {
-    #[error("Failed to deserialize XML while reading {element}: {error}")]
+    #[error("Failed to deserialize XML while reading {element}: {}{}", reader_error_message(.error), at(.position))]
     ElementReadError {
         #[source]
         error: xml::reader::Error,
         element: String,
+        position: Option<XmlPosition>,
+    },
+    #[error("Got unexpected XML element when reading {element}: {error}{}", at(.position))]
+    UnexpectedElementReadError {
+        error: String,
+        element: String,
+        position: Option<XmlPosition>,
     },
-    #[error("Got unexpected XML element when reading {element}: {error}")]
-    UnexpectedElementReadError { error: String, element: String },
 
-    #[error("Ended element {element} without data for required field {required_field}")]
+    #[error("Ended element {element} without data for required field {required_field}{}", at(.position))]
     RequiredDataMissing {
         required_field: String,
         element: String,
+        position: Option<XmlPosition>,
     },
 
-    #[error("Invalid enum value '{value}' given in {element}")]
-    InvalidEnumVariant { value: String, element: String },
+    #[error("Invalid enum value '{value}' given in {element}{}", at(.position))]
+    InvalidEnumVariant {
+        value: String,
+        element: String,
+        position: Option<XmlPosition>,
+    },
 
-    #[error("Could not parse {value} as {data_type} on {element}")]
+    #[error("Could not parse {value} as {data_type} on {element}{}", at(.position))]
     InvalidParseError {
         value: String,
         data_type: String,
         element: String,
+        position: Option<XmlPosition>,
     },
 
     #[error(
-        "Expected document to be in the form {expected_namespace}, but received {}", .actual_namespace.as_ref().unwrap_or(&"no CycloneDX namespace".to_string())
+        "Expected document to be in the form {expected_namespace}, but received {}{}", .actual_namespace.as_ref().unwrap_or(&"no CycloneDX namespace".to_string()), at(.position)
     )]
     InvalidNamespaceError {
         expected_namespace: String,
         actual_namespace: Option<String>,
+        position: Option<XmlPosition>,
+    },
+
+    #[error("Got unknown element or attribute {path}{}", at(.position))]
+    UnknownContentError {
+        path: String,
+        position: Option<XmlPosition>,
+    },
+    #[error("Document exceeds the limit of {limit}{}", at(.position))]
+    LimitExceeded {
+        limit: String,
+        position: Option<XmlPosition>,
+    },
+
+    #[error("Components or services are nested {depth} levels deep, more than allowed{}", at(.position))]
+    MaxDepthExceeded {
+        depth: usize,
+        position: Option<XmlPosition>,
+    },
+}
+
+impl XmlReadError {
+    /// Where in the document the error was found, if it is known
+    pub fn position(&self) -> Option<XmlPosition> {
+        match self {
+            Self::ElementReadError { position, .. }
+            | Self::UnexpectedElementReadError { position, .. }
+            | Self::RequiredDataMissing { position, .. }
+            | Self::InvalidEnumVariant { position, .. }
+            | Self::InvalidParseError { position, .. }
+            | Self::InvalidNamespaceError { position, .. }
+            | Self::UnknownContentError { position, .. }
+            | Self::LimitExceeded { position, .. }
+            | Self::MaxDepthExceeded { position, .. } => *position,
+        }
+    }
+
+    /// Records the position unless the error already has one, e.g. the position the reader
+    /// stopped at when the error was returned
+    pub(crate) fn or_position(mut self, position: impl Into<XmlPosition>) -> Self {
+        self.position_mut().get_or_insert(position.into());
+        self
+    }
+
+    fn position_mut(&mut self) -> &mut Option<XmlPosition> {
+        match self {
+            Self::ElementReadError { position, .. }
+            | Self::UnexpectedElementReadError { position, .. }
+            | Self::RequiredDataMissing { position, .. }
+            | Self::InvalidEnumVariant { position, .. }
+            | Self::InvalidParseError { position, .. }
+            | Self::InvalidNamespaceError { position, .. }
+            | Self::UnknownContentError { position, .. }
+            | Self::LimitExceeded { position, .. }
+            | Self::MaxDepthExceeded { position, .. } => position,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum BomReadError {
+    #[error("Failed to read input: {error}")]
+    InputReadError {
+        #[from]
+        error: std::io::Error,
+    },
+    #[error("Expected a JSON or XML document, but the input starts with {found}")]
+    UnknownFormatError { found: String },
+
+    #[error(transparent)]
+    JsonReadError {
+        #[from]
+        error: JsonReadError,
+    },
+    #[error(transparent)]
+    XmlReadError {
+        #[from]
+        error: XmlReadError,
     },
 }
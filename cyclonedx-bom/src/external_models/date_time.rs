@@ -17,6 +17,8 @@
  */
 
 use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
 
 use thiserror::Error;
 use time::{format_description::well_known::Iso8601, OffsetDateTime};
@@ -50,19 +52,46 @@ impl DateTime {
             .map_err(|_| DateTimeError::FailedCurrentTime)?;
         Ok(Self(now))
     }
+
+    /// Wraps the value without checking it, e.g. to keep what a document contains
+    /// when reading it leniently. [Validate] reports it if it is invalid.
+    pub fn new_unchecked(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// The point in time the timestamp stands for
+    pub fn to_offset_date_time(&self) -> Result<OffsetDateTime, DateTimeError> {
+        OffsetDateTime::parse(&self.0, &Iso8601::DEFAULT).map_err(|e| {
+            DateTimeError::InvalidDateTime(format!("DateTime does not conform to ISO 8601: {}", e))
+        })
+    }
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch,
+    /// e.g. the value of `SOURCE_DATE_EPOCH` in reproducible builds
+    pub fn from_unix_timestamp(seconds: i64) -> Result<Self, DateTimeError> {
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .map_err(|e| DateTimeError::InvalidDateTime(e.to_string()))?
+            .format(&Iso8601::DEFAULT)
+            .map(Self)
+            .map_err(|e| DateTimeError::InvalidDateTime(e.to_string()))
+    }
 }
 
 impl TryFrom<String> for DateTime {
     type Error = DateTimeError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match OffsetDateTime::parse(&value, &Iso8601::DEFAULT) {
-            Ok(_) => Ok(Self(value)),
-            Err(e) => Err(DateTimeError::InvalidDateTime(format!(
-                "DateTime does not conform to ISO 8601: {}",
-                e
-            ))),
-        }
+        let date_time = Self(value);
+        date_time.to_offset_date_time()?;
+        Ok(date_time)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.to_string())
     }
 }
 
@@ -71,7 +100,7 @@ impl Validate for DateTime {
         &self,
         context: ValidationContext,
     ) -> Result<ValidationResult, ValidationError> {
-        match OffsetDateTime::parse(&self.0.to_string(), &Iso8601::DEFAULT) {
+        match self.to_offset_date_time() {
             Ok(_) => Ok(ValidationResult::Passed),
             Err(_) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
@@ -83,9 +112,9 @@ impl Validate for DateTime {
     }
 }
 
-impl ToString for DateTime {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -113,6 +142,30 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed)
     }
 
+    #[test]
+    fn it_should_convert_to_the_point_in_time() {
+        let date_time = DateTime::from_str("2023-11-14T23:13:20+01:00").expect("Invalid timestamp");
+
+        assert_eq!(
+            date_time.to_offset_date_time().map(|d| d.unix_timestamp()),
+            Ok(1_700_000_000)
+        );
+        assert_eq!(date_time.to_string(), "2023-11-14T23:13:20+01:00");
+        assert!(DateTime::from_str("14/11/2023").is_err());
+
+        let unchecked = DateTime::new_unchecked("14/11/2023");
+        assert_eq!(unchecked.to_string(), "14/11/2023");
+        assert!(unchecked.to_offset_date_time().is_err());
+    }
+
+    #[test]
+    fn it_should_create_a_datetime_from_a_unix_timestamp() {
+        let date_time = DateTime::from_unix_timestamp(1_700_000_000).expect("Invalid timestamp");
+
+        assert_eq!(date_time.to_string(), "2023-11-14T22:13:20.000000000Z");
+        assert!(DateTime::from_unix_timestamp(i64::MAX).is_err());
+    }
+
     #[test]
     fn invalid_datetimes_should_fail_validation() {
         let validation_result = DateTime("invalid date".to_string())
@@ -36,12 +36,27 @@ impl NormalizedString {
     /// let normalized_string = NormalizedString::new("A\r\nstring\rwith\ninvalid\tcharacters");
     /// assert_eq!(normalized_string.to_string(), "A string with invalid characters".to_string());
     /// ```
-    pub fn new(value: &str) -> Self {
-        let value = value.replace("\r\n", " ").replace(['\r', '\n', '\t'], " ");
+    pub fn new(value: impl AsRef<str>) -> Self {
+        let value = value
+            .as_ref()
+            .replace("\r\n", " ")
+            .replace(['\r', '\n', '\t'], " ");
         NormalizedString(value)
     }
 
+    /// Compares two strings after fully normalizing them, i.e. after also collapsing runs of
+    /// whitespace into a single space and trimming leading and trailing whitespace
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    ///
+    /// assert!(NormalizedString::new("a  b ").normalized_eq(&NormalizedString::new("a b")));
+    /// ```
+    pub fn normalized_eq(&self, other: &NormalizedString) -> bool {
+        self.0.split_whitespace().eq(other.0.split_whitespace())
+    }
+
     /// Allow for the existence of invalid inputs from other data sources
+    #[cfg(test)]
     pub(crate) fn new_unchecked(value: String) -> Self {
         NormalizedString(value)
     }
@@ -112,6 +127,13 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_compare_strings_ignoring_whitespace_differences() {
+        assert!(NormalizedString::new("a  b").normalized_eq(&NormalizedString::new("a b")));
+        assert!(NormalizedString::new(" a\tb ").normalized_eq(&NormalizedString::new("a b")));
+        assert!(!NormalizedString::new("ab").normalized_eq(&NormalizedString::new("a b")));
+    }
+
     #[test]
     fn it_should_pass_validation() {
         let validation_result = NormalizedString("no_whitespace".to_string())
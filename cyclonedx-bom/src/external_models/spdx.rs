@@ -18,11 +18,20 @@
 
 use std::convert::TryFrom;
 
-use spdx::{Expression, ParseMode};
+use spdx::{
+    expression::{ExprNode, Operator},
+    Expression, ParseError, ParseMode,
+};
 use thiserror::Error;
 
 use crate::validation::{FailureReason, Validate, ValidationResult};
 
+/// The license identifiers of `spdx-license-list.txt`, generated by the build script
+#[cfg(feature = "spdx-license-list")]
+mod license_list {
+    include!(concat!(env!("OUT_DIR"), "/spdx_license_list.rs"));
+}
+
 /// An identifier for a single, specific license
 ///
 /// The list of valid SPDX license identifiers can be found on the [SPDX website](https://spdx.org/licenses/)
@@ -58,6 +67,24 @@ impl SpdxIdentifier {
             )),
         }
     }
+
+    /// Whether the identifier is on the SPDX license list embedded with the `spdx-license-list`
+    /// feature, which is useful for identifiers that were read from a document without being
+    /// checked
+    ///
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// let spdx_identifier = SpdxIdentifier::try_from("MIT".to_string()).unwrap();
+    /// assert!(spdx_identifier.is_known());
+    /// ```
+    #[cfg(feature = "spdx-license-list")]
+    pub fn is_known(&self) -> bool {
+        license_list::SPDX_LICENSE_IDS
+            .binary_search(&self.0.as_str())
+            .is_ok()
+    }
 }
 
 impl TryFrom<String> for SpdxIdentifier {
@@ -85,9 +112,14 @@ impl Validate for SpdxIdentifier {
         &self,
         context: crate::validation::ValidationContext,
     ) -> Result<ValidationResult, crate::validation::ValidationError> {
-        match Self::try_from(self.0.clone()) {
-            Ok(_) => Ok(ValidationResult::Passed),
-            Err(_) => Ok(ValidationResult::Failed {
+        #[cfg(feature = "spdx-license-list")]
+        let known = self.is_known();
+        #[cfg(not(feature = "spdx-license-list"))]
+        let known = spdx::license_id(&self.0).is_some();
+
+        match known {
+            true => Ok(ValidationResult::Passed),
+            false => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
                     message: "SPDX identifier is not valid".to_string(),
                     context,
@@ -140,10 +172,71 @@ impl SpdxExpression {
     pub fn parse_lax(value: String) -> Result<Self, SpdxExpressionError> {
         match Expression::parse_mode(&value, ParseMode::LAX) {
             Ok(_) => Self(value).convert_lax(),
-            Err(e) => Err(SpdxExpressionError::InvalidLaxSpdxExpression(format!(
-                "{}",
-                e.reason
-            ))),
+            Err(e) => Err(SpdxExpressionError::InvalidLaxSpdxExpression(
+                describe_parse_error(&e),
+            )),
+        }
+    }
+
+    /// Parse an expression into its tree of licenses and operators, reporting the
+    /// offending token if it is not valid
+    /// ```
+    /// use cyclonedx_bom::external_models::spdx::{ExpressionTree, SpdxExpression};
+    ///
+    /// let tree = SpdxExpression::parse("MIT OR (Apache-2.0 AND ISC)").unwrap();
+    /// assert!(matches!(tree, ExpressionTree::Or(operands) if operands.len() == 2));
+    ///
+    /// let error = SpdxExpression::parse("MIT OR OR ISC").unwrap_err();
+    /// assert!(error.to_string().contains("'OR' at position 7"));
+    /// ```
+    pub fn parse(value: &str) -> Result<ExpressionTree, SpdxExpressionError> {
+        let expression = Expression::parse(value)
+            .map_err(|e| SpdxExpressionError::InvalidSpdxExpression(describe_parse_error(&e)))?;
+        ExpressionTree::from_expression(value, &expression).ok_or_else(|| {
+            SpdxExpressionError::InvalidSpdxExpression("unbalanced operators".to_string())
+        })
+    }
+
+    /// Rewrite the expression into a canonical form, so that equivalent expressions
+    /// compare equal: the operands of `AND` and `OR` are sorted and deduplicated,
+    /// redundant parentheses are removed and whitespace is normalized.
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    /// use std::convert::TryFrom;
+    ///
+    /// let left = SpdxExpression::try_from("MIT OR Apache-2.0".to_string())?;
+    /// let right = SpdxExpression::try_from("Apache-2.0 OR MIT".to_string())?;
+    /// assert_eq!(left.canonicalize(), right.canonicalize());
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn canonicalize(&self) -> SpdxExpression {
+        match Self::parse(&self.0) {
+            Ok(tree) => Self(tree.render_canonical()),
+            Err(_) => self.clone(),
+        }
+    }
+
+    /// Whether the given license is one of the licenses the expression refers to,
+    /// irrespective of the operators combining them
+    /// ```
+    /// use cyclonedx_bom::prelude::*;
+    /// # use cyclonedx_bom::external_models::spdx::SpdxExpressionError;
+    /// use std::convert::TryFrom;
+    ///
+    /// let expression = SpdxExpression::try_from("GPL-3.0-only OR MIT".to_string())?;
+    /// let gpl = SpdxIdentifier::try_from("GPL-3.0-only".to_string()).unwrap();
+    /// assert!(expression.contains_identifier(&gpl));
+    /// # Ok::<(), SpdxExpressionError>(())
+    /// ```
+    pub fn contains_identifier(&self, identifier: &SpdxIdentifier) -> bool {
+        match Expression::parse_mode(&self.0, ParseMode::LAX) {
+            Ok(expression) => expression.requirements().any(|req| {
+                self.0[req.span.start as usize..req.span.end as usize]
+                    .trim()
+                    .eq_ignore_ascii_case(&identifier.0)
+            }),
+            Err(_) => false,
         }
     }
 
@@ -160,16 +253,108 @@ impl SpdxExpression {
     }
 }
 
+/// A license expression parsed by [SpdxExpression::parse], in which nested operations
+/// of the same kind are flattened, e.g. `A AND (B AND C)` has three operands
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionTree {
+    /// A license as written in the expression, with the exception following `WITH`, if any
+    License {
+        license: String,
+        exception: Option<String>,
+    },
+    And(Vec<ExpressionTree>),
+    Or(Vec<ExpressionTree>),
+}
+
+impl ExpressionTree {
+    /// Builds the tree from the postfix nodes of a parsed expression
+    fn from_expression(source: &str, expression: &Expression) -> Option<Self> {
+        let mut stack: Vec<ExpressionTree> = Vec::new();
+        for node in expression.iter() {
+            match node {
+                ExprNode::Req(req) => {
+                    // The span only covers the license, not its exception
+                    let license = source[req.span.start as usize..req.span.end as usize]
+                        .trim()
+                        .to_string();
+                    let exception = req.req.exception.map(|e| e.name.to_string());
+                    stack.push(ExpressionTree::License { license, exception });
+                }
+                ExprNode::Op(operator) => {
+                    let right = stack.pop()?;
+                    let left = stack.pop()?;
+                    let mut operands = Vec::new();
+                    for operand in [left, right] {
+                        match (operator, operand) {
+                            (Operator::And, ExpressionTree::And(nested))
+                            | (Operator::Or, ExpressionTree::Or(nested)) => operands.extend(nested),
+                            (_, operand) => operands.push(operand),
+                        }
+                    }
+                    stack.push(match operator {
+                        Operator::And => ExpressionTree::And(operands),
+                        Operator::Or => ExpressionTree::Or(operands),
+                    });
+                }
+            }
+        }
+
+        match stack.pop() {
+            Some(tree) if stack.is_empty() => Some(tree),
+            _ => None,
+        }
+    }
+
+    /// Renders the tree with sorted, unique operands, only adding the parentheses
+    /// that are needed because `AND` binds more tightly than `OR`
+    fn render_canonical(&self) -> String {
+        let (operands, separator) = match self {
+            Self::License {
+                license,
+                exception: Some(exception),
+            } => return format!("{} WITH {}", license, exception),
+            Self::License { license, .. } => return license.clone(),
+            Self::And(operands) => (operands, " AND "),
+            Self::Or(operands) => (operands, " OR "),
+        };
+        let mut rendered: Vec<String> = operands
+            .iter()
+            .map(|operand| match (self, operand) {
+                (Self::And(_), Self::Or(_)) => format!("({})", operand.render_canonical()),
+                _ => operand.render_canonical(),
+            })
+            .collect();
+        rendered.sort();
+        rendered.dedup();
+        rendered.join(separator)
+    }
+}
+
+/// The reason along with the offending token and its position in the expression
+fn describe_parse_error(error: &ParseError) -> String {
+    let token = error
+        .original
+        .get(error.span.clone())
+        .unwrap_or_default()
+        .trim();
+    match token {
+        "" => format!("{} at position {}", error.reason, error.span.start),
+        token => format!(
+            "{}: '{}' at position {}",
+            error.reason, token, error.span.start
+        ),
+    }
+}
+
 impl TryFrom<String> for SpdxExpression {
     type Error = SpdxExpressionError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match Expression::parse(&value) {
             Ok(_) => Ok(Self(value)),
-            Err(e) => Err(SpdxExpressionError::InvalidSpdxExpression(format!(
-                "{}",
-                e.reason
-            ))),
+            Err(e) => Err(SpdxExpressionError::InvalidSpdxExpression(
+                describe_parse_error(&e),
+            )),
         }
     }
 }
@@ -234,6 +419,15 @@ mod test {
         );
     }
 
+    #[cfg(feature = "spdx-license-list")]
+    #[test]
+    fn it_should_only_know_identifiers_on_the_spdx_license_list() {
+        assert!(SpdxIdentifier("MIT".to_string()).is_known());
+        assert!(SpdxIdentifier("Apache-2.0".to_string()).is_known());
+        assert!(!SpdxIdentifier("MITT".to_string()).is_known());
+        assert!(!SpdxIdentifier("mit".to_string()).is_known());
+    }
+
     #[test]
     fn it_should_succeed_in_converting_an_imprecise_spdx_identifier() {
         let actual =
@@ -301,7 +495,88 @@ mod test {
             .expect_err("Should have failed to parse as a license");
         assert_eq!(
             actual,
-            SpdxExpressionError::InvalidSpdxExpression("unknown term".to_string())
+            SpdxExpressionError::InvalidSpdxExpression(
+                "unknown term: 'not' at position 0".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_parse_an_spdx_expression_into_a_tree() {
+        let license = |license: &str, exception: Option<&str>| ExpressionTree::License {
+            license: license.to_string(),
+            exception: exception.map(str::to_string),
+        };
+
+        let actual = SpdxExpression::parse(
+            "MIT OR (Apache-2.0 WITH LLVM-exception AND (ISC AND Zlib)) OR BSD-3-Clause",
+        )
+        .expect("Failed to parse as a license");
+
+        assert_eq!(
+            actual,
+            ExpressionTree::Or(vec![
+                license("MIT", None),
+                ExpressionTree::And(vec![
+                    license("Apache-2.0", Some("LLVM-exception")),
+                    license("ISC", None),
+                    license("Zlib", None),
+                ]),
+                license("BSD-3-Clause", None),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_point_at_the_offending_token_of_an_invalid_spdx_expression() {
+        let reason = |expression: &str| match SpdxExpression::parse(expression) {
+            Err(SpdxExpressionError::InvalidSpdxExpression(reason)) => reason,
+            other => panic!("Expected an invalid expression, got {:?}", other),
+        };
+
+        assert!(reason("MIT OR OR ISC").ends_with("'OR' at position 7"));
+        assert!(reason("MIT AND (ISC").contains("position"));
+        assert_eq!(reason("MIT AND Foo"), "unknown term: 'Foo' at position 8");
+        assert!(reason("WITH MIT").ends_with("'WITH' at position 0"));
+    }
+
+    #[test]
+    fn it_should_keep_the_original_string_of_a_parsed_expression() {
+        let expression = "Apache-2.0   OR MIT";
+        SpdxExpression::parse(expression).expect("Failed to parse as a license");
+        let actual =
+            SpdxExpression::try_from(expression.to_string()).expect("Failed to parse as a license");
+
+        assert_eq!(actual.to_string(), expression);
+    }
+
+    #[test]
+    fn it_should_canonicalize_commutative_operators() {
+        let canonical = |expression: &str| {
+            SpdxExpression::try_from(expression.to_string())
+                .expect("Invalid SPDX expression")
+                .canonicalize()
+                .to_string()
+        };
+
+        assert_eq!(canonical("MIT OR Apache-2.0"), "Apache-2.0 OR MIT");
+        assert_eq!(canonical("Apache-2.0 OR MIT"), "Apache-2.0 OR MIT");
+        assert_eq!(canonical("Zlib AND MIT"), canonical("MIT AND Zlib"));
+        assert_eq!(
+            canonical("(BSD-3-Clause OR Apache-2.0) AND MIT"),
+            "(Apache-2.0 OR BSD-3-Clause) AND MIT"
+        );
+        assert_eq!(
+            canonical("MIT AND (Apache-2.0 OR BSD-3-Clause)"),
+            "(Apache-2.0 OR BSD-3-Clause) AND MIT"
+        );
+        assert_eq!(
+            canonical("MIT  OR (ISC OR Zlib) OR MIT"),
+            "ISC OR MIT OR Zlib"
+        );
+        assert_eq!(
+            canonical("Apache-2.0   WITH LLVM-exception OR MIT"),
+            "Apache-2.0 WITH LLVM-exception OR MIT"
         );
     }
 
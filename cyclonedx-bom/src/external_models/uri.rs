@@ -16,7 +16,7 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::{convert::TryFrom, str::FromStr};
+use std::{collections::BTreeMap, convert::TryFrom, fmt, str::FromStr};
 
 use fluent_uri::Uri as Url;
 use packageurl::PackageUrl;
@@ -26,9 +26,34 @@ use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationResult,
 };
 
+/// A [Package URL](https://github.com/package-url/purl-spec), stored as written so that it
+/// round-trips faithfully
+/// ```
+/// use cyclonedx_bom::prelude::*;
+/// use std::str::FromStr;
+///
+/// let purl = Purl::from_str("pkg:cargo/serde@1.0.0")?;
+/// let components = purl.components()?;
+/// assert_eq!(components.package_type, "cargo");
+/// assert_eq!(components.name, "serde");
+/// assert_eq!(components.version.as_deref(), Some("1.0.0"));
+/// assert_eq!(purl.to_string(), "pkg:cargo/serde@1.0.0");
+/// # Ok::<(), cyclonedx_bom::external_models::uri::UriError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Purl(pub(crate) String);
 
+/// The parts of a Package URL: `pkg:type/namespace/name@version?qualifiers#subpath`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PurlComponents {
+    pub package_type: String,
+    pub namespace: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub qualifiers: BTreeMap<String, String>,
+    pub subpath: Option<String>,
+}
+
 impl Purl {
     pub fn new(package_type: &str, name: &str, version: &str) -> Result<Purl, UriError> {
         match packageurl::PackageUrl::new(package_type, name) {
@@ -36,11 +61,29 @@ impl Purl {
             Err(e) => Err(UriError::InvalidPurl(e.to_string())),
         }
     }
+
+    /// Parses the Package URL into its parts, failing if it does not conform to the spec
+    pub fn components(&self) -> Result<PurlComponents, UriError> {
+        let purl = PackageUrl::from_str(&self.0)
+            .map_err(|e| UriError::InvalidPurl(format!("{} in \"{}\"", e, self.0)))?;
+        Ok(PurlComponents {
+            package_type: purl.ty().to_string(),
+            namespace: purl.namespace().map(String::from),
+            name: purl.name().to_string(),
+            version: purl.version().map(String::from),
+            qualifiers: purl
+                .qualifiers()
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            subpath: purl.subpath().map(String::from),
+        })
+    }
 }
 
-impl ToString for Purl {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl fmt::Display for Purl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -62,25 +105,81 @@ impl Validate for Purl {
 }
 
 impl FromStr for Purl {
-    type Err = ();
+    type Err = UriError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(s.to_string()))
+        let purl = Self(s.to_string());
+        purl.components()?;
+        Ok(purl)
     }
 }
 
+/// A URI reference as defined in [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986),
+/// stored as written so that it round-trips faithfully
+/// ```
+/// use cyclonedx_bom::external_models::uri::{Uri, UriStrictness};
+///
+/// assert!(Uri::parse("https://example.com/LICENSE").is_ok());
+/// assert!(Uri::parse("../LICENSE").is_ok());
+/// assert!(Uri::parse_with("../LICENSE", UriStrictness::Absolute).is_err());
+/// assert!(Uri::parse("https://example.com/a b").is_err());
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Uri(pub(crate) String);
 
+/// Whether a [Uri] may be a relative reference, as the schema allows for most `url` fields
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UriStrictness {
+    /// Any URI reference, e.g. `https://example.com` or `../LICENSE`
+    #[default]
+    Reference,
+    /// Only URIs with a scheme, e.g. `https://example.com`
+    Absolute,
+}
+
+impl Uri {
+    /// Parses a URI reference, which may be relative
+    pub fn parse(value: &str) -> Result<Self, UriError> {
+        Self::parse_with(value, UriStrictness::Reference)
+    }
+
+    /// Parses a URI reference, only accepting relative ones if the strictness allows it
+    pub fn parse_with(value: &str, strictness: UriStrictness) -> Result<Self, UriError> {
+        let uri = Url::parse(value).map_err(|e| {
+            UriError::InvalidUri(format!(
+                "Uri does not conform to RFC 3986: {:?} at index {}",
+                e.kind(),
+                e.index()
+            ))
+        })?;
+        if strictness == UriStrictness::Absolute && uri.scheme().is_none() {
+            return Err(UriError::InvalidUri(format!(
+                "Uri has no scheme: {}",
+                value
+            )));
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    /// Wraps the value without checking it, e.g. to keep what a document contains
+    /// when reading it leniently. [Validate] reports it if it is invalid.
+    pub fn new_unchecked(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+}
+
 impl TryFrom<String> for Uri {
     type Error = UriError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match Url::parse(value.as_str()) {
-            Ok(_) => Ok(Uri(value)),
-            Err(_) => Err(UriError::InvalidUri(
-                "Uri does not conform to RFC 3986".to_string(),
-            )),
-        }
+        Self::parse(&value)
+    }
+}
+
+impl FromStr for Uri {
+    type Err = UriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
     }
 }
 
@@ -101,9 +200,9 @@ impl Validate for Uri {
     }
 }
 
-impl ToString for Uri {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl fmt::Display for Uri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -151,6 +250,78 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_parse_a_purl_into_its_components() {
+        let purl = Purl::from_str(
+            "pkg:cargo/rustsec/cargo-audit@0.18.3?download_url=https%3A%2F%2Fexample.com&arch=x86_64#src/bin",
+        )
+        .expect("Failed to parse purl");
+
+        assert_eq!(
+            purl.components().expect("Failed to parse purl"),
+            PurlComponents {
+                package_type: "cargo".to_string(),
+                namespace: Some("rustsec".to_string()),
+                name: "cargo-audit".to_string(),
+                version: Some("0.18.3".to_string()),
+                qualifiers: BTreeMap::from([
+                    ("arch".to_string(), "x86_64".to_string()),
+                    (
+                        "download_url".to_string(),
+                        "https://example.com".to_string()
+                    ),
+                ]),
+                subpath: Some("src/bin".to_string()),
+            }
+        );
+        assert_eq!(
+            purl.to_string(),
+            "pkg:cargo/rustsec/cargo-audit@0.18.3?download_url=https%3A%2F%2Fexample.com&arch=x86_64#src/bin"
+        );
+    }
+
+    #[test]
+    fn it_should_describe_why_a_purl_is_invalid() {
+        assert_eq!(
+            Purl::from_str("cargo/serde@1.0.0"),
+            Err(UriError::InvalidPurl(
+                "missing scheme in \"cargo/serde@1.0.0\"".to_string()
+            ))
+        );
+        assert!(Purl::from_str("pkg:cargo").is_err());
+    }
+
+    #[test]
+    fn it_should_parse_uris_with_the_requested_strictness() {
+        for relative in ["../LICENSE", "docs/index.html#usage", "//example.com/path"] {
+            assert_eq!(
+                Uri::parse(relative).map(|uri| uri.to_string()),
+                Ok(relative.to_string())
+            );
+            assert_eq!(
+                Uri::parse_with(relative, UriStrictness::Absolute),
+                Err(UriError::InvalidUri(format!(
+                    "Uri has no scheme: {}",
+                    relative
+                )))
+            );
+        }
+        assert!(Uri::parse_with(
+            "urn:cdx:3e671687-395b-41f5-a30f-a58921a69b79/1",
+            UriStrictness::Absolute
+        )
+        .is_ok());
+
+        assert_eq!(
+            Uri::parse("https://example.com/a b"),
+            Err(UriError::InvalidUri(
+                "Uri does not conform to RFC 3986: UnexpectedChar at index 21".to_string()
+            ))
+        );
+        assert!(Uri::from_str("https://example.com/%zz").is_err());
+        assert_eq!(Uri::new_unchecked("not a uri").to_string(), "not a uri");
+    }
+
     #[test]
     fn valid_uris_should_pass_validation() {
         let validation_result = Uri("https://example.com".to_string())
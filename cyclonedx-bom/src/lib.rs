@@ -126,6 +126,8 @@ pub mod errors;
 pub mod external_models;
 pub mod models;
 pub mod prelude;
+#[cfg(feature = "syft")]
+pub mod syft;
 pub mod validation;
 
 mod specs;
@@ -17,6 +17,7 @@
  */
 
 use base64::{engine::general_purpose::STANDARD, Engine};
+use thiserror::Error;
 
 use crate::{
     external_models::normalized_string::NormalizedString,
@@ -42,6 +43,68 @@ impl AttachedText {
             content: STANDARD.encode(content),
         }
     }
+
+    /// The content as raw bytes, decoded according to its encoding
+    pub fn decoded_content(&self) -> Result<Vec<u8>, AttachedTextError> {
+        match &self.encoding {
+            None => Ok(self.content.clone().into_bytes()),
+            Some(Encoding::Base64) => STANDARD
+                .decode(&self.content)
+                .map_err(|e| AttachedTextError::InvalidBase64(e.to_string())),
+            Some(Encoding::UnknownEncoding(encoding)) => {
+                Err(AttachedTextError::UnknownEncoding(encoding.clone()))
+            }
+        }
+    }
+
+    /// The content as text, for attachments with a textual content type.
+    /// Attachments without a content type are `text/plain`.
+    ///
+    /// ```
+    /// use cyclonedx_bom::models::attached_text::AttachedText;
+    ///
+    /// let text = AttachedText::new(None, "Licensed under MIT");
+    /// assert_eq!(text.as_string().unwrap(), "Licensed under MIT");
+    /// ```
+    pub fn as_string(&self) -> Result<String, AttachedTextError> {
+        if let Some(content_type) = &self.content_type {
+            if !is_text_content_type(&content_type.0) {
+                return Err(AttachedTextError::BinaryContent(content_type.to_string()));
+            }
+        }
+
+        String::from_utf8(self.decoded_content()?).map_err(|_| AttachedTextError::InvalidUtf8)
+    }
+}
+
+/// Whether a MIME type, ignoring its parameters, describes text
+fn is_text_content_type(content_type: &str) -> bool {
+    let mime_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    mime_type.starts_with("text/")
+        || mime_type.ends_with("+json")
+        || mime_type.ends_with("+xml")
+        || matches!(
+            mime_type.as_str(),
+            "application/json" | "application/xml" | "application/javascript"
+        )
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AttachedTextError {
+    #[error("Content type `{0}` is not text, use `decoded_content` to read the raw bytes")]
+    BinaryContent(String),
+    #[error("Content is not valid Base64: {0}")]
+    InvalidBase64(String),
+    #[error("Unknown encoding `{0}`")]
+    UnknownEncoding(String),
+    #[error("Content is not valid UTF-8")]
+    InvalidUtf8,
 }
 
 impl Validate for AttachedText {
@@ -151,6 +214,33 @@ mod test {
         )
     }
 
+    #[test]
+    fn it_should_read_plain_and_base64_text() {
+        let plain = AttachedText {
+            content_type: Some(NormalizedString::new("text/plain; charset=utf-8")),
+            encoding: None,
+            content: "this text is plain".to_string(),
+        };
+        assert_eq!(plain.as_string(), Ok("this text is plain".to_string()));
+
+        let base64 = AttachedText {
+            content_type: None,
+            encoding: Some(Encoding::Base64),
+            content: "dGhpcyB0ZXh0IGlzIHBsYWlu".to_string(),
+        };
+        assert_eq!(base64.as_string(), Ok("this text is plain".to_string()));
+    }
+
+    #[test]
+    fn it_should_refuse_to_read_binary_content_as_text() {
+        let binary = AttachedText::new(Some(NormalizedString::new("image/png")), [0x89, 0x50]);
+        assert_eq!(
+            binary.as_string(),
+            Err(AttachedTextError::BinaryContent("image/png".to_string()))
+        );
+        assert_eq!(binary.decoded_content(), Ok(vec![0x89, 0x50]));
+    }
+
     #[test]
     fn valid_attached_text_should_pass_validation() {
         let validation_result = AttachedText {
@@ -16,43 +16,74 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::convert::TryInto;
 use std::fmt;
+use std::io::{BufRead, BufReader, Read};
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
-use serde::{Deserialize, Serialize};
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
-use xml::{EmitterConfig, EventReader, EventWriter, ParserConfig};
+use xml::reader::ParserConfig2;
+use xml::{common::Position, EmitterConfig, EventReader, EventWriter, ParserConfig};
 
-use crate::errors::BomError;
-use crate::models::component::{Component, Components};
+use crate::errors::{BomError, BomReadError};
+use crate::external_models::date_time::DateTime;
+use crate::models::component::{Classification, Component, Components, Scope};
 use crate::models::composition::{BomReference, Compositions};
-use crate::models::dependency::Dependencies;
-use crate::models::external_reference::ExternalReferences;
+use crate::models::dependency::{Dependencies, Dependency};
+use crate::models::external_reference::{ExternalReference, ExternalReferences};
+use crate::models::foreign_extension::ForeignExtension;
+use crate::models::hash::Hashes;
+use crate::models::license::{LicenseChoice, Licenses};
 use crate::models::metadata::Metadata;
 use crate::models::property::Properties;
 use crate::models::service::{Service, Services};
 use crate::models::signature::Signature;
-use crate::models::vulnerability::Vulnerabilities;
+use crate::models::vulnerability::{AnalysisStateConflict, Vulnerabilities, Vulnerability};
+use crate::models::vulnerability_target::VulnerabilityTarget;
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
 };
-use crate::xml::{FromXmlDocument, ToXml};
+use crate::xml::{
+    read_document, FromXmlDocument, FromXmlSections, FromXmlType, ToXml, XmlElement, XmlRecovery,
+};
 
 /// Represents the spec version of a BOM.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 #[non_exhaustive]
 pub enum SpecVersion {
     #[serde(rename = "1.3")]
     V1_3,
     #[serde(rename = "1.4")]
     V1_4,
+    #[serde(rename = "1.5")]
+    V1_5,
+    #[serde(rename = "1.6")]
+    V1_6,
+}
+
+impl SpecVersion {
+    /// All supported versions, from the oldest to the newest
+    pub const ALL: [SpecVersion; 4] = [
+        SpecVersion::V1_3,
+        SpecVersion::V1_4,
+        SpecVersion::V1_5,
+        SpecVersion::V1_6,
+    ];
+
+    /// The namespace of XML documents conforming to this version
+    pub fn xml_namespace(&self) -> String {
+        format!("{}{}", XML_NAMESPACE_PREFIX, self)
+    }
 }
 
+const XML_NAMESPACE_PREFIX: &str = "http://cyclonedx.org/schema/bom/";
+
 impl FromStr for SpecVersion {
     type Err = BomError;
 
@@ -60,21 +91,394 @@ impl FromStr for SpecVersion {
         match input {
             "1.3" => Ok(SpecVersion::V1_3),
             "1.4" => Ok(SpecVersion::V1_4),
+            "1.5" => Ok(SpecVersion::V1_5),
+            "1.6" => Ok(SpecVersion::V1_6),
             s => Err(BomError::UnsupportedSpecVersion(s.to_string())),
         }
     }
 }
 
-impl ToString for SpecVersion {
-    fn to_string(&self) -> String {
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match self {
             SpecVersion::V1_3 => "1.3",
             SpecVersion::V1_4 => "1.4",
+            SpecVersion::V1_5 => "1.5",
+            SpecVersion::V1_6 => "1.6",
         };
-        s.to_string()
+        f.write_str(s)
+    }
+}
+
+/// How closely a BOM has to follow the specification to be parsed, see [ParseOptions::mode]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum ParseMode {
+    /// Reject documents with elements, attributes or fields that would be dropped when reading
+    /// them, i.e. that are not part of the specification or not supported by this crate, and
+    /// documents with values that are well-formed but invalid, e.g. a `serialNumber` that is
+    /// not a `urn:uuid`, or a `metadata.timestamp` or the `created`, `published` or `updated`
+    /// date of a vulnerability that is not an ISO 8601 date-time
+    Strict,
+    /// Accept any document that is well-formed, skipping what cannot be read
+    #[default]
+    Lax,
+}
+
+/// Options for [Bom::parse_from_xml_with_options] and [Bom::parse_from_json_with_options]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    /// Keep the elements from other namespaces that are direct children of `bom` as
+    /// [ForeignExtension]s, so that they are written back when the BOM is output as XML.
+    /// They are dropped by default, and rejected in [ParseMode::Strict].
+    pub preserve_foreign: bool,
+    pub mode: ParseMode,
+    /// Limits on what reading an XML document may take
+    pub limits: XmlLimits,
+    /// How deeply components, or services, may be nested in each other. They are read
+    /// recursively, so a deeper document is rejected with `MaxDepthExceeded` instead of
+    /// exhausting the stack.
+    pub max_nesting: usize,
+}
+
+const DEFAULT_MAX_NESTING: usize = 50;
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            preserve_foreign: false,
+            mode: ParseMode::default(),
+            limits: XmlLimits::default(),
+            max_nesting: DEFAULT_MAX_NESTING,
+        }
+    }
+}
+
+/// Limits on what reading an XML document may take, as BOMs are often untrusted. A document
+/// exceeding them is rejected with
+/// [XmlReadError::LimitExceeded](crate::errors::XmlReadError::LimitExceeded). The defaults
+/// are generous enough for any real BOM.
+///
+/// All limits are checked by [Bom::parse_from_xml_with_options]. The other XML entry points
+/// read the document in a single pass and only limit entity expansion, to the defaults.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct XmlLimits {
+    /// The size of the document in bytes
+    pub max_bytes: u64,
+    /// How deeply elements may be nested. Nested components are read recursively, so this
+    /// also keeps deeply nested documents from exhausting the stack.
+    pub max_depth: usize,
+    /// How many characters an entity declared in the document may expand to
+    pub max_entity_expansion_length: usize,
+    /// How many times entities may expand into other entities
+    pub max_entity_expansion_depth: u8,
+}
+
+impl Default for XmlLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 512 * 1024 * 1024,
+            max_depth: 128,
+            max_entity_expansion_length: 1_000_000,
+            max_entity_expansion_depth: 10,
+        }
+    }
+}
+
+impl XmlLimits {
+    fn parser_config(&self) -> ParserConfig2 {
+        ParserConfig::default()
+            .trim_whitespace(true)
+            .max_entity_expansion_length(self.max_entity_expansion_length)
+            .max_entity_expansion_depth(self.max_entity_expansion_depth)
+    }
+
+    /// Reads the whole input, checking it against the limits and the maximum nesting of
+    /// components and services before it is parsed, along with the version of the
+    /// specification given by the namespace of the root element
+    fn read<R: std::io::Read>(
+        &self,
+        reader: R,
+        max_nesting: usize,
+    ) -> Result<(Vec<u8>, SpecVersion), crate::errors::XmlReadError> {
+        let mut content = Vec::new();
+        reader
+            .take(self.max_bytes.saturating_add(1))
+            .read_to_end(&mut content)
+            .map_err(|error| crate::errors::XmlReadError::ElementReadError {
+                error: error.into(),
+                element: "document".to_string(),
+                position: None,
+            })?;
+        if content.len() as u64 > self.max_bytes {
+            return Err(crate::errors::XmlReadError::LimitExceeded {
+                limit: format!("{} bytes", self.max_bytes),
+                position: None,
+            });
+        }
+
+        let mut event_reader = EventReader::new_with_config(&content[..], self.parser_config());
+        let mut version = None;
+        let mut depth = 0;
+        let (mut components, mut services) = (0, 0);
+        loop {
+            match event_reader.next() {
+                Ok(xml::reader::XmlEvent::StartElement { name, .. }) => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(crate::errors::XmlReadError::LimitExceeded {
+                            limit: format!("{} nested elements", self.max_depth),
+                            position: Some(event_reader.position().into()),
+                        });
+                    }
+                    let version = match version {
+                        Some(version) => version,
+                        None => *version.insert(root_spec_version(&name, event_reader.position())?),
+                    };
+                    if name.namespace.as_deref() != Some(version.xml_namespace().as_str()) {
+                        continue;
+                    }
+                    match name.local_name.as_str() {
+                        "component" => components += 1,
+                        "service" => services += 1,
+                        _ => (),
+                    }
+                    if components.max(services) > max_nesting {
+                        return Err(crate::errors::XmlReadError::MaxDepthExceeded {
+                            depth: components.max(services),
+                            position: Some(event_reader.position().into()),
+                        });
+                    }
+                }
+                Ok(xml::reader::XmlEvent::EndElement { name }) => {
+                    depth -= 1;
+                    let namespace = version.map(|version| version.xml_namespace());
+                    if name.namespace != namespace {
+                        continue;
+                    }
+                    match name.local_name.as_str() {
+                        "component" => components -= 1,
+                        "service" => services -= 1,
+                        _ => (),
+                    }
+                }
+                Ok(xml::reader::XmlEvent::EndDocument) => break,
+                Ok(_) => (),
+                Err(error) if is_entity_too_big(&error) => {
+                    return Err(self.entity_expansion_exceeded(error.position()));
+                }
+                Err(error) if version.is_none() => {
+                    return Err(crate::xml::to_xml_read_error("document")(error));
+                }
+                // Left to the parser, which reports the element it failed in
+                Err(_) => break,
+            }
+        }
+
+        match version {
+            Some(version) => Ok((content, version)),
+            None => Err(missing_root_element()),
+        }
+    }
+
+    fn entity_expansion_exceeded(
+        &self,
+        position: xml::common::TextPosition,
+    ) -> crate::errors::XmlReadError {
+        crate::errors::XmlReadError::LimitExceeded {
+            limit: format!(
+                "{} characters or {} levels of entity expansion",
+                self.max_entity_expansion_length, self.max_entity_expansion_depth
+            ),
+            position: Some(position.into()),
+        }
+    }
+
+    /// Reports an entity that expands beyond the limits as such, instead of as the element
+    /// that was being read when it was expanded
+    fn check_entity_expansion(
+        &self,
+        error: crate::errors::XmlReadError,
+    ) -> crate::errors::XmlReadError {
+        match error {
+            crate::errors::XmlReadError::ElementReadError { ref error, .. }
+                if is_entity_too_big(error) =>
+            {
+                self.entity_expansion_exceeded(error.position())
+            }
+            error => error,
+        }
+    }
+}
+
+fn is_entity_too_big(error: &xml::reader::Error) -> bool {
+    matches!(error.kind(), xml::reader::ErrorKind::Syntax(message) if message == "Entity too big")
+}
+
+/// Records what is read from the inner reader, so that it can be read again
+struct RecordingReader<R> {
+    inner: R,
+    recorded: Vec<u8>,
+}
+
+impl<R: std::io::Read> std::io::Read for RecordingReader<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let length = self.inner.read(buffer)?;
+        self.recorded.extend_from_slice(&buffer[..length]);
+        Ok(length)
+    }
+}
+
+/// Reads the input up to its root element to find the version of the specification it
+/// conforms to. The returned reader starts over from the beginning of the input.
+fn peek_xml_spec_version<R: std::io::Read>(
+    reader: R,
+) -> Result<(SpecVersion, impl std::io::Read), crate::errors::XmlReadError> {
+    let limits = XmlLimits::default();
+    let recording = RecordingReader {
+        inner: reader,
+        recorded: Vec::new(),
+    };
+    let mut event_reader = EventReader::new_with_config(recording, limits.parser_config());
+    let version = loop {
+        match event_reader.next() {
+            Ok(xml::reader::XmlEvent::StartElement { name, .. }) => {
+                break root_spec_version(&name, event_reader.position())?;
+            }
+            Ok(xml::reader::XmlEvent::EndDocument) => return Err(missing_root_element()),
+            Ok(_) => (),
+            Err(error) if is_entity_too_big(&error) => {
+                return Err(limits.entity_expansion_exceeded(error.position()));
+            }
+            Err(error) => return Err(crate::xml::to_xml_read_error("document")(error)),
+        }
+    };
+
+    let RecordingReader { inner, recorded } = event_reader.into_inner();
+    Ok((version, std::io::Cursor::new(recorded).chain(inner)))
+}
+
+/// The version of the specification an XML document conforms to, given by the namespace of its
+/// root element
+fn root_spec_version(
+    name: &xml::name::OwnedName,
+    position: xml::common::TextPosition,
+) -> Result<SpecVersion, crate::errors::XmlReadError> {
+    name.namespace
+        .as_deref()
+        .and_then(|namespace| namespace.strip_prefix(XML_NAMESPACE_PREFIX))
+        .and_then(|version| SpecVersion::from_str(version).ok())
+        .ok_or_else(|| crate::errors::XmlReadError::InvalidNamespaceError {
+            expected_namespace: SpecVersion::ALL
+                .map(|version| version.xml_namespace())
+                .join(" or "),
+            actual_namespace: name.namespace.clone(),
+            position: Some(position.into()),
+        })
+}
+
+fn missing_root_element() -> crate::errors::XmlReadError {
+    crate::errors::XmlReadError::RequiredDataMissing {
+        required_field: "bom".to_string(),
+        element: "document".to_string(),
+        position: None,
+    }
+}
+
+/// Something in a document that was accepted when parsing it, but is not quite right
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// Where in the document it is, e.g. `components[0].scope` or
+    /// `bom/components/component/scope`
+    pub path: String,
+    pub message: String,
+}
+
+impl ParseWarning {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Renders the path followed by the message
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// A BOM along with the warnings about the document it was parsed from, see
+/// [Bom::parse_from_json_with_options] and [Bom::parse_from_xml_with_options]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedBom {
+    pub bom: Bom,
+    /// The version of the specification the document was read as
+    pub spec_version: SpecVersion,
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// A value that was dropped when converting a BOM to an older version of the specification
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConversionWarning {
+    pub message: String,
+    pub context: ValidationContext,
+}
+
+/// Renders the path to the dropped value followed by the message
+impl fmt::Display for ConversionWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.message)
     }
 }
 
+/// A bom-ref that is shared by several components, services or vulnerabilities
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateBomRef {
+    pub bom_ref: String,
+    /// The elements with the bom-ref, in document order
+    pub paths: Vec<ValidationContext>,
+}
+
+/// What [Bom::merge] does with a component or service that both BOMs contain
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// Keep the one of the BOM merged into
+    KeepFirst,
+    /// Keep the one of the BOM being merged
+    KeepLast,
+    /// Fail with [MergeError::ComponentConflict] or [MergeError::ServiceConflict], and with
+    /// [MergeError::AnalysisStateConflicts] for vulnerabilities analysed differently
+    Error,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum MergeError {
+    #[error("Both BOMs contain the component {0}")]
+    ComponentConflict(String),
+
+    #[error("Both BOMs contain the service {0}")]
+    ServiceConflict(String),
+
+    #[error("The merged BOM contains {} bom-refs more than once", .0.len())]
+    DuplicateBomRefs(Vec<DuplicateBomRef>),
+
+    #[error("Both BOMs contain {} vulnerabilities with a different analysis state", .0.len())]
+    AnalysisStateConflicts(Vec<AnalysisStateConflict>),
+}
+
+/// The major sections of a BOM, taken out of it with [Bom::into_parts].
+/// Absent sections are empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BomParts {
+    pub metadata: Option<Metadata>,
+    pub components: Vec<Component>,
+    pub services: Vec<Service>,
+    pub dependencies: Vec<Dependency>,
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Bom {
     pub version: u32,
@@ -90,27 +494,493 @@ pub struct Bom {
     pub vulnerabilities: Option<Vulnerabilities>,
     /// Added in version 1.4
     pub signature: Option<Signature>,
+    /// Elements from other namespaces, only read with [ParseOptions::preserve_foreign]
+    /// and only written to XML
+    pub foreign_extensions: Vec<ForeignExtension>,
 }
 
 impl Bom {
+    /// Start building a BOM with [BomBuilder]
+    pub fn builder() -> BomBuilder {
+        BomBuilder::new()
+    }
+
+    /// Parse a JSON or XML document, telling them apart by the first non-whitespace character.
+    ///
+    /// The version of the specification is taken from the `specVersion` field of a JSON document
+    /// and from the namespace of an XML document. Input that starts with neither `{` nor `<`
+    /// is rejected with [BomReadError::UnknownFormatError].
+    pub fn parse<R: std::io::Read>(reader: R) -> Result<Self, BomReadError> {
+        let mut reader = BufReader::new(reader);
+        let first_byte = loop {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                break None;
+            }
+            match buffer.iter().position(|byte| !byte.is_ascii_whitespace()) {
+                Some(position) => {
+                    let byte = buffer[position];
+                    reader.consume(position);
+                    break Some(byte);
+                }
+                None => {
+                    let length = buffer.len();
+                    reader.consume(length);
+                }
+            }
+        };
+
+        match first_byte {
+            Some(b'{') => Ok(Self::parse_from_json_any(reader)?),
+            Some(b'<') => Ok(Self::parse_from_xml_any(reader)?),
+            Some(byte) => Err(BomReadError::UnknownFormatError {
+                found: format!("'{}'", char::from(byte).escape_default()),
+            }),
+            None => Err(BomReadError::UnknownFormatError {
+                found: "no content".to_string(),
+            }),
+        }
+    }
+
     /// General function to parse a JSON file, fetches the `specVersion` field first then applies the right conversion.
+    ///
+    /// Same as [Self::parse_from_json_any].
     pub fn parse_from_json<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        Self::parse_from_json_any(reader)
+    }
+
+    /// Parse a JSON document of any supported version of the specification.
+    ///
+    /// The `bomFormat` field must be `CycloneDX`, and the `specVersion` field selects the
+    /// version to read the document as. An unknown version is rejected with
+    /// [BomError::UnsupportedSpecVersion], listing the supported ones.
+    pub fn parse_from_json_any<R: std::io::Read>(
         mut reader: R,
     ) -> Result<Self, crate::errors::JsonReadError> {
         let json: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        check_json_nesting(&json, DEFAULT_MAX_NESTING)?;
 
-        if let Some(version) = json.get("specVersion") {
-            let version = version
-                .as_str()
-                .ok_or_else(|| BomError::UnsupportedSpecVersion(version.to_string()))?;
+        match json_spec_version(&json)? {
+            SpecVersion::V1_3 => Self::read_json::<crate::specs::v1_3::bom::Bom>(json),
+            SpecVersion::V1_4 => Self::read_json::<crate::specs::v1_4::bom::Bom>(json),
+            SpecVersion::V1_5 => Self::read_json::<crate::specs::v1_5::bom::Bom>(json),
+            SpecVersion::V1_6 => Self::read_json::<crate::specs::v1_6::bom::Bom>(json),
+        }
+    }
+
+    /// Same as [Self::parse_from_json_any], but also returns [ParseWarning]s about what was
+    /// accepted without being quite right, e.g. fields that were ignored, a missing `version`
+    /// or an unknown component `scope`.
+    ///
+    /// In [ParseMode::Strict] documents with fields that are not part of the specification are
+    /// rejected as [BomError::UnknownField], and documents with invalid values as
+    /// [BomError::InvalidSerialNumber] or [BomError::InvalidDateTime].
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, ParseMode, ParseOptions};
+    ///
+    /// let input = r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "color": "red"}"#;
+    /// let parsed = Bom::parse_from_json_with_options(input.as_bytes(), ParseOptions::default())?;
+    /// assert_eq!(parsed.bom.version, 1);
+    ///
+    /// let warnings: Vec<String> = parsed.warnings.iter().map(|w| w.to_string()).collect();
+    /// assert_eq!(warnings, ["version: Missing version, assuming 1", "color: Unknown field, ignored"]);
+    ///
+    /// let options = ParseOptions { mode: ParseMode::Strict, ..ParseOptions::default() };
+    /// let error = Bom::parse_from_json_with_options(input.as_bytes(), options).unwrap_err();
+    /// assert_eq!(error.to_string(), "Invalid input format found: Unknown field 'color'");
+    /// # Ok::<(), cyclonedx_bom::errors::JsonReadError>(())
+    /// ```
+    pub fn parse_from_json_with_options<R: std::io::Read>(
+        mut reader: R,
+        options: ParseOptions,
+    ) -> Result<ParsedBom, crate::errors::JsonReadError> {
+        let json: serde_json::Value = serde_json::from_reader(&mut reader)?;
+        check_json_nesting(&json, options.max_nesting)?;
+
+        let spec_version = json_spec_version(&json)?;
+        match spec_version {
+            SpecVersion::V1_3 => Self::read_json_with_options::<crate::specs::v1_3::bom::Bom>(
+                json,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_4 => Self::read_json_with_options::<crate::specs::v1_4::bom::Bom>(
+                json,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_5 => Self::read_json_with_options::<crate::specs::v1_5::bom::Bom>(
+                json,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_6 => Self::read_json_with_options::<crate::specs::v1_6::bom::Bom>(
+                json,
+                spec_version,
+                options,
+            ),
+        }
+    }
+
+    fn read_json<B: serde::de::DeserializeOwned + Into<Self>>(
+        json: Value,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        Ok(B::deserialize(json)?.into())
+    }
+
+    fn read_json_with_options<B: serde::de::DeserializeOwned + Serialize + Into<Self>>(
+        json: Value,
+        spec_version: SpecVersion,
+        options: ParseOptions,
+    ) -> Result<ParsedBom, crate::errors::JsonReadError> {
+        let (bom, unknown_fields) = read_json_checked::<B>(&json)?;
+        let bom: Self = bom.into();
 
-            match SpecVersion::from_str(version)? {
-                SpecVersion::V1_3 => Ok(crate::specs::v1_3::bom::Bom::deserialize(json)?.into()),
-                SpecVersion::V1_4 => Ok(crate::specs::v1_4::bom::Bom::deserialize(json)?.into()),
+        if options.mode == ParseMode::Strict {
+            if let Some(path) = unknown_fields.first() {
+                return Err(BomError::UnknownField(path.clone()).into());
+            }
+            if let Some(serial_number) = bom.invalid_serial_number() {
+                return Err(BomError::InvalidSerialNumber(serial_number.to_string()).into());
+            }
+            if let Some((field, date_time)) = bom.invalid_date_time() {
+                return Err(BomError::InvalidDateTime(field, date_time.to_string()).into());
             }
+        }
+
+        let mut warnings = Vec::new();
+        if json.get("version").is_none() {
+            warnings.push(ParseWarning::new("version", "Missing version, assuming 1"));
+        }
+        warnings.extend(
+            unknown_fields
+                .into_iter()
+                .map(|path| ParseWarning::new(path, "Unknown field, ignored")),
+        );
+        warnings.extend(unknown_value_warnings(&bom, PathStyle::Json));
+        Ok(ParsedBom {
+            bom,
+            spec_version,
+            warnings,
+        })
+    }
+
+    /// Parse an XML document of any supported version of the specification,
+    /// which is selected by the namespace of the root element.
+    ///
+    /// A document in another namespace is rejected with
+    /// [XmlReadError::InvalidNamespaceError](crate::errors::XmlReadError::InvalidNamespaceError),
+    /// listing the supported ones.
+    pub fn parse_from_xml_any<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let (version, reader) = peek_xml_spec_version(reader)?;
+        Self::parse_from_xml_version(reader, version)
+    }
+
+    fn parse_from_xml_version<R: std::io::Read>(
+        reader: R,
+        version: SpecVersion,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        match version {
+            SpecVersion::V1_3 => Self::parse_from_xml_v1_3(reader),
+            SpecVersion::V1_4 => Self::parse_from_xml_v1_4(reader),
+            SpecVersion::V1_5 => Self::parse_from_xml_v1_5(reader),
+            SpecVersion::V1_6 => Self::parse_from_xml_v1_6(reader),
+        }
+    }
+
+    /// Same as [Self::parse_from_xml_any], but also returns [ParseWarning]s about what was
+    /// accepted without being quite right, e.g. elements that were ignored, a missing `version`
+    /// or an unknown component `scope`.
+    ///
+    /// In [ParseMode::Strict] documents with elements or attributes that are not part of the
+    /// specification are rejected as
+    /// [XmlReadError::UnknownContentError](crate::errors::XmlReadError::UnknownContentError),
+    /// and documents with invalid values as
+    /// [XmlReadError::InvalidParseError](crate::errors::XmlReadError::InvalidParseError).
+    ///
+    /// The document is checked against [ParseOptions::limits] and [ParseOptions::max_nesting]
+    /// before it is read.
+    /// ```
+    /// use cyclonedx_bom::models::bom::{Bom, ParseOptions};
+    ///
+    /// let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ex="https://example.com" version="1">
+    ///   <ex:build id="42"><ex:host>ci</ex:host></ex:build>
+    /// </bom>"#;
+    /// let options = ParseOptions { preserve_foreign: true, ..ParseOptions::default() };
+    /// let bom = Bom::parse_from_xml_with_options(input.as_bytes(), options)?.bom;
+    /// assert_eq!(bom.foreign_extensions[0].name, "ex:build");
+    ///
+    /// let mut output = Vec::new();
+    /// bom.output_as_xml_v1_5(&mut output).expect("Failed to write BOM");
+    /// assert!(String::from_utf8(output).unwrap().contains("<ex:host>ci</ex:host>"));
+    /// # Ok::<(), cyclonedx_bom::errors::XmlReadError>(())
+    /// ```
+    pub fn parse_from_xml_with_options<R: std::io::Read>(
+        reader: R,
+        options: ParseOptions,
+    ) -> Result<ParsedBom, crate::errors::XmlReadError> {
+        let (content, spec_version) = options.limits.read(reader, options.max_nesting)?;
+        match spec_version {
+            SpecVersion::V1_3 => Self::read_xml_with_options::<crate::specs::v1_3::bom::Bom>(
+                &content,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_4 => Self::read_xml_with_options::<crate::specs::v1_4::bom::Bom>(
+                &content,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_5 => Self::read_xml_with_options::<crate::specs::v1_5::bom::Bom>(
+                &content,
+                spec_version,
+                options,
+            ),
+            SpecVersion::V1_6 => Self::read_xml_with_options::<crate::specs::v1_6::bom::Bom>(
+                &content,
+                spec_version,
+                options,
+            ),
+        }
+    }
+
+    fn read_xml<R: std::io::Read, B: FromXmlDocument + Into<Self>>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let limits = XmlLimits::default();
+        let mut event_reader = EventReader::new_with_config(reader, limits.parser_config());
+        let mut bom: Self = read_document::<_, B>(&mut event_reader)
+            .map_err(|error| limits.check_entity_expansion(error))?
+            .into();
+        bom.foreign_extensions.clear();
+        Ok(bom)
+    }
+
+    fn read_xml_with_options<B: FromXmlDocument + ToXml + Into<Self>>(
+        content: &[u8],
+        spec_version: SpecVersion,
+        options: ParseOptions,
+    ) -> Result<ParsedBom, crate::errors::XmlReadError> {
+        let (input, bom, unmatched) = read_xml_checked::<B>(content, &options.limits)?;
+        let mut bom: Self = bom.into();
+        let foreign_extensions = if options.preserve_foreign {
+            Vec::new()
         } else {
-            Err(BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string()).into())
+            std::mem::take(&mut bom.foreign_extensions)
+        };
+
+        if options.mode == ParseMode::Strict {
+            let unknown = unmatched.first().cloned().or_else(|| {
+                foreign_extensions
+                    .first()
+                    .map(|extension| format!("bom/{}", extension.name))
+            });
+            if let Some(path) = unknown {
+                return Err(crate::errors::XmlReadError::UnknownContentError {
+                    path,
+                    position: None,
+                });
+            }
+            if let Some(serial_number) = bom.invalid_serial_number() {
+                return Err(crate::errors::XmlReadError::InvalidParseError {
+                    value: serial_number.to_string(),
+                    data_type: "urn:uuid".to_string(),
+                    element: "serialNumber".to_string(),
+                    position: None,
+                });
+            }
+            if let Some((field, date_time)) = bom.invalid_date_time() {
+                return Err(crate::errors::XmlReadError::InvalidParseError {
+                    value: date_time.to_string(),
+                    data_type: "dateTime".to_string(),
+                    element: field,
+                    position: None,
+                });
+            }
+        }
+
+        let mut warnings = Vec::new();
+        if !input
+            .attributes
+            .iter()
+            .any(|a| a.name.local_name == "version")
+        {
+            warnings.push(ParseWarning::new(
+                "bom/@version",
+                "Missing version, assuming 1",
+            ));
+        }
+        warnings.extend(
+            unmatched
+                .into_iter()
+                .map(|path| ParseWarning::new(path, "Unknown element or attribute, ignored")),
+        );
+        warnings.extend(foreign_extensions.into_iter().map(|extension| {
+            ParseWarning::new(
+                format!("bom/{}", extension.name),
+                "Element from another namespace, ignored",
+            )
+        }));
+        warnings.extend(unknown_value_warnings(&bom, PathStyle::Xml));
+        Ok(ParsedBom {
+            bom,
+            spec_version,
+            warnings,
+        })
+    }
+
+    /// Parse the input as an XML document conforming to the given version of the specification,
+    /// collecting as many errors as possible instead of stopping at the first one.
+    ///
+    /// A top-level element that cannot be read is dropped and its error recorded. For lists such
+    /// as `components` only the items that cannot be read are dropped. The returned `Bom` holds
+    /// everything that could be read.
+    ///
+    /// Errors that leave nothing to recover, such as a document that is not well-formed or not in
+    /// the expected namespace, are still returned as an error.
+    pub fn parse_from_xml_collecting_errors<R: std::io::Read>(
+        reader: R,
+        version: SpecVersion,
+    ) -> Result<(Self, Vec<crate::errors::XmlReadError>), crate::errors::XmlReadError> {
+        let limits = XmlLimits::default();
+        let (content, _) = limits.read(reader, DEFAULT_MAX_NESTING)?;
+        let mut recovery = XmlRecovery::scan(&mut EventReader::new_with_config(
+            &content[..],
+            limits.parser_config(),
+        ))?;
+
+        let mut event_reader = EventReader::new_with_config(&content[..], limits.parser_config());
+        let bom = match version {
+            SpecVersion::V1_3 => Self::read_xml_sections::<_, crate::specs::v1_3::bom::Bom>(
+                &mut event_reader,
+                &mut recovery,
+            ),
+            SpecVersion::V1_4 => Self::read_xml_sections::<_, crate::specs::v1_4::bom::Bom>(
+                &mut event_reader,
+                &mut recovery,
+            ),
+            SpecVersion::V1_5 => Self::read_xml_sections::<_, crate::specs::v1_5::bom::Bom>(
+                &mut event_reader,
+                &mut recovery,
+            ),
+            SpecVersion::V1_6 => Self::read_xml_sections::<_, crate::specs::v1_6::bom::Bom>(
+                &mut event_reader,
+                &mut recovery,
+            ),
+        }
+        .map_err(|error| error.or_position(event_reader.position()))?;
+        Ok((bom, recovery.errors))
+    }
+
+    /// Reads the root element and then each top-level element on its own, recovering from the
+    /// errors of those that cannot be read. Elements from other namespaces are skipped.
+    fn read_xml_sections<R: std::io::Read, B: FromXmlSections>(
+        event_reader: &mut EventReader<R>,
+        recovery: &mut XmlRecovery,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        let to_error = crate::xml::to_xml_read_error;
+        match event_reader.next().map_err(to_error("bom"))? {
+            xml::reader::XmlEvent::StartDocument { .. } => (),
+            unexpected => return Err(crate::xml::unexpected_element_error("bom", unexpected)),
+        }
+        let (bom_name, attributes) = match event_reader.next().map_err(to_error("bom"))? {
+            xml::reader::XmlEvent::StartElement {
+                name,
+                attributes,
+                namespace,
+            } if name.local_name == "bom" => {
+                crate::xml::expected_namespace_or_error(B::VERSION, &namespace)?;
+                (name, attributes)
+            }
+            unexpected => return Err(crate::xml::unexpected_element_error("bom", unexpected)),
+        };
+
+        let version = crate::xml::optional_attribute(&attributes, "version")
+            .map(|version| u32::from_xml_value("version", version))
+            .transpose()?;
+        let mut bom = Self {
+            version: version.unwrap_or(1),
+            serial_number: crate::xml::optional_attribute(&attributes, "serialNumber").map(UrnUuid),
+            ..Self::default()
+        };
+
+        loop {
+            match event_reader.next().map_err(to_error("bom"))? {
+                xml::reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => {
+                    if !B::read_xml_section(event_reader, &name, &attributes, &mut bom, recovery)? {
+                        crate::xml::read_lax_validation_tag(event_reader, &name)?;
+                    }
+                }
+                xml::reader::XmlEvent::EndElement { name } if name == bom_name => break,
+                unexpected => return Err(crate::xml::unexpected_element_error("bom", unexpected)),
+            }
+        }
+
+        match event_reader.next().map_err(to_error("bom"))? {
+            xml::reader::XmlEvent::EndDocument => Ok(bom),
+            unexpected => Err(crate::xml::unexpected_element_error("bom", unexpected)),
+        }
+    }
+
+    fn invalid_serial_number(&self) -> Option<&UrnUuid> {
+        self.serial_number
+            .as_ref()
+            .filter(|serial_number| !matches_urn_uuid_regex(&serial_number.0))
+    }
+
+    /// The path to the first timestamp checked in [ParseMode::Strict] that is invalid, and its value
+    fn invalid_date_time(&self) -> Option<(String, &DateTime)> {
+        let is_invalid = |date_time: &DateTime| date_time.to_offset_date_time().is_err();
+
+        if let Some(timestamp) = self
+            .metadata
+            .as_ref()
+            .and_then(|m| m.timestamp.as_ref())
+            .filter(|t| is_invalid(t))
+        {
+            return Some(("metadata.timestamp".to_string(), timestamp));
         }
+
+        for (index, vulnerability) in self
+            .vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .enumerate()
+        {
+            let dates = [
+                ("created", &vulnerability.created),
+                ("published", &vulnerability.published),
+                ("updated", &vulnerability.updated),
+            ];
+            for (field, date_time) in dates {
+                if let Some(date_time) = date_time.as_ref().filter(|d| is_invalid(d)) {
+                    return Some((format!("vulnerabilities[{}].{}", index, field), date_time));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Parse the top-level `components` of a JSON document one at a time, passing each to
+    /// `callback` as soon as it has been read.
+    ///
+    /// All other sections of the document are skipped without being kept in memory, which
+    /// allows processing BOMs that are too large to parse as a whole.
+    pub fn stream_components_from_json<R: std::io::Read, F: FnMut(Component)>(
+        reader: R,
+        callback: F,
+    ) -> Result<(), crate::errors::JsonReadError> {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        deserializer.deserialize_map(ComponentStream(callback))?;
+        deserializer.end()?;
+        Ok(())
     }
 
     /// Parse the input as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
@@ -132,10 +1002,7 @@ impl Bom {
     pub fn parse_from_xml_v1_3<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_3::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+        Self::read_xml::<_, crate::specs::v1_3::bom::Bom>(reader)
     }
 
     /// Output as a JSON document conforming to [version 1.3 of the specification](https://cyclonedx.org/docs/1.3/json/)
@@ -172,10 +1039,7 @@ impl Bom {
     pub fn parse_from_xml_v1_4<R: std::io::Read>(
         reader: R,
     ) -> Result<Self, crate::errors::XmlReadError> {
-        let config = ParserConfig::default().trim_whitespace(true);
-        let mut event_reader = EventReader::new_with_config(reader, config);
-        let bom = crate::specs::v1_4::bom::Bom::read_xml_document(&mut event_reader)?;
-        Ok(bom.into())
+        Self::read_xml::<_, crate::specs::v1_4::bom::Bom>(reader)
     }
 
     /// Output as a JSON document conforming to [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/)
@@ -199,407 +1063,4328 @@ impl Bom {
         let bom: crate::specs::v1_4::bom::Bom = self.into();
         bom.write_xml_element(&mut event_writer)
     }
-}
 
-impl Default for Bom {
-    /// Construct a BOM with a default `version` of `1` and `serial_number` with a random UUID
-    fn default() -> Self {
-        Self {
-            version: 1,
-            serial_number: Some(UrnUuid::generate()),
-            metadata: None,
-            components: None,
-            services: None,
-            external_references: None,
-            dependencies: None,
-            compositions: None,
-            properties: None,
-            vulnerabilities: None,
-            signature: None,
-        }
+    /// Parse the input as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn parse_from_json_v1_5<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_5::bom::Bom = serde_json::from_reader(&mut reader)?;
+        Ok(bom.into())
     }
-}
 
-impl Validate for Bom {
-    fn validate_with_context(
-        &self,
-        context: ValidationContext,
-    ) -> Result<ValidationResult, ValidationError> {
-        let mut results: Vec<ValidationResult> = vec![];
+    /// Parse the input as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    pub fn parse_from_xml_v1_5<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        Self::read_xml::<_, crate::specs::v1_5::bom::Bom>(reader)
+    }
 
-        let mut bom_refs_context = BomReferencesContext::default();
+    /// Output as a JSON document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/json/)
+    pub fn output_as_json_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
 
-        if let Some(serial_number) = &self.serial_number {
-            let context = context.extend_context_with_struct_field("Bom", "serial_number");
+    /// Output as an XML document conforming to [version 1.5 of the specification](https://cyclonedx.org/docs/1.5/xml/)
+    pub fn output_as_xml_v1_5<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
 
-            results.push(serial_number.validate_with_context(context)?);
+        let bom: crate::specs::v1_5::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Parse the input as a JSON document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/json/)
+    pub fn parse_from_json_v1_6<R: std::io::Read>(
+        mut reader: R,
+    ) -> Result<Self, crate::errors::JsonReadError> {
+        let bom: crate::specs::v1_6::bom::Bom = serde_json::from_reader(&mut reader)?;
+        Ok(bom.into())
+    }
+
+    /// Parse the input as an XML document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/xml/)
+    pub fn parse_from_xml_v1_6<R: std::io::Read>(
+        reader: R,
+    ) -> Result<Self, crate::errors::XmlReadError> {
+        Self::read_xml::<_, crate::specs::v1_6::bom::Bom>(reader)
+    }
+
+    /// Output as a JSON document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/json/)
+    pub fn output_as_json_v1_6<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::JsonWriteError> {
+        let bom: crate::specs::v1_6::bom::Bom = self.into();
+        serde_json::to_writer_pretty(writer, &bom)?;
+        Ok(())
+    }
+
+    /// Output as an XML document conforming to [version 1.6 of the specification](https://cyclonedx.org/docs/1.6/xml/)
+    pub fn output_as_xml_v1_6<W: std::io::Write>(
+        self,
+        writer: &mut W,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        let config = EmitterConfig::default().perform_indent(true);
+        let mut event_writer = EventWriter::new_with_config(writer, config);
+
+        let bom: crate::specs::v1_6::bom::Bom = self.into();
+        bom.write_xml_element(&mut event_writer)
+    }
+
+    /// Output as a JSON document conforming to the given version of the specification into a
+    /// newly allocated buffer
+    pub fn to_cyclonedx_json_bytes(
+        self,
+        version: SpecVersion,
+    ) -> Result<Vec<u8>, crate::errors::JsonWriteError> {
+        let mut output = Vec::new();
+        match version {
+            SpecVersion::V1_3 => self.output_as_json_v1_3(&mut output)?,
+            SpecVersion::V1_4 => self.output_as_json_v1_4(&mut output)?,
+            SpecVersion::V1_5 => self.output_as_json_v1_5(&mut output)?,
+            SpecVersion::V1_6 => self.output_as_json_v1_6(&mut output)?,
         }
+        Ok(output)
+    }
 
-        if let Some(metadata) = &self.metadata {
-            let context = context.extend_context_with_struct_field("Bom", "metadata");
-            let component_bom_ref_context =
-                context.extend_context_with_struct_field("Metadata", "component");
+    /// Output as an XML document conforming to the given version of the specification into a
+    /// newly allocated buffer
+    pub fn to_cyclonedx_xml_bytes(
+        self,
+        version: SpecVersion,
+    ) -> Result<Vec<u8>, crate::errors::XmlWriteError> {
+        let mut output = Vec::new();
+        match version {
+            SpecVersion::V1_3 => self.output_as_xml_v1_3(&mut output)?,
+            SpecVersion::V1_4 => self.output_as_xml_v1_4(&mut output)?,
+            SpecVersion::V1_5 => self.output_as_xml_v1_5(&mut output)?,
+            SpecVersion::V1_6 => self.output_as_xml_v1_6(&mut output)?,
+        }
+        Ok(output)
+    }
 
-            results.push(metadata.validate_with_context(context)?);
+    /// Converts the BOM to what the given version of the specification can represent,
+    /// together with a warning for each value that had to be dropped
+    pub fn convert_to(
+        &self,
+        version: SpecVersion,
+    ) -> Result<(Bom, Vec<ConversionWarning>), BomError> {
+        let converted = match version {
+            SpecVersion::V1_3 => {
+                let bom: crate::specs::v1_3::bom::Bom = self.clone().try_into()?;
+                bom.into()
+            }
+            SpecVersion::V1_4 => crate::specs::v1_4::bom::Bom::from(self.clone()).into(),
+            SpecVersion::V1_5 => crate::specs::v1_5::bom::Bom::from(self.clone()).into(),
+            SpecVersion::V1_6 => crate::specs::v1_6::bom::Bom::from(self.clone()).into(),
+        };
+
+        let mut warnings = Vec::new();
+        let context = ValidationContext::default();
+        let mut dropped = |present: bool, added_in: SpecVersion, context: ValidationContext| {
+            if present && version < added_in {
+                warnings.push(ConversionWarning {
+                    message: format!("Dropped, as it was added in version {}", added_in),
+                    context,
+                });
+            }
+        };
+
+        dropped(
+            self.signature.is_some(),
+            SpecVersion::V1_4,
+            context.extend_context_with_struct_field("Bom", "signature"),
+        );
 
+        if let Some(metadata) = &self.metadata {
+            let context = context.extend_context_with_struct_field("Bom", "metadata");
+            dropped(
+                metadata.lifecycles.is_some(),
+                SpecVersion::V1_5,
+                context.extend_context_with_struct_field("Metadata", "lifecycles"),
+            );
             if let Some(component) = &metadata.component {
-                validate_component_bom_refs(
-                    component,
-                    &mut bom_refs_context,
-                    &component_bom_ref_context,
-                    &mut results,
-                );
+                let context = context.extend_context_with_struct_field("Metadata", "component");
+                component_conversion_warnings(component, context, &mut dropped);
+            }
+            if let Some(licenses) = &metadata.licenses {
+                let context = context.extend_context_with_struct_field("Metadata", "licenses");
+                licenses_conversion_warnings(licenses, context, &mut dropped);
             }
         }
 
         if let Some(components) = &self.components {
             let context = context.extend_context_with_struct_field("Bom", "components");
-            let component_bom_ref_context = context.clone();
-
-            results.push(components.validate_with_context(context)?);
-
-            // record the component references
-            validate_components(
-                components,
-                &mut bom_refs_context,
-                &component_bom_ref_context,
-                &mut results,
-            );
+            for (index, component) in components.0.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                component_conversion_warnings(component, context, &mut dropped);
+            }
         }
 
         if let Some(services) = &self.services {
             let context = context.extend_context_with_struct_field("Bom", "services");
-            let service_bom_ref_context = context.clone();
-
-            results.push(services.validate_with_context(context)?);
-
-            // record the service references
-            validate_services(
-                services,
-                &mut bom_refs_context,
-                &service_bom_ref_context,
-                &mut results,
-            );
+            for (index, service) in services.0.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                service_conversion_warnings(service, context, &mut dropped);
+            }
         }
 
-        if let Some(external_references) = &self.external_references {
-            let context = context.extend_context_with_struct_field("Bom", "external_references");
+        if let Some(compositions) = &self.compositions {
+            let context = context.extend_context_with_struct_field("Bom", "compositions");
+            for (index, composition) in compositions.0.iter().enumerate() {
+                dropped(
+                    composition.signature.is_some(),
+                    SpecVersion::V1_4,
+                    context.extend_context(vec![
+                        ValidationPathComponent::Array { index },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Composition".to_string(),
+                            field_name: "signature".to_string(),
+                        },
+                    ]),
+                );
+            }
+        }
 
-            results.push(external_references.validate_with_context(context)?);
+        if let Some(vulnerabilities) = &self.vulnerabilities {
+            let context = context.extend_context_with_struct_field("Bom", "vulnerabilities");
+            for (index, vulnerability) in vulnerabilities.0.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                if version < SpecVersion::V1_4 {
+                    dropped(true, SpecVersion::V1_4, context);
+                    continue;
+                }
+                dropped(
+                    vulnerability.workaround.is_some(),
+                    SpecVersion::V1_5,
+                    context.extend_context_with_struct_field("Vulnerability", "workaround"),
+                );
+                dropped(
+                    vulnerability.proof_of_concept.is_some(),
+                    SpecVersion::V1_5,
+                    context.extend_context_with_struct_field("Vulnerability", "proof_of_concept"),
+                );
+            }
         }
 
-        if let Some(dependencies) = &self.dependencies {
-            let context = context.extend_context_with_struct_field("Bom", "dependencies");
+        Ok((converted, warnings))
+    }
 
-            for (dependency_index, dependency) in dependencies.0.iter().enumerate() {
-                let context = context.extend_context(vec![ValidationPathComponent::Array {
-                    index: dependency_index,
-                }]);
-                if !bom_refs_context.contains(&dependency.dependency_ref) {
-                    let dependency_context =
-                        context.extend_context_with_struct_field("Dependency", "dependency_ref");
+    /// Check that the email addresses of the contacts in the BOM look like `local@domain` with a
+    /// dot in the domain, see [crate::models::organization::OrganizationalContact::validate_email].
+    /// The schema accepts addresses that fail this check, such as `support@partner`, so its
+    /// results are warnings, which are not part of [Validate::validate] or [Self::validate_strict].
+    pub fn validate_contact_emails(&self) -> ValidationResult {
+        let mut results: Vec<ValidationResult> = vec![];
+        let context = ValidationContext::default();
 
-                    results.push(ValidationResult::Failed {
-                        reasons: vec![FailureReason {
-                            message: "Dependency reference does not exist in the BOM".to_string(),
-                            context: dependency_context,
-                        }],
-                    })
+        if let Some(metadata) = &self.metadata {
+            let context = context.extend_context_with_struct_field("Bom", "metadata");
+            for (index, author) in metadata.authors.iter().flatten().enumerate() {
+                results.push(
+                    author.validate_email(&field_item_context(
+                        &context, "Metadata", "authors", index,
+                    )),
+                );
+            }
+            for (field_name, entity) in [
+                ("manufacture", &metadata.manufacture),
+                ("supplier", &metadata.supplier),
+            ] {
+                if let Some(entity) = entity {
+                    results.push(entity.validate_contact_emails(
+                        &context.extend_context_with_struct_field("Metadata", field_name),
+                    ));
                 }
+            }
+            if let Some(component) = &metadata.component {
+                let context = context.extend_context_with_struct_field("Metadata", "component");
+                validate_component_contact_emails(component, &context, &mut results);
+            }
+        }
 
-                for (sub_dependency_index, sub_dependency) in
-                    dependency.dependencies.iter().enumerate()
-                {
-                    if !bom_refs_context.contains(sub_dependency) {
-                        let context = context.extend_context(vec![
-                            ValidationPathComponent::Struct {
-                                struct_name: "Dependency".to_string(),
-                                field_name: "dependencies".to_string(),
-                            },
-                            ValidationPathComponent::Array {
-                                index: sub_dependency_index,
-                            },
-                        ]);
+        for (index, component) in self.components.iter().flat_map(|c| c.0.iter()).enumerate() {
+            let context = field_item_context(&context, "Bom", "components", index);
+            validate_component_contact_emails(component, &context, &mut results);
+        }
 
-                        results.push(ValidationResult::Failed {
-                            reasons: vec![FailureReason {
-                                message: "Dependency reference does not exist in the BOM"
-                                    .to_string(),
-                                context,
-                            }],
-                        })
-                    }
-                }
+        for (index, service) in self.services.iter().flat_map(|s| s.0.iter()).enumerate() {
+            let context = field_item_context(&context, "Bom", "services", index);
+            validate_service_contact_emails(service, &context, &mut results);
+        }
+
+        for (index, vulnerability) in self
+            .vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .enumerate()
+        {
+            let credits = match &vulnerability.vulnerability_credits {
+                Some(credits) => credits,
+                None => continue,
+            };
+            let context = field_item_context(&context, "Bom", "vulnerabilities", index)
+                .extend_context_with_struct_field("Vulnerability", "vulnerability_credits");
+            for (index, organization) in credits.organizations().iter().enumerate() {
+                results.push(organization.validate_contact_emails(&field_item_context(
+                    &context,
+                    "VulnerabilityCredits",
+                    "organizations",
+                    index,
+                )));
+            }
+            for (index, individual) in credits.individuals().iter().enumerate() {
+                results.push(individual.validate_email(&field_item_context(
+                    &context,
+                    "VulnerabilityCredits",
+                    "individuals",
+                    index,
+                )));
             }
         }
 
-        if let Some(compositions) = &self.compositions {
-            let context = context.extend_context_with_struct_field("Bom", "compositions");
-            let compositions_context = context.clone();
+        results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
 
-            results.push(compositions.validate_with_context(context)?);
+    /// Validate the BOM like [Validate::validate], and additionally enforce rules that
+    /// the specification leaves optional but many consumers rely on:
+    ///
+    /// - `metadata.component` describes the subject of the BOM
+    pub fn validate_strict(&self) -> Result<ValidationResult, ValidationError> {
+        let result = self.validate()?;
 
-            for (composition_index, composition) in compositions.0.iter().enumerate() {
-                let compositions_context =
-                    compositions_context.extend_context(vec![ValidationPathComponent::Array {
-                        index: composition_index,
-                    }]);
+        let has_subject = self
+            .metadata
+            .as_ref()
+            .is_some_and(|m| m.component.is_some());
+        if has_subject {
+            return Ok(result);
+        }
 
-                if let Some(assemblies) = &composition.assemblies {
-                    let compositions_context = compositions_context
-                        .extend_context_with_struct_field("Composition", "assemblies");
-                    for (assembly_index, BomReference(assembly)) in assemblies.iter().enumerate() {
-                        if !bom_refs_context.contains(assembly) {
-                            let compositions_context = compositions_context.extend_context(vec![
-                                ValidationPathComponent::Array {
-                                    index: assembly_index,
-                                },
-                            ]);
-                            results.push(ValidationResult::Failed {
-                                reasons: vec![FailureReason {
-                                    message: "Composition reference does not exist in the BOM"
-                                        .to_string(),
-                                    context: compositions_context,
-                                }],
-                            });
-                        }
-                    }
-                }
+        let context = ValidationContext::default()
+            .extend_context_with_struct_field("Bom", "metadata")
+            .extend_context_with_struct_field("Metadata", "component");
+        Ok(result.merge(ValidationResult::Failed {
+            reasons: vec![FailureReason {
+                message: "Metadata component describing the subject of the BOM is missing"
+                    .to_string(),
+                context,
+            }],
+        }))
+    }
 
-                if let Some(dependencies) = &composition.dependencies {
-                    let compositions_context = compositions_context
-                        .extend_context_with_struct_field("Composition", "dependencies");
-                    for (dependency_index, BomReference(dependency)) in
-                        dependencies.iter().enumerate()
-                    {
-                        if !bom_refs_context.contains(dependency) {
-                            let compositions_context = compositions_context.extend_context(vec![
-                                ValidationPathComponent::Array {
-                                    index: dependency_index,
-                                },
-                            ]);
-                            results.push(ValidationResult::Failed {
-                                reasons: vec![FailureReason {
-                                    message: "Composition reference does not exist in the BOM"
-                                        .to_string(),
-                                    context: compositions_context,
-                                }],
-                            });
-                        }
-                    }
-                }
-            }
-        }
+    /// Check that the Package URL of each component, where it can be parsed, refers to the
+    /// same name and version as the component itself, returning every
+    /// [ValidationError::PurlMismatch] found.
+    ///
+    /// This is stricter than [Validate::validate], which only compares the version of
+    /// `pkg:cargo` Package URLs, as components named after a Cargo target or renamed keep the
+    /// crate name in their purl.
+    pub fn validate_purl_consistency(&self) -> Vec<ValidationError> {
+        collect_purl_mismatches(
+            self,
+            PurlComparison::NameAndVersion,
+            &ValidationContext::default(),
+        )
+    }
 
-        if let Some(properties) = &self.properties {
-            let context = context.extend_context_with_struct_field("Bom", "properties");
+    /// Replaces the metadata of the BOM
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
 
-            results.push(properties.validate_with_context(context)?);
-        }
+    /// The metadata of the BOM, which is created empty, without a timestamp, if it is absent
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        self.metadata.get_or_insert_with(Metadata::default)
+    }
+
+    /// The first component with the given name, and version if one is given, looking at the
+    /// metadata component, then the components and their nested components in document order
+    pub fn find_component(&self, name: &str, version: Option<&str>) -> Option<&Component> {
+        self.find_component_where(&|component| {
+            let version_matches = match version {
+                Some(version) => component
+                    .version
+                    .as_ref()
+                    .is_some_and(|v| v.as_ref() == version),
+                None => true,
+            };
+            component.name.as_ref() == name && version_matches
+        })
+    }
+
+    /// The component a bom-ref, e.g. of a dependency, refers to, looking at the metadata
+    /// component, then the components and their nested components in document order.
+    ///
+    /// Should several components share the bom-ref, the first one is returned,
+    /// see [Self::duplicate_bom_refs] to find those. Services are looked up with
+    /// [Self::service_by_ref].
+    pub fn component_by_ref(&self, bom_ref: &str) -> Option<&Component> {
+        self.find_component_where(&|component| component.bom_ref.as_deref() == Some(bom_ref))
+    }
+
+    /// The service a bom-ref refers to, looking at the services and their nested services
+    /// in document order, the first one if several share the bom-ref
+    pub fn service_by_ref(&self, bom_ref: &str) -> Option<&Service> {
+        self.services
+            .iter()
+            .flat_map(|s| s.0.iter())
+            .find_map(|service| find_service_by_ref(service, bom_ref))
+    }
+
+    /// The vulnerabilities that affect the component or service with the given bom-ref,
+    /// i.e. have a target referring to it, in document order.
+    ///
+    /// Targets are matched on the exact bom-ref only. The versions or version ranges a target
+    /// lists are not compared with the version of the component, see
+    /// [crate::models::vulnerability_target::VersionRange::contains] to do that.
+    pub fn vulnerabilities_for(&self, bom_ref: &str) -> Vec<&Vulnerability> {
+        self.vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .filter(|vulnerability| {
+                vulnerability
+                    .vulnerability_targets
+                    .iter()
+                    .flat_map(|t| t.0.iter())
+                    .any(|target| target.bom_ref == bom_ref)
+            })
+            .collect()
+    }
+
+    fn find_component_where(&self, predicate: &impl Fn(&Component) -> bool) -> Option<&Component> {
+        let metadata_component = self.metadata.as_ref().and_then(|m| m.component.as_ref());
+        metadata_component
+            .into_iter()
+            .chain(self.components.iter().flat_map(|c| c.0.iter()))
+            .find_map(|component| find_component_in(component, predicate))
+    }
 
+    /// The bom-refs that occur more than once among the metadata component, the components,
+    /// the services, including nested ones, and the vulnerabilities, in order of their first
+    /// occurrence
+    pub fn duplicate_bom_refs(&self) -> Vec<DuplicateBomRef> {
+        let mut occurrences: Vec<(&str, ValidationContext)> = Vec::new();
+        let context = ValidationContext::default();
+
+        if let Some(component) = self.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            let context = context
+                .extend_context_with_struct_field("Bom", "metadata")
+                .extend_context_with_struct_field("Metadata", "component");
+            collect_component_bom_refs(component, context, &mut occurrences);
+        }
+        if let Some(components) = &self.components {
+            let context = context.extend_context_with_struct_field("Bom", "components");
+            for (index, component) in components.0.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                collect_component_bom_refs(component, context, &mut occurrences);
+            }
+        }
+        if let Some(services) = &self.services {
+            let context = context.extend_context_with_struct_field("Bom", "services");
+            for (index, service) in services.0.iter().enumerate() {
+                let context =
+                    context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                collect_service_bom_refs(service, context, &mut occurrences);
+            }
+        }
         if let Some(vulnerabilities) = &self.vulnerabilities {
             let context = context.extend_context_with_struct_field("Bom", "vulnerabilities");
-            results.push(vulnerabilities.validate_with_context(context)?);
+            for (index, vulnerability) in vulnerabilities.0.iter().enumerate() {
+                if let Some(bom_ref) = &vulnerability.bom_ref {
+                    let context =
+                        context.extend_context(vec![ValidationPathComponent::Array { index }]);
+                    occurrences.push((bom_ref, context));
+                }
+            }
         }
 
-        Ok(results
-            .into_iter()
-            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+        let mut duplicates: Vec<DuplicateBomRef> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for (bom_ref, context) in occurrences {
+            match positions.get(bom_ref) {
+                Some(&position) => duplicates[position].paths.push(context),
+                None => {
+                    positions.insert(bom_ref, duplicates.len());
+                    duplicates.push(DuplicateBomRef {
+                        bom_ref: bom_ref.to_string(),
+                        paths: vec![context],
+                    });
+                }
+            }
+        }
+        duplicates.retain(|duplicate| duplicate.paths.len() > 1);
+        duplicates
     }
-}
 
-#[derive(Default)]
-struct BomReferencesContext {
-    component_bom_refs: HashSet<String>,
-    service_bom_refs: HashSet<String>,
-}
+    /// Merge `other` into this BOM, e.g. to assemble the SBOM of a product from those of its parts.
+    ///
+    /// Components are the same if they share their bom-ref or purl, services if they share their
+    /// bom-ref. Of those contained in both BOMs, `strategy` decides which one to keep, and the
+    /// dependencies, vulnerability targets and composition references of the other one are moved
+    /// to it. Dependencies and external references are united, as are vulnerabilities, keeping
+    /// the analysis state of this BOM on a conflict, unless `strategy` is [MergeStrategy::Error],
+    /// which fails with the conflicting analysis states.
+    ///
+    /// The metadata of this BOM is kept, while the metadata component of `other` becomes a
+    /// component unless it is the same as the metadata component of this BOM.
+    /// The version, serial number, compositions and properties of this BOM are kept as well,
+    /// and signatures are dropped as they no longer match the content.
+    ///
+    /// Nested components are not compared, so that the merged BOM can still contain duplicate
+    /// bom-refs, which are returned as [MergeError::DuplicateBomRefs].
+    pub fn merge(self, other: Bom, strategy: MergeStrategy) -> Result<Bom, MergeError> {
+        let mut self_renames = HashMap::new();
+        let mut other_renames = HashMap::new();
 
-impl BomReferencesContext {
+        let (metadata, other_root) = match (self.metadata, other.metadata) {
+            (Some(metadata), other) => (Some(metadata), other.and_then(|m| m.component)),
+            (None, other) => (other, None),
+        };
+        let root = metadata.as_ref().and_then(|m| m.component.as_ref());
+
+        let mut components = self.components.map(|c| c.0).unwrap_or_default();
+        let incoming_components = other_root
+            .into_iter()
+            .chain(other.components.into_iter().flat_map(|c| c.0));
+        for incoming in incoming_components {
+            if let Some(root) = root.filter(|root| is_same_component(root, &incoming)) {
+                record_rename(&incoming.bom_ref, &root.bom_ref, &mut other_renames);
+                continue;
+            }
+            let existing = components
+                .iter_mut()
+                .find(|existing| is_same_component(existing, &incoming));
+            match (existing, strategy) {
+                (None, _) => components.push(incoming),
+                (Some(existing), MergeStrategy::KeepFirst) => {
+                    record_rename(&incoming.bom_ref, &existing.bom_ref, &mut other_renames);
+                }
+                (Some(existing), MergeStrategy::KeepLast) => {
+                    record_rename(&existing.bom_ref, &incoming.bom_ref, &mut self_renames);
+                    *existing = incoming;
+                }
+                (Some(_), MergeStrategy::Error) => {
+                    let key = match (&incoming.bom_ref, &incoming.purl) {
+                        (Some(bom_ref), _) => bom_ref.clone(),
+                        (None, purl) => purl.as_ref().map(ToString::to_string).unwrap_or_default(),
+                    };
+                    return Err(MergeError::ComponentConflict(key));
+                }
+            }
+        }
+
+        let mut services = self.services.map(|s| s.0).unwrap_or_default();
+        for incoming in other.services.into_iter().flat_map(|s| s.0) {
+            let existing = services.iter_mut().find(|existing| {
+                existing.bom_ref.is_some() && existing.bom_ref == incoming.bom_ref
+            });
+            match (existing, strategy) {
+                (None, _) => services.push(incoming),
+                (Some(_), MergeStrategy::KeepFirst) => (),
+                (Some(existing), MergeStrategy::KeepLast) => *existing = incoming,
+                (Some(existing), MergeStrategy::Error) => {
+                    return Err(MergeError::ServiceConflict(
+                        existing.bom_ref.clone().unwrap_or_default(),
+                    ));
+                }
+            }
+        }
+
+        let self_dependencies = self
+            .dependencies
+            .into_iter()
+            .flat_map(|d| d.0)
+            .map(|d| renamed_dependency(d, &self_renames));
+        let other_dependencies = other
+            .dependencies
+            .into_iter()
+            .flat_map(|d| d.0)
+            .map(|d| renamed_dependency(d, &other_renames));
+        let dependencies = united_dependencies(self_dependencies.chain(other_dependencies));
+
+        let mut external_references = self.external_references.map(|e| e.0).unwrap_or_default();
+        for reference in other.external_references.into_iter().flat_map(|e| e.0) {
+            if !external_references.contains(&reference) {
+                external_references.push(reference);
+            }
+        }
+
+        let mut self_vulnerabilities = self.vulnerabilities;
+        let mut other_vulnerabilities = other.vulnerabilities;
+        if let Some(vulnerabilities) = &mut self_vulnerabilities {
+            rename_vulnerability_targets(vulnerabilities, &self_renames);
+        }
+        if let Some(vulnerabilities) = &mut other_vulnerabilities {
+            rename_vulnerability_targets(vulnerabilities, &other_renames);
+        }
+        let vulnerabilities = match (self_vulnerabilities, other_vulnerabilities) {
+            (Some(mut vulnerabilities), Some(other)) => {
+                let conflicts = vulnerabilities.merge(other);
+                if strategy == MergeStrategy::Error && !conflicts.is_empty() {
+                    return Err(MergeError::AnalysisStateConflicts(conflicts));
+                }
+                Some(vulnerabilities)
+            }
+            (vulnerabilities, other) => vulnerabilities.or(other),
+        };
+
+        let mut compositions = self.compositions;
+        if let Some(compositions) = &mut compositions {
+            rename_composition_refs(compositions, &self_renames);
+        }
+
+        let merged = Bom {
+            version: self.version,
+            serial_number: self.serial_number,
+            metadata,
+            components: (!components.is_empty()).then_some(Components(components)),
+            services: (!services.is_empty()).then_some(Services(services)),
+            external_references: (!external_references.is_empty())
+                .then_some(ExternalReferences(external_references)),
+            dependencies: (!dependencies.is_empty()).then_some(Dependencies(dependencies)),
+            compositions,
+            properties: self.properties,
+            vulnerabilities,
+            signature: None,
+            foreign_extensions: self
+                .foreign_extensions
+                .into_iter()
+                .chain(other.foreign_extensions)
+                .collect(),
+        };
+
+        let duplicates = merged.duplicate_bom_refs();
+        if !duplicates.is_empty() {
+            return Err(MergeError::DuplicateBomRefs(duplicates));
+        }
+        Ok(merged)
+    }
+
+    /// Collapse the top-level components that share their purl or, lacking one, their group,
+    /// name and version, returning the number of components removed.
+    ///
+    /// The first occurrence survives, gaining the hashes and properties of its duplicates,
+    /// and dependencies, vulnerability targets and composition references of the duplicates
+    /// are moved to it.
+    pub fn deduplicate(&mut self) -> usize {
+        let components = match self.components.take() {
+            Some(components) => components.0,
+            None => return 0,
+        };
+
+        let mut survivors: Vec<Component> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut renames = HashMap::new();
+        let mut removed = 0;
+        for component in components {
+            let key = deduplication_key(&component);
+            match positions.get(&key) {
+                Some(&position) => {
+                    let survivor = &mut survivors[position];
+                    record_rename(&component.bom_ref, &survivor.bom_ref, &mut renames);
+                    absorb_duplicate(survivor, component);
+                    removed += 1;
+                }
+                None => {
+                    positions.insert(key, survivors.len());
+                    survivors.push(component);
+                }
+            }
+        }
+        self.components = Some(Components(survivors));
+
+        if let Some(dependencies) = self.dependencies.take() {
+            let renamed = dependencies
+                .0
+                .into_iter()
+                .map(|d| renamed_dependency(d, &renames));
+            self.dependencies = Some(Dependencies(united_dependencies(renamed)));
+        }
+        if let Some(vulnerabilities) = &mut self.vulnerabilities {
+            rename_vulnerability_targets(vulnerabilities, &renames);
+        }
+        if let Some(compositions) = &mut self.compositions {
+            rename_composition_refs(compositions, &renames);
+        }
+
+        removed
+    }
+
+    /// Takes the components out of the BOM, flattening nested components into the list.
+    ///
+    /// Each component is followed by its nested components, which are removed from it,
+    /// so that every component occurs once. The metadata component is not included.
+    pub fn into_components(self) -> Vec<Component> {
+        fn flatten(mut component: Component, flattened: &mut Vec<Component>) {
+            let nested = component.components.take();
+            flattened.push(component);
+            for nested_component in nested.into_iter().flat_map(|c| c.0) {
+                flatten(nested_component, flattened);
+            }
+        }
+
+        let mut flattened = Vec::new();
+        for component in self.components.into_iter().flat_map(|c| c.0) {
+            flatten(component, &mut flattened);
+        }
+        flattened
+    }
+
+    /// Takes the metadata, components, services, dependencies and vulnerabilities out of the
+    /// BOM. Unlike [Self::into_components], nested components and services are kept in place.
+    pub fn into_parts(self) -> BomParts {
+        BomParts {
+            metadata: self.metadata,
+            components: self.components.map(|c| c.0).unwrap_or_default(),
+            services: self.services.map(|s| s.0).unwrap_or_default(),
+            dependencies: self.dependencies.map(|d| d.0).unwrap_or_default(),
+            vulnerabilities: self.vulnerabilities.map(|v| v.0).unwrap_or_default(),
+        }
+    }
+
+    /// Components, including nested ones, that do not declare any license and are
+    /// needed at runtime, i.e. whose scope is not `excluded`
+    pub fn components_without_licenses(&self) -> Vec<&Component> {
+        let mut unlicensed = Vec::new();
+        if let Some(components) = &self.components {
+            collect_components_without_licenses(components, false, &mut unlicensed);
+        }
+        unlicensed
+    }
+
+    /// Same as [Self::components_without_licenses], but also considers components
+    /// that are excluded from the runtime
+    pub fn components_without_licenses_in_any_scope(&self) -> Vec<&Component> {
+        let mut unlicensed = Vec::new();
+        if let Some(components) = &self.components {
+            collect_components_without_licenses(components, true, &mut unlicensed);
+        }
+        unlicensed
+    }
+
+    /// All external references in the BOM, together with the bom-ref of the component or
+    /// service they belong to. References of the BOM itself, and of components or services
+    /// without a bom-ref, are paired with an empty string.
+    pub fn all_external_references(&self) -> impl Iterator<Item = (&str, &ExternalReference)> {
+        let mut references = Vec::new();
+        collect_external_references("", &self.external_references, &mut references);
+        if let Some(component) = self.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+            collect_component_external_references(component, &mut references);
+        }
+        for component in self.components.iter().flat_map(|c| c.0.iter()) {
+            collect_component_external_references(component, &mut references);
+        }
+        for service in self.services.iter().flat_map(|s| s.0.iter()) {
+            collect_service_external_references(service, &mut references);
+        }
+        references.into_iter()
+    }
+
+    /// The bom-ref of the metadata component, which describes the subject of the BOM and is the
+    /// root of the dependency graph.
+    ///
+    /// Returns `None` if there is no metadata component or it has no bom-ref, in which case one
+    /// has to be assigned to it before the dependency graph can be traversed from the root.
+    pub fn root_bom_ref(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.component.as_ref())
+            .and_then(|c| c.bom_ref.as_deref())
+    }
+
+    /// The minimum number of dependency edges from the metadata component to each bom-ref
+    /// reachable from it, the metadata component itself being at depth 0.
+    ///
+    /// References that cannot be reached from the metadata component are omitted, and the map
+    /// is empty if the metadata component has no bom-ref.
+    pub fn dependency_depths(&self) -> HashMap<String, usize> {
+        let mut depths = HashMap::new();
+        let root = match self.root_bom_ref() {
+            Some(root) => root,
+            None => return depths,
+        };
+
+        let edges: HashMap<&str, &Vec<String>> = self
+            .dependencies
+            .iter()
+            .flat_map(|d| d.0.iter())
+            .map(|d| (d.dependency_ref.as_str(), &d.dependencies))
+            .collect();
+
+        let mut queue = VecDeque::from([root]);
+        depths.insert(root.to_string(), 0);
+        while let Some(current) = queue.pop_front() {
+            let depth = depths[current];
+            for dependency in edges.get(current).into_iter().flat_map(|d| d.iter()) {
+                if !depths.contains_key(dependency) {
+                    depths.insert(dependency.clone(), depth + 1);
+                    queue.push_back(dependency);
+                }
+            }
+        }
+        depths
+    }
+
+    /// Bring the BOM into a canonical form, so that BOMs describing the same content compare
+    /// equal regardless of the tool that produced them.
+    ///
+    /// - empty collections are removed
+    /// - components, services, dependencies, properties, external references and hashes are
+    ///   sorted
+    /// - bom-refs are derived from the purl, or the name and version, of a component or service
+    ///   and all references to them are updated
+    ///
+    /// Normalizing an already normalized BOM does not change it.
+    pub fn normalize(&mut self) {
+        let mut bom_refs = BomReferenceNormalizer::default();
+
+        if let Some(metadata) = &mut self.metadata {
+            if let Some(component) = &mut metadata.component {
+                normalize_component(component, &mut bom_refs);
+            }
+            normalize_properties(&mut metadata.properties);
+        }
+        normalize_components(&mut self.components, &mut bom_refs);
+        normalize_services(&mut self.services, &mut bom_refs);
+        normalize_external_references(&mut self.external_references);
+        normalize_properties(&mut self.properties);
+
+        if let Some(dependencies) = &mut self.dependencies {
+            for dependency in dependencies.0.iter_mut() {
+                dependency.dependency_ref = bom_refs.resolve(&dependency.dependency_ref);
+                for sub_dependency in dependency.dependencies.iter_mut() {
+                    *sub_dependency = bom_refs.resolve(sub_dependency);
+                }
+                dependency.dependencies.sort();
+                dependency.dependencies.dedup();
+            }
+            dependencies
+                .0
+                .sort_by(|a, b| a.dependency_ref.cmp(&b.dependency_ref));
+        }
+        strip_if_empty(&mut self.dependencies, |d| d.0.is_empty());
+
+        if let Some(compositions) = &mut self.compositions {
+            for composition in compositions.0.iter_mut() {
+                for references in [&mut composition.assemblies, &mut composition.dependencies] {
+                    for BomReference(reference) in references.iter_mut().flatten() {
+                        *reference = bom_refs.resolve(reference);
+                    }
+                }
+            }
+        }
+        strip_if_empty(&mut self.compositions, |c| c.0.is_empty());
+
+        if let Some(vulnerabilities) = &mut self.vulnerabilities {
+            for vulnerability in vulnerabilities.0.iter_mut() {
+                for target in vulnerability
+                    .vulnerability_targets
+                    .iter_mut()
+                    .flat_map(|t| t.0.iter_mut())
+                {
+                    target.bom_ref = bom_refs.resolve(&target.bom_ref);
+                }
+                normalize_properties(&mut vulnerability.properties);
+            }
+        }
+        strip_if_empty(&mut self.vulnerabilities, |v| v.0.is_empty());
+    }
+}
+
+/// The version of the specification a JSON document names in its `specVersion`, after
+/// checking that it is a CycloneDX document
+fn json_spec_version(json: &Value) -> Result<SpecVersion, crate::errors::JsonReadError> {
+    match json.get("bomFormat") {
+        Some(Value::String(format)) if format == "CycloneDX" => (),
+        Some(format) => return Err(BomError::UnsupportedBomFormat(format.to_string()).into()),
+        None => {
+            return Err(
+                BomError::UnsupportedBomFormat("No field 'bomFormat' found".to_string()).into(),
+            )
+        }
+    }
+
+    match json.get("specVersion") {
+        Some(version) => {
+            let version = version
+                .as_str()
+                .ok_or_else(|| BomError::UnsupportedSpecVersion(version.to_string()))?;
+            Ok(SpecVersion::from_str(version)?)
+        }
+        None => {
+            Err(BomError::UnsupportedSpecVersion("No field 'specVersion' found".to_string()).into())
+        }
+    }
+}
+
+/// Rejects a document whose components or services are nested deeper than allowed, before
+/// they are read recursively
+fn check_json_nesting(
+    json: &Value,
+    max_nesting: usize,
+) -> Result<(), crate::errors::JsonReadError> {
+    let depth = json_nesting_depth(json);
+    if depth > max_nesting {
+        return Err(crate::errors::JsonReadError::MaxDepthExceeded { depth });
+    }
+    Ok(())
+}
+
+/// How deeply components or services are nested, counting those at the top level as 1
+fn json_nesting_depth(value: &Value) -> usize {
+    match value {
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(key, value)| {
+                let depth = json_nesting_depth(value);
+                match (key.as_str(), value) {
+                    ("component", Value::Object(_)) => depth + 1,
+                    (
+                        "components" | "services" | "ancestors" | "descendants" | "variants",
+                        Value::Array(_),
+                    ) => depth + 1,
+                    _ => depth,
+                }
+            })
+            .max()
+            .unwrap_or(0),
+        Value::Array(items) => items.iter().map(json_nesting_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Reads the document along with the paths of the fields that were skipped, as whatever
+/// serde skipped is missing when the document is written back
+fn read_json_checked<B: serde::de::DeserializeOwned + Serialize>(
+    json: &Value,
+) -> Result<(B, Vec<String>), crate::errors::JsonReadError> {
+    let bom = B::deserialize(json)?;
+    let output = serde_json::to_value(&bom)?;
+    let mut unknown_fields = Vec::new();
+    collect_unknown_fields(json, &output, "", &mut unknown_fields);
+    Ok((bom, unknown_fields))
+}
+
+/// Collects the paths of the fields of `input` that have no counterpart in `output`, e.g.
+/// `components[1].unknown`. Fields that are `null` and the `$schema` of the document are
+/// ignored.
+fn collect_unknown_fields(input: &Value, output: &Value, path: &str, unknown: &mut Vec<String>) {
+    match (input, output) {
+        (Value::Object(input), Value::Object(output)) => {
+            for (key, value) in input {
+                if value.is_null() || (path.is_empty() && key == "$schema") {
+                    continue;
+                }
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                match output.get(key) {
+                    Some(counterpart) => collect_unknown_fields(value, counterpart, &path, unknown),
+                    None => unknown.push(path),
+                }
+            }
+        }
+        (Value::Array(input), Value::Array(output)) => {
+            for (index, value) in input.iter().enumerate() {
+                let path = format!("{path}[{index}]");
+                match output.get(index) {
+                    Some(counterpart) => collect_unknown_fields(value, counterpart, &path, unknown),
+                    None => unknown.push(path),
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PathStyle {
+    Json,
+    Xml,
+}
+
+/// Warnings about the component types and scopes that are kept, but are not part of the
+/// specification
+fn unknown_value_warnings(bom: &Bom, style: PathStyle) -> Vec<ParseWarning> {
+    let mut warnings = Vec::new();
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        let path = match style {
+            PathStyle::Json => "metadata.component".to_string(),
+            PathStyle::Xml => "bom/metadata/component".to_string(),
+        };
+        collect_unknown_value_warnings(component, path, style, &mut warnings);
+    }
+    if let Some(components) = &bom.components {
+        let root = match style {
+            PathStyle::Json => "",
+            PathStyle::Xml => "bom",
+        };
+        collect_components_unknown_value_warnings(components, root, style, &mut warnings);
+    }
+    warnings
+}
+
+fn collect_components_unknown_value_warnings(
+    components: &Components,
+    parent: &str,
+    style: PathStyle,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    for (index, component) in components.0.iter().enumerate() {
+        let path = match (style, parent.is_empty()) {
+            (PathStyle::Json, true) => format!("components[{index}]"),
+            (PathStyle::Json, false) => format!("{parent}.components[{index}]"),
+            (PathStyle::Xml, _) if index == 0 => format!("{parent}/components/component"),
+            (PathStyle::Xml, _) => format!("{parent}/components/component[{index}]"),
+        };
+        collect_unknown_value_warnings(component, path, style, warnings);
+    }
+}
+
+fn collect_unknown_value_warnings(
+    component: &Component,
+    path: String,
+    style: PathStyle,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    let separator = match style {
+        PathStyle::Json => ".",
+        PathStyle::Xml => "/",
+    };
+    if let Classification::UnknownClassification(value) = &component.component_type {
+        let field = match style {
+            PathStyle::Json => "type",
+            PathStyle::Xml => "@type",
+        };
+        warnings.push(ParseWarning::new(
+            format!("{path}{separator}{field}"),
+            format!("Unknown component type '{value}'"),
+        ));
+    }
+    if let Some(Scope::UnknownScope(value)) = &component.scope {
+        warnings.push(ParseWarning::new(
+            format!("{path}{separator}scope"),
+            format!("Unknown scope '{value}'"),
+        ));
+    }
+    if let Some(components) = &component.components {
+        collect_components_unknown_value_warnings(components, &path, style, warnings);
+    }
+}
+
+/// Reads the document along with the paths of the elements and attributes that were
+/// skipped, as whatever the reader skipped is missing when the document is written back
+fn read_xml_checked<B: FromXmlDocument + ToXml>(
+    content: &[u8],
+    limits: &XmlLimits,
+) -> Result<(XmlElement, B, Vec<String>), crate::errors::XmlReadError> {
+    let config = limits.parser_config();
+    let input = XmlElement::read_document(&mut EventReader::new_with_config(content, config))?;
+    let bom = read_document::<_, B>(&mut EventReader::new_with_config(
+        content,
+        limits.parser_config(),
+    ))?;
+
+    let mut output = Vec::new();
+    bom.write_xml_element(&mut EventWriter::new(&mut output))
+        .map_err(xml_rewrite_error)?;
+    let config = ParserConfig::default().trim_whitespace(true);
+    let output = XmlElement::read_document(&mut EventReader::new_with_config(&output[..], config))?;
+    let unmatched = input.unmatched(&output);
+    Ok((input, bom, unmatched))
+}
+
+fn xml_rewrite_error(error: crate::errors::XmlWriteError) -> crate::errors::XmlReadError {
+    crate::errors::XmlReadError::UnexpectedElementReadError {
+        error: format!("Failed to write the document back: {error}"),
+        element: "bom".to_string(),
+        position: None,
+    }
+}
+
+fn component_conversion_warnings(
+    component: &Component,
+    context: ValidationContext,
+    dropped: &mut impl FnMut(bool, SpecVersion, ValidationContext),
+) {
+    dropped(
+        component.signature.is_some(),
+        SpecVersion::V1_4,
+        context.extend_context_with_struct_field("Component", "signature"),
+    );
+    dropped(
+        component.tags.is_some(),
+        SpecVersion::V1_6,
+        context.extend_context_with_struct_field("Component", "tags"),
+    );
+    dropped(
+        !component.omnibor_ids.is_empty(),
+        SpecVersion::V1_6,
+        context.extend_context_with_struct_field("Component", "omnibor_ids"),
+    );
+    dropped(
+        !component.swhids.is_empty(),
+        SpecVersion::V1_6,
+        context.extend_context_with_struct_field("Component", "swhids"),
+    );
+    if let Some(licenses) = &component.licenses {
+        let context = context.extend_context_with_struct_field("Component", "licenses");
+        licenses_conversion_warnings(licenses, context, dropped);
+    }
+
+    let mut nested = vec![(
+        context.extend_context_with_struct_field("Component", "components"),
+        component.components.as_ref(),
+    )];
+    if let Some(pedigree) = &component.pedigree {
+        let context = context.extend_context_with_struct_field("Component", "pedigree");
+        nested.push((
+            context.extend_context_with_struct_field("Pedigree", "ancestors"),
+            pedigree.ancestors.as_ref(),
+        ));
+        nested.push((
+            context.extend_context_with_struct_field("Pedigree", "descendants"),
+            pedigree.descendants.as_ref(),
+        ));
+        nested.push((
+            context.extend_context_with_struct_field("Pedigree", "variants"),
+            pedigree.variants.as_ref(),
+        ));
+    }
+    for (context, components) in nested {
+        for (index, component) in components.iter().flat_map(|c| c.0.iter()).enumerate() {
+            let context = context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            component_conversion_warnings(component, context, dropped);
+        }
+    }
+}
+
+fn service_conversion_warnings(
+    service: &Service,
+    context: ValidationContext,
+    dropped: &mut impl FnMut(bool, SpecVersion, ValidationContext),
+) {
+    dropped(
+        service.signature.is_some(),
+        SpecVersion::V1_4,
+        context.extend_context_with_struct_field("Service", "signature"),
+    );
+    if let Some(licenses) = &service.licenses {
+        let context = context.extend_context_with_struct_field("Service", "licenses");
+        licenses_conversion_warnings(licenses, context, dropped);
+    }
+    for (index, service) in service.services.iter().flat_map(|s| s.0.iter()).enumerate() {
+        let context = context
+            .extend_context_with_struct_field("Service", "services")
+            .extend_context(vec![ValidationPathComponent::Array { index }]);
+        service_conversion_warnings(service, context, dropped);
+    }
+}
+
+fn licenses_conversion_warnings(
+    licenses: &Licenses,
+    context: ValidationContext,
+    dropped: &mut impl FnMut(bool, SpecVersion, ValidationContext),
+) {
+    for (index, license) in licenses.0.iter().enumerate() {
+        if let LicenseChoice::License(license) = license {
+            dropped(
+                license.acknowledgement.is_some(),
+                SpecVersion::V1_6,
+                context.extend_context(vec![
+                    ValidationPathComponent::Array { index },
+                    ValidationPathComponent::EnumVariant {
+                        variant_name: "License".to_string(),
+                    },
+                    ValidationPathComponent::Struct {
+                        struct_name: "License".to_string(),
+                        field_name: "acknowledgement".to_string(),
+                    },
+                ]),
+            );
+        }
+    }
+}
+
+impl Default for Bom {
+    /// Construct a BOM with a default `version` of `1` and `serial_number` with a random UUID
+    fn default() -> Self {
+        Self {
+            version: 1,
+            serial_number: Some(UrnUuid::generate()),
+            metadata: None,
+            components: None,
+            services: None,
+            external_references: None,
+            dependencies: None,
+            compositions: None,
+            properties: None,
+            vulnerabilities: None,
+            signature: None,
+            foreign_extensions: Vec::new(),
+        }
+    }
+}
+
+/// Builds a [Bom] one part at a time, see [Bom::builder]
+#[derive(Clone, Debug, Default)]
+pub struct BomBuilder {
+    version: Option<u32>,
+    serial_number: Option<UrnUuid>,
+    metadata: Option<Metadata>,
+    components: Vec<Component>,
+    dependencies: Vec<Dependency>,
+}
+
+impl BomBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The version of the BOM, `1` if not set
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// The serial number of the BOM, a random UUID if not set
+    pub fn serial_number(mut self, serial_number: UrnUuid) -> Self {
+        self.serial_number = Some(serial_number);
+        self
+    }
+
+    pub fn metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Append a top-level component
+    pub fn add_component(mut self, component: Component) -> Self {
+        self.components.push(component);
+        self
+    }
+
+    /// Append the dependencies of a component or service
+    pub fn add_dependency(mut self, dependency: Dependency) -> Self {
+        self.dependencies.push(dependency);
+        self
+    }
+
+    /// Create the BOM, leaving out the lists nothing was added to
+    pub fn build(self) -> Bom {
+        Bom {
+            version: self.version.unwrap_or(1),
+            serial_number: Some(self.serial_number.unwrap_or_else(UrnUuid::generate)),
+            metadata: self.metadata,
+            components: (!self.components.is_empty()).then_some(Components(self.components)),
+            dependencies: (!self.dependencies.is_empty())
+                .then_some(Dependencies(self.dependencies)),
+            ..Bom::default()
+        }
+    }
+}
+
+impl Validate for Bom {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        let mut bom_refs_context = BomReferencesContext::default();
+
+        if let Some(serial_number) = &self.serial_number {
+            let context = context.extend_context_with_struct_field("Bom", "serial_number");
+
+            results.push(serial_number.validate_with_context(context)?);
+        }
+
+        if let Some(metadata) = &self.metadata {
+            let context = context.extend_context_with_struct_field("Bom", "metadata");
+            let component_bom_ref_context =
+                context.extend_context_with_struct_field("Metadata", "component");
+
+            results.push(metadata.validate_with_context(context)?);
+
+            if let Some(component) = &metadata.component {
+                validate_component_bom_refs(
+                    component,
+                    &mut bom_refs_context,
+                    &component_bom_ref_context,
+                    &mut results,
+                );
+            }
+        }
+
+        if let Some(components) = &self.components {
+            let context = context.extend_context_with_struct_field("Bom", "components");
+            let component_bom_ref_context = context.clone();
+
+            results.push(components.validate_with_context(context)?);
+
+            // record the component references
+            validate_components(
+                components,
+                &mut bom_refs_context,
+                &component_bom_ref_context,
+                &mut results,
+            );
+        }
+
+        let purl_mismatches: Vec<FailureReason> =
+            collect_purl_mismatches(self, PurlComparison::CargoVersion, &context)
+                .into_iter()
+                .filter_map(purl_mismatch_failure)
+                .collect();
+        if !purl_mismatches.is_empty() {
+            results.push(ValidationResult::Failed {
+                reasons: purl_mismatches,
+            });
+        }
+
+        if let Some(services) = &self.services {
+            let context = context.extend_context_with_struct_field("Bom", "services");
+            let service_bom_ref_context = context.clone();
+
+            results.push(services.validate_with_context(context)?);
+
+            // record the service references
+            validate_services(
+                services,
+                &mut bom_refs_context,
+                &service_bom_ref_context,
+                &mut results,
+            );
+        }
+
+        if let Some(external_references) = &self.external_references {
+            let context = context.extend_context_with_struct_field("Bom", "external_references");
+
+            results.push(external_references.validate_with_context(context)?);
+        }
+
+        if let Some(dependencies) = &self.dependencies {
+            let context = context.extend_context_with_struct_field("Bom", "dependencies");
+
+            for (dependency_index, dependency) in dependencies.0.iter().enumerate() {
+                let context = context.extend_context(vec![ValidationPathComponent::Array {
+                    index: dependency_index,
+                }]);
+                if !bom_refs_context.contains(&dependency.dependency_ref) {
+                    let dependency_context =
+                        context.extend_context_with_struct_field("Dependency", "dependency_ref");
+
+                    results.push(ValidationResult::Failed {
+                        reasons: vec![FailureReason {
+                            message: "Dependency reference does not exist in the BOM".to_string(),
+                            context: dependency_context,
+                        }],
+                    })
+                }
+
+                for (sub_dependency_index, sub_dependency) in
+                    dependency.dependencies.iter().enumerate()
+                {
+                    if !bom_refs_context.contains(sub_dependency) {
+                        let context = context.extend_context(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Dependency".to_string(),
+                                field_name: "dependencies".to_string(),
+                            },
+                            ValidationPathComponent::Array {
+                                index: sub_dependency_index,
+                            },
+                        ]);
+
+                        results.push(ValidationResult::Failed {
+                            reasons: vec![FailureReason {
+                                message: "Dependency reference does not exist in the BOM"
+                                    .to_string(),
+                                context,
+                            }],
+                        })
+                    }
+                }
+            }
+        }
+
+        if let Some(compositions) = &self.compositions {
+            let context = context.extend_context_with_struct_field("Bom", "compositions");
+            let compositions_context = context.clone();
+
+            results.push(compositions.validate_with_context(context)?);
+
+            for (composition_index, composition) in compositions.0.iter().enumerate() {
+                let compositions_context =
+                    compositions_context.extend_context(vec![ValidationPathComponent::Array {
+                        index: composition_index,
+                    }]);
+
+                if let Some(assemblies) = &composition.assemblies {
+                    let compositions_context = compositions_context
+                        .extend_context_with_struct_field("Composition", "assemblies");
+                    for (assembly_index, BomReference(assembly)) in assemblies.iter().enumerate() {
+                        if !bom_refs_context.contains(assembly) {
+                            let compositions_context = compositions_context.extend_context(vec![
+                                ValidationPathComponent::Array {
+                                    index: assembly_index,
+                                },
+                            ]);
+                            results.push(ValidationResult::Failed {
+                                reasons: vec![FailureReason {
+                                    message: "Composition reference does not exist in the BOM"
+                                        .to_string(),
+                                    context: compositions_context,
+                                }],
+                            });
+                        }
+                    }
+                }
+
+                if let Some(dependencies) = &composition.dependencies {
+                    let compositions_context = compositions_context
+                        .extend_context_with_struct_field("Composition", "dependencies");
+                    for (dependency_index, BomReference(dependency)) in
+                        dependencies.iter().enumerate()
+                    {
+                        if !bom_refs_context.contains(dependency) {
+                            let compositions_context = compositions_context.extend_context(vec![
+                                ValidationPathComponent::Array {
+                                    index: dependency_index,
+                                },
+                            ]);
+                            results.push(ValidationResult::Failed {
+                                reasons: vec![FailureReason {
+                                    message: "Composition reference does not exist in the BOM"
+                                        .to_string(),
+                                    context: compositions_context,
+                                }],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(properties) = &self.properties {
+            let context = context.extend_context_with_struct_field("Bom", "properties");
+
+            results.push(properties.validate_with_context(context)?);
+        }
+
+        if let Some(vulnerabilities) = &self.vulnerabilities {
+            let context = context.extend_context_with_struct_field("Bom", "vulnerabilities");
+            results.push(vulnerabilities.validate_with_context(context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// Visits the top level of a JSON BOM, only descending into `components`
+struct ComponentStream<F>(F);
+
+impl<'de, F: FnMut(Component)> Visitor<'de> for ComponentStream<F> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a CycloneDX BOM object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<Self::Value, A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "components" {
+                map.next_value_seed(ComponentSeq(&mut self.0))?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the elements of the `components` array one by one.
+/// The most recent version of the specification is used as it can read components of all
+/// earlier versions.
+struct ComponentSeq<'a, F>(&'a mut F);
+
+impl<'de, 'a, F: FnMut(Component)> DeserializeSeed<'de> for ComponentSeq<'a, F> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, 'a, F: FnMut(Component)> Visitor<'de> for ComponentSeq<'a, F> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of components")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while let Some(component) =
+            seq.next_element::<crate::specs::v1_6::component::Component>()?
+        {
+            (self.0)(component.into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct BomReferencesContext {
+    component_bom_refs: HashSet<String>,
+    service_bom_refs: HashSet<String>,
+}
+
+impl BomReferencesContext {
     fn contains(&self, bom_ref: &String) -> bool {
         self.component_bom_refs.contains(bom_ref) || self.service_bom_refs.contains(bom_ref)
     }
 
-    fn add_component_bom_ref(&mut self, bom_ref: impl ToString) {
-        self.component_bom_refs.insert(bom_ref.to_string());
-    }
+    fn add_component_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.component_bom_refs.insert(bom_ref.to_string());
+    }
+
+    fn add_service_bom_ref(&mut self, bom_ref: impl ToString) {
+        self.service_bom_refs.insert(bom_ref.to_string());
+    }
+}
+
+fn validate_component_bom_refs(
+    component: &Component,
+    bom_refs: &mut BomReferencesContext,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    if let Some(bom_ref) = &component.bom_ref {
+        if bom_refs.contains(bom_ref) {
+            let context = context.extend_context_with_struct_field("Component", "bom_ref");
+            results.push(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
+                    context,
+                }],
+            });
+        }
+        bom_refs.add_component_bom_ref(bom_ref);
+    }
+
+    if let Some(components) = &component.components {
+        let context = context.extend_context_with_struct_field("Component", "components");
+        validate_components(components, bom_refs, &context, results);
+    }
+}
+
+fn validate_components(
+    components: &Components,
+    bom_refs: &mut BomReferencesContext,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    // record the component references
+    for (component_index, component) in components.0.iter().enumerate() {
+        let context = context.extend_context(vec![ValidationPathComponent::Array {
+            index: component_index,
+        }]);
+
+        validate_component_bom_refs(component, bom_refs, &context, results);
+    }
+}
+
+/// Which parts of a component the Package URL is compared with
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PurlComparison {
+    /// The version of `pkg:cargo` Package URLs. Names are not compared, as a component may be
+    /// named after a Cargo target or renamed, and other ecosystems are skipped, as their purl
+    /// conventions vary.
+    CargoVersion,
+    /// The name and version of any Package URL
+    NameAndVersion,
+}
+
+fn collect_component_purl_mismatches(
+    component: &Component,
+    comparison: PurlComparison,
+    context: &ValidationContext,
+    mismatches: &mut Vec<ValidationError>,
+) {
+    let purl = component
+        .purl
+        .as_ref()
+        .and_then(|p| p.components().ok())
+        .filter(|c| comparison == PurlComparison::NameAndVersion || c.package_type == "cargo");
+    if let Some(purl) = purl {
+        let context = context.extend_context_with_struct_field("Component", "purl");
+
+        if comparison == PurlComparison::NameAndVersion && purl.name != component.name.to_string() {
+            mismatches.push(ValidationError::PurlMismatch {
+                context: context.clone(),
+                field: "name".to_string(),
+                purl_value: purl.name,
+                component_value: component.name.to_string(),
+            });
+        }
+
+        if let (Some(purl_version), Some(component_version)) = (purl.version, &component.version) {
+            if purl_version != component_version.to_string() {
+                mismatches.push(ValidationError::PurlMismatch {
+                    context,
+                    field: "version".to_string(),
+                    purl_value: purl_version,
+                    component_value: component_version.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(components) = &component.components {
+        let context = context.extend_context_with_struct_field("Component", "components");
+        collect_components_purl_mismatches(components, comparison, &context, mismatches);
+    }
+}
+
+fn collect_components_purl_mismatches(
+    components: &Components,
+    comparison: PurlComparison,
+    context: &ValidationContext,
+    mismatches: &mut Vec<ValidationError>,
+) {
+    for (component_index, component) in components.0.iter().enumerate() {
+        let context = context.extend_context(vec![ValidationPathComponent::Array {
+            index: component_index,
+        }]);
+
+        collect_component_purl_mismatches(component, comparison, &context, mismatches);
+    }
+}
+
+fn collect_purl_mismatches(
+    bom: &Bom,
+    comparison: PurlComparison,
+    context: &ValidationContext,
+) -> Vec<ValidationError> {
+    let mut mismatches = vec![];
+
+    if let Some(component) = bom.metadata.as_ref().and_then(|m| m.component.as_ref()) {
+        let context = context
+            .extend_context_with_struct_field("Bom", "metadata")
+            .extend_context_with_struct_field("Metadata", "component");
+        collect_component_purl_mismatches(component, comparison, &context, &mut mismatches);
+    }
+
+    if let Some(components) = &bom.components {
+        let context = context.extend_context_with_struct_field("Bom", "components");
+        collect_components_purl_mismatches(components, comparison, &context, &mut mismatches);
+    }
+
+    mismatches
+}
+
+/// Reports a purl mismatch as a reason for the validation to fail, alongside the other reasons
+fn purl_mismatch_failure(mismatch: ValidationError) -> Option<FailureReason> {
+    match mismatch {
+        ValidationError::PurlMismatch {
+            context,
+            field,
+            purl_value,
+            component_value,
+        } => Some(FailureReason {
+            message: format!(
+                r#"Purl {field} "{purl_value}" does not match component {field} "{component_value}""#
+            ),
+            context,
+        }),
+        _ => None,
+    }
+}
+
+/// The context of an item in a list held by a struct field, e.g. `Metadata.authors[0]`
+fn field_item_context(
+    context: &ValidationContext,
+    struct_name: &str,
+    field_name: &str,
+    index: usize,
+) -> ValidationContext {
+    context.extend_context(vec![
+        ValidationPathComponent::Struct {
+            struct_name: struct_name.to_string(),
+            field_name: field_name.to_string(),
+        },
+        ValidationPathComponent::Array { index },
+    ])
+}
+
+fn validate_component_contact_emails(
+    component: &Component,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    if let Some(supplier) = &component.supplier {
+        results.push(supplier.validate_contact_emails(
+            &context.extend_context_with_struct_field("Component", "supplier"),
+        ));
+    }
+    for (index, nested) in component
+        .components
+        .iter()
+        .flat_map(|c| c.0.iter())
+        .enumerate()
+    {
+        let context = field_item_context(context, "Component", "components", index);
+        validate_component_contact_emails(nested, &context, results);
+    }
+}
+
+fn validate_service_contact_emails(
+    service: &Service,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    if let Some(provider) = &service.provider {
+        results.push(provider.validate_contact_emails(
+            &context.extend_context_with_struct_field("Service", "provider"),
+        ));
+    }
+    for (index, nested) in service.services.iter().flat_map(|s| s.0.iter()).enumerate() {
+        let context = field_item_context(context, "Service", "services", index);
+        validate_service_contact_emails(nested, &context, results);
+    }
+}
+
+fn collect_external_references<'a>(
+    owner: &'a str,
+    external_references: &'a Option<ExternalReferences>,
+    references: &mut Vec<(&'a str, &'a ExternalReference)>,
+) {
+    for reference in external_references.iter().flat_map(|r| r.0.iter()) {
+        references.push((owner, reference));
+    }
+}
+
+fn collect_component_external_references<'a>(
+    component: &'a Component,
+    references: &mut Vec<(&'a str, &'a ExternalReference)>,
+) {
+    let owner = component.bom_ref.as_deref().unwrap_or_default();
+    collect_external_references(owner, &component.external_references, references);
+
+    let pedigree = component.pedigree.as_ref();
+    let nested = [
+        &component.components,
+        pedigree.map_or(&None, |p| &p.ancestors),
+        pedigree.map_or(&None, |p| &p.descendants),
+        pedigree.map_or(&None, |p| &p.variants),
+    ];
+    for components in nested.into_iter().flatten() {
+        for nested_component in &components.0 {
+            collect_component_external_references(nested_component, references);
+        }
+    }
+}
+
+/// Whether two components share their bom-ref or purl, see [Bom::merge]
+fn is_same_component(a: &Component, b: &Component) -> bool {
+    (a.bom_ref.is_some() && a.bom_ref == b.bom_ref) || (a.purl.is_some() && a.purl == b.purl)
+}
+
+fn record_rename(
+    from: &Option<String>,
+    to: &Option<String>,
+    renames: &mut HashMap<String, String>,
+) {
+    if let (Some(from), Some(to)) = (from, to) {
+        if from != to {
+            renames.insert(from.clone(), to.clone());
+        }
+    }
+}
+
+fn renamed_dependency(dependency: Dependency, renames: &HashMap<String, String>) -> Dependency {
+    let rename = |bom_ref: String| renames.get(&bom_ref).cloned().unwrap_or(bom_ref);
+    let mut dependencies: Vec<String> = Vec::new();
+    for dependency_ref in dependency.dependencies.into_iter().map(rename) {
+        if !dependencies.contains(&dependency_ref) {
+            dependencies.push(dependency_ref);
+        }
+    }
+    Dependency {
+        dependency_ref: rename(dependency.dependency_ref),
+        dependencies,
+    }
+}
+
+/// Points the targets of the vulnerabilities at the renamed bom-refs, dropping targets that
+/// become duplicates of another one
+fn rename_vulnerability_targets(
+    vulnerabilities: &mut Vulnerabilities,
+    renames: &HashMap<String, String>,
+) {
+    for vulnerability in vulnerabilities.0.iter_mut() {
+        if let Some(targets) = &mut vulnerability.vulnerability_targets {
+            let mut renamed: Vec<VulnerabilityTarget> = Vec::new();
+            for mut target in targets.0.drain(..) {
+                if let Some(new_ref) = renames.get(&target.bom_ref) {
+                    target.bom_ref = new_ref.clone();
+                }
+                if !renamed.contains(&target) {
+                    renamed.push(target);
+                }
+            }
+            targets.0 = renamed;
+        }
+    }
+}
+
+/// Points the assemblies and dependencies of the compositions at the renamed bom-refs,
+/// dropping references that become duplicates of another one
+fn rename_composition_refs(compositions: &mut Compositions, renames: &HashMap<String, String>) {
+    let rename = |references: &mut Vec<BomReference>| {
+        let mut renamed: Vec<BomReference> = Vec::new();
+        for BomReference(bom_ref) in references.drain(..) {
+            let reference = BomReference(renames.get(&bom_ref).cloned().unwrap_or(bom_ref));
+            if !renamed.contains(&reference) {
+                renamed.push(reference);
+            }
+        }
+        *references = renamed;
+    };
+    for composition in compositions.0.iter_mut() {
+        if let Some(assemblies) = &mut composition.assemblies {
+            rename(assemblies);
+        }
+        if let Some(dependencies) = &mut composition.dependencies {
+            rename(dependencies);
+        }
+    }
+}
+
+/// Unites the dependencies listed for the same bom-ref, keeping the order of their first occurrence
+fn united_dependencies(dependencies: impl Iterator<Item = Dependency>) -> Vec<Dependency> {
+    let mut united: Vec<Dependency> = Vec::new();
+    for dependency in dependencies {
+        let existing = united
+            .iter_mut()
+            .find(|existing| existing.dependency_ref == dependency.dependency_ref);
+        match existing {
+            Some(existing) => {
+                for dependency_ref in dependency.dependencies {
+                    if !existing.dependencies.contains(&dependency_ref) {
+                        existing.dependencies.push(dependency_ref);
+                    }
+                }
+            }
+            None => united.push(dependency),
+        }
+    }
+    united
+}
+
+/// The identity of a component for [Bom::deduplicate]
+fn deduplication_key(component: &Component) -> String {
+    match &component.purl {
+        Some(purl) => purl.to_string(),
+        None => format!(
+            "{}/{}@{}",
+            component
+                .group
+                .as_ref()
+                .map(|g| g.as_ref())
+                .unwrap_or_default(),
+            component.name,
+            component
+                .version
+                .as_ref()
+                .map(|v| v.as_ref())
+                .unwrap_or_default()
+        ),
+    }
+}
+
+/// Adds the hashes and properties of a duplicate that the surviving component lacks
+fn absorb_duplicate(survivor: &mut Component, duplicate: Component) {
+    for hash in duplicate.hashes.into_iter().flat_map(|h| h.0) {
+        let hashes = survivor.hashes.get_or_insert_with(|| Hashes(Vec::new()));
+        if !hashes.0.contains(&hash) {
+            hashes.0.push(hash);
+        }
+    }
+    for property in duplicate.properties.into_iter().flat_map(|p| p.0) {
+        let properties = survivor
+            .properties
+            .get_or_insert_with(|| Properties(Vec::new()));
+        if !properties.0.contains(&property) {
+            properties.0.push(property);
+        }
+    }
+}
+
+fn collect_component_bom_refs<'a>(
+    component: &'a Component,
+    context: ValidationContext,
+    occurrences: &mut Vec<(&'a str, ValidationContext)>,
+) {
+    let nested_context = context.extend_context_with_struct_field("Component", "components");
+    if let Some(bom_ref) = &component.bom_ref {
+        occurrences.push((bom_ref, context));
+    }
+    for (index, nested) in component
+        .components
+        .iter()
+        .flat_map(|c| c.0.iter())
+        .enumerate()
+    {
+        let context = nested_context.extend_context(vec![ValidationPathComponent::Array { index }]);
+        collect_component_bom_refs(nested, context, occurrences);
+    }
+}
+
+fn collect_service_bom_refs<'a>(
+    service: &'a Service,
+    context: ValidationContext,
+    occurrences: &mut Vec<(&'a str, ValidationContext)>,
+) {
+    let nested_context = context.extend_context_with_struct_field("Service", "services");
+    if let Some(bom_ref) = &service.bom_ref {
+        occurrences.push((bom_ref, context));
+    }
+    for (index, nested) in service.services.iter().flat_map(|c| c.0.iter()).enumerate() {
+        let context = nested_context.extend_context(vec![ValidationPathComponent::Array { index }]);
+        collect_service_bom_refs(nested, context, occurrences);
+    }
+}
+
+fn collect_service_external_references<'a>(
+    service: &'a Service,
+    references: &mut Vec<(&'a str, &'a ExternalReference)>,
+) {
+    let owner = service.bom_ref.as_deref().unwrap_or_default();
+    collect_external_references(owner, &service.external_references, references);
+
+    for nested_service in service.services.iter().flat_map(|s| s.0.iter()) {
+        collect_service_external_references(nested_service, references);
+    }
+}
+
+fn find_service_by_ref<'a>(service: &'a Service, bom_ref: &str) -> Option<&'a Service> {
+    if service.bom_ref.as_deref() == Some(bom_ref) {
+        return Some(service);
+    }
+
+    service
+        .services
+        .iter()
+        .flat_map(|s| s.0.iter())
+        .find_map(|nested| find_service_by_ref(nested, bom_ref))
+}
+
+fn find_component_in<'a>(
+    component: &'a Component,
+    predicate: &impl Fn(&Component) -> bool,
+) -> Option<&'a Component> {
+    if predicate(component) {
+        return Some(component);
+    }
+
+    component
+        .components
+        .iter()
+        .flat_map(|c| c.0.iter())
+        .find_map(|nested| find_component_in(nested, predicate))
+}
+
+fn collect_components_without_licenses<'a>(
+    components: &'a Components,
+    include_excluded: bool,
+    unlicensed: &mut Vec<&'a Component>,
+) {
+    for component in &components.0 {
+        let excluded = matches!(component.scope, Some(Scope::Excluded));
+        let licensed = component.licenses.as_ref().is_some_and(|l| !l.0.is_empty());
+        if !licensed && (include_excluded || !excluded) {
+            unlicensed.push(component);
+        }
+        if let Some(subcomponents) = &component.components {
+            collect_components_without_licenses(subcomponents, include_excluded, unlicensed);
+        }
+    }
+}
+
+/// Assigns unique, content derived bom-refs and remembers the references they replace
+#[derive(Default)]
+struct BomReferenceNormalizer {
+    assigned: HashSet<String>,
+    replaced: HashMap<String, String>,
+}
+
+impl BomReferenceNormalizer {
+    fn assign(&mut self, old_ref: &str, base: String) -> String {
+        let mut new_ref = base.clone();
+        let mut suffix = 2;
+        while self.assigned.contains(&new_ref) {
+            new_ref = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        self.assigned.insert(new_ref.clone());
+        self.replaced
+            .entry(old_ref.to_string())
+            .or_insert_with(|| new_ref.clone());
+        new_ref
+    }
+
+    fn resolve(&self, bom_ref: &str) -> String {
+        self.replaced
+            .get(bom_ref)
+            .cloned()
+            .unwrap_or_else(|| bom_ref.to_string())
+    }
+}
+
+fn strip_if_empty<T>(collection: &mut Option<T>, is_empty: impl Fn(&T) -> bool) {
+    if collection.as_ref().is_some_and(is_empty) {
+        *collection = None;
+    }
+}
+
+fn name_and_version(name: &impl fmt::Display, version: Option<&impl fmt::Display>) -> String {
+    match version {
+        Some(version) => format!("{}@{}", name, version),
+        None => name.to_string(),
+    }
+}
+
+fn normalize_components(
+    components: &mut Option<Components>,
+    bom_refs: &mut BomReferenceNormalizer,
+) {
+    strip_if_empty(components, |c| c.0.is_empty());
+
+    if let Some(components) = components {
+        components.0.sort_by_cached_key(|c| {
+            (
+                c.group.as_ref().map(|g| g.to_string()),
+                c.name.to_string(),
+                c.version.as_ref().map(|v| v.to_string()),
+                c.purl.as_ref().map(|p| p.to_string()),
+            )
+        });
+        for component in components.0.iter_mut() {
+            normalize_component(component, bom_refs);
+        }
+    }
+}
+
+fn normalize_component(component: &mut Component, bom_refs: &mut BomReferenceNormalizer) {
+    if let Some(bom_ref) = &component.bom_ref {
+        let base = match &component.purl {
+            Some(purl) => purl.to_string(),
+            None => name_and_version(&component.name, component.version.as_ref()),
+        };
+        component.bom_ref = Some(bom_refs.assign(bom_ref, base));
+    }
+
+    if let Some(hashes) = &mut component.hashes {
+        hashes
+            .0
+            .sort_by_cached_key(|h| (h.alg.to_string(), h.content.0.clone()));
+    }
+    strip_if_empty(&mut component.hashes, |h| h.0.is_empty());
+    strip_if_empty(&mut component.licenses, |l| l.0.is_empty());
+    normalize_external_references(&mut component.external_references);
+    normalize_properties(&mut component.properties);
+    normalize_components(&mut component.components, bom_refs);
+}
+
+fn normalize_services(services: &mut Option<Services>, bom_refs: &mut BomReferenceNormalizer) {
+    strip_if_empty(services, |s| s.0.is_empty());
+
+    if let Some(services) = services {
+        services.0.sort_by_cached_key(|s| {
+            (
+                s.group.as_ref().map(|g| g.to_string()),
+                s.name.to_string(),
+                s.version.as_ref().map(|v| v.to_string()),
+            )
+        });
+        for service in services.0.iter_mut() {
+            if let Some(bom_ref) = &service.bom_ref {
+                let base = name_and_version(&service.name, service.version.as_ref());
+                service.bom_ref = Some(bom_refs.assign(bom_ref, base));
+            }
+
+            strip_if_empty(&mut service.endpoints, |e| e.is_empty());
+            strip_if_empty(&mut service.data, |d| d.is_empty());
+            strip_if_empty(&mut service.licenses, |l| l.0.is_empty());
+            normalize_external_references(&mut service.external_references);
+            normalize_properties(&mut service.properties);
+            normalize_services(&mut service.services, bom_refs);
+        }
+    }
+}
+
+fn normalize_external_references(external_references: &mut Option<ExternalReferences>) {
+    strip_if_empty(external_references, |e| e.0.is_empty());
+
+    if let Some(external_references) = external_references {
+        external_references
+            .0
+            .sort_by_cached_key(|e| (e.external_reference_type.to_string(), e.url.to_string()));
+    }
+}
+
+fn normalize_properties(properties: &mut Option<Properties>) {
+    strip_if_empty(properties, |p| p.0.is_empty());
+
+    if let Some(properties) = properties {
+        properties
+            .0
+            .sort_by_cached_key(|p| (p.name.clone(), p.value.to_string()));
+    }
+}
+
+fn validate_service_bom_refs(
+    service: &Service,
+    bom_refs: &mut BomReferencesContext,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    if let Some(bom_ref) = &service.bom_ref {
+        if bom_refs.contains(bom_ref) {
+            let context = context.extend_context_with_struct_field("Service", "bom_ref");
+            results.push(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
+                    context,
+                }],
+            });
+        }
+        bom_refs.add_service_bom_ref(bom_ref);
+    }
+
+    if let Some(services) = &service.services {
+        let context = context.extend_context_with_struct_field("Service", "services");
+        validate_services(services, bom_refs, &context, results);
+    }
+}
+
+fn validate_services(
+    services: &Services,
+    bom_refs: &mut BomReferencesContext,
+    context: &ValidationContext,
+    results: &mut Vec<ValidationResult>,
+) {
+    // record the service references
+    for (service_index, service) in services.0.iter().enumerate() {
+        let context = context.extend_context(vec![ValidationPathComponent::Array {
+            index: service_index,
+        }]);
+
+        validate_service_bom_refs(service, bom_refs, &context, results);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrnUuid(pub(crate) String);
+
+impl UrnUuid {
+    pub fn new(value: String) -> Result<Self, UrnUuidError> {
+        match matches_urn_uuid_regex(&value) {
+            true => Ok(Self(value)),
+            false => Err(UrnUuidError::InvalidUrnUuid(
+                "UrnUuid does not match regular expression".to_string(),
+            )),
+        }
+    }
+
+    pub fn generate() -> Self {
+        Self::from(uuid::Uuid::new_v4())
+    }
+
+    /// Derives a name-based (version 5) UUID from `content`, so that the same content
+    /// always results in the same serial number
+    pub fn v5_from_content(namespace: &uuid::Uuid, content: &[u8]) -> Self {
+        Self::from(uuid::Uuid::new_v5(namespace, content))
+    }
+}
+
+impl fmt::Display for UrnUuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<uuid::Uuid> for UrnUuid {
+    fn from(uuid: uuid::Uuid) -> Self {
+        Self(format!("urn:uuid:{}", uuid))
+    }
+}
+
+impl Validate for UrnUuid {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match matches_urn_uuid_regex(&self.0) {
+            true => Ok(ValidationResult::Passed),
+            false => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "UrnUuid does not match regular expression".to_string(),
+                    context,
+                }],
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum UrnUuidError {
+    #[error("Invalid urn:uuid: {0}")]
+    InvalidUrnUuid(String),
+}
+
+fn matches_urn_uuid_regex(value: &str) -> bool {
+    static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-[1-5][0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$",
+        )
+        .expect("Failed to compile regex.")
+    });
+    UUID_REGEX.is_match(value)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        external_models::{
+            date_time::DateTime,
+            normalized_string::NormalizedString,
+            spdx::SpdxExpression,
+            uri::{Purl, PurlComponents, Uri},
+        },
+        models::{
+            component::{Classification, Component},
+            composition::{AggregateType, BomReference, Composition},
+            dependency::Dependency,
+            external_reference::{ExternalReference, ExternalReferenceType},
+            hash::{Hash, HashAlgorithm, HashValue, Hashes},
+            license::{License, LicenseChoice, Licenses},
+            lifecycle::{Lifecycle, Lifecycles, Phase},
+            organization::{OrganizationalContact, OrganizationalEntity},
+            property::Property,
+            service::Service,
+            vulnerability::Vulnerability,
+            vulnerability_analysis::{ImpactAnalysisState, VulnerabilityAnalysis},
+            vulnerability_credits::VulnerabilityCredits,
+            vulnerability_target::VulnerabilityTargets,
+        },
+        validation::ValidationPathComponent,
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::io::Read;
+
+    #[test]
+    fn it_should_round_trip_every_spec_version_through_its_string_form() {
+        for version in [
+            SpecVersion::V1_3,
+            SpecVersion::V1_4,
+            SpecVersion::V1_5,
+            SpecVersion::V1_6,
+        ] {
+            assert_eq!(
+                SpecVersion::from_str(&version.to_string()).unwrap(),
+                version
+            );
+        }
+        assert_eq!(SpecVersion::V1_5.to_string(), "1.5");
+    }
+
+    #[test]
+    fn it_should_reject_an_unknown_spec_version() {
+        for input in ["1.2", "1.7", "v1.4", ""] {
+            assert!(matches!(
+                SpecVersion::from_str(input),
+                Err(BomError::UnsupportedSpecVersion(version)) if version == input
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_parse_json_using_function_without_suffix() {
+        let input = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.3",
+            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+            "version": 1,
+            "components": []
+        }"#;
+        let result = Bom::parse_from_json(input.as_bytes());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_should_output_bytes_that_parse_back_to_the_same_bom() {
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "lib-x",
+                "v0.1.0",
+                Some("lib-x".to_string()),
+            )])),
+            ..Bom::default()
+        };
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_4)
+            .expect("Failed to write JSON");
+        let parsed = Bom::parse_from_json_v1_4(json.as_slice()).expect("Failed to parse JSON");
+        assert_eq!(parsed, bom);
+
+        let xml = bom
+            .clone()
+            .to_cyclonedx_xml_bytes(SpecVersion::V1_3)
+            .expect("Failed to write XML");
+        let parsed = Bom::parse_from_xml_v1_3(xml.as_slice()).expect("Failed to parse XML");
+        assert_eq!(parsed, bom);
+    }
+
+    #[test]
+    fn it_should_build_a_bom_from_its_parts() {
+        let component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        let serial_number = UrnUuid::generate();
+
+        let bom = Bom::builder()
+            .version(2)
+            .serial_number(serial_number.clone())
+            .metadata(Metadata::new().unwrap())
+            .add_component(component.clone())
+            .add_dependency(Dependency {
+                dependency_ref: "lib-x".to_string(),
+                dependencies: vec![],
+            })
+            .build();
+
+        assert_eq!(bom.version, 2);
+        assert_eq!(bom.serial_number, Some(serial_number));
+        assert!(bom.metadata.is_some());
+        assert_eq!(bom.components, Some(Components(vec![component])));
+        assert_eq!(bom.dependencies.map(|d| d.0.len()), Some(1));
+        assert_eq!(bom.services, None);
+    }
+
+    #[test]
+    fn it_should_build_a_valid_empty_bom_with_a_serial_number() {
+        let bom = BomBuilder::new().build();
+
+        assert_eq!(bom.version, 1);
+        assert!(bom.serial_number.is_some());
+        assert_eq!(bom.components, None);
+        assert_eq!(bom.dependencies, None);
+        assert_eq!(bom.validate(), Ok(ValidationResult::Passed));
+    }
+
+    #[test]
+    fn it_should_detect_the_format_and_version_of_the_input() {
+        let bom = Bom {
+            components: Some(Components(vec![Component::new(
+                Classification::Library,
+                "lib-x",
+                "v0.1.0",
+                Some("lib-x".to_string()),
+            )])),
+            ..Bom::default()
+        };
+
+        for version in [SpecVersion::V1_3, SpecVersion::V1_5] {
+            let json = bom.clone().to_cyclonedx_json_bytes(version).unwrap();
+            let xml = bom.clone().to_cyclonedx_xml_bytes(version).unwrap();
+            for document in [json, xml] {
+                let input = "\n  ".as_bytes().chain(document.as_slice());
+                assert_eq!(Bom::parse(input).expect("Failed to parse"), bom);
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_read_the_version_of_a_json_document() {
+        let parse = |json: &str| Bom::parse_from_json_any(json.as_bytes());
+
+        let bom = parse(r#"{"bomFormat": "CycloneDX", "specVersion": "1.5", "version": 2}"#)
+            .expect("Failed to parse JSON");
+        assert_eq!(bom.version, 2);
+
+        let error =
+            parse(r#"{"bomFormat": "CycloneDX", "specVersion": "2.0", "version": 1}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid input format found: Unsupported Spec Version '2.0', supported are 1.3, 1.4, 1.5, 1.6"
+        );
+
+        let error =
+            parse(r#"{"bomFormat": "SPDX", "specVersion": "1.5", "version": 1}"#).unwrap_err();
+        assert!(matches!(
+            error,
+            crate::errors::JsonReadError::BomError {
+                error: BomError::UnsupportedBomFormat(_)
+            }
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_version_of_an_xml_document_from_its_namespace() {
+        let parse = |xml: &str| Bom::parse_from_xml_any(xml.as_bytes());
+
+        let bom = parse(r#"<?xml version="1.0"?><bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="3"/>"#)
+            .expect("Failed to parse XML");
+        assert_eq!(bom.version, 3);
+
+        let error =
+            parse(r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.2" version="1"/>"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Expected document to be in the form http://cyclonedx.org/schema/bom/1.3 or \
+             http://cyclonedx.org/schema/bom/1.4 or http://cyclonedx.org/schema/bom/1.5 or \
+             http://cyclonedx.org/schema/bom/1.6, but received http://cyclonedx.org/schema/bom/1.2 \
+             at line 1, column 61"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_input_that_is_neither_json_nor_xml() {
+        for (input, found) in [(" bom", "'b'"), ("\n", "no content")] {
+            match Bom::parse(input.as_bytes()) {
+                Err(BomReadError::UnknownFormatError { found: actual }) => {
+                    assert_eq!(actual, found)
+                }
+                result => panic!("Expected an unknown format, got {:?}", result),
+            }
+        }
+
+        let result = Bom::parse(r#"<bom xmlns="http://example.com/bom" version="1"/>"#.as_bytes());
+        assert!(matches!(
+            result,
+            Err(BomReadError::XmlReadError {
+                error: crate::errors::XmlReadError::InvalidNamespaceError { .. }
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_round_trip_component_tags_in_v1_6() {
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.tags = Some(vec!["crypto".to_string(), "parser".to_string()]);
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_6)
+            .expect("Failed to write JSON");
+        let parsed = Bom::parse_from_json_v1_6(json.as_slice()).expect("Failed to parse JSON");
+        assert_eq!(parsed, bom);
+
+        let xml = bom
+            .clone()
+            .to_cyclonedx_xml_bytes(SpecVersion::V1_6)
+            .expect("Failed to write XML");
+        let parsed = Bom::parse_from_xml_v1_6(xml.as_slice()).expect("Failed to parse XML");
+        assert_eq!(parsed, bom);
+
+        let json = bom
+            .to_cyclonedx_json_bytes(SpecVersion::V1_4)
+            .expect("Failed to write JSON");
+        let parsed = Bom::parse_from_json_v1_4(json.as_slice()).expect("Failed to parse JSON");
+        let tags = parsed.components.and_then(|c| c.0[0].tags.clone());
+        assert_eq!(tags, None);
+    }
+
+    #[test]
+    fn it_should_round_trip_the_component_modified_flag() {
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.modified = Some(true);
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        for version in [
+            SpecVersion::V1_3,
+            SpecVersion::V1_4,
+            SpecVersion::V1_5,
+            SpecVersion::V1_6,
+        ] {
+            let json = bom
+                .clone()
+                .to_cyclonedx_json_bytes(version)
+                .expect("Failed to write JSON");
+            let xml = bom
+                .clone()
+                .to_cyclonedx_xml_bytes(version)
+                .expect("Failed to write XML");
+            let (from_json, from_xml) = match version {
+                SpecVersion::V1_3 => (
+                    Bom::parse_from_json_v1_3(json.as_slice()),
+                    Bom::parse_from_xml_v1_3(xml.as_slice()),
+                ),
+                SpecVersion::V1_4 => (
+                    Bom::parse_from_json_v1_4(json.as_slice()),
+                    Bom::parse_from_xml_v1_4(xml.as_slice()),
+                ),
+                SpecVersion::V1_5 => (
+                    Bom::parse_from_json_v1_5(json.as_slice()),
+                    Bom::parse_from_xml_v1_5(xml.as_slice()),
+                ),
+                SpecVersion::V1_6 => (
+                    Bom::parse_from_json_v1_6(json.as_slice()),
+                    Bom::parse_from_xml_v1_6(xml.as_slice()),
+                ),
+            };
+            let modified = |bom: Bom| bom.components.and_then(|c| c.0[0].modified);
+            assert_eq!(
+                modified(from_json.expect("Failed to parse JSON")),
+                Some(true)
+            );
+            assert_eq!(modified(from_xml.expect("Failed to parse XML")), Some(true));
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_values_dropped_by_a_conversion() {
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.tags = Some(vec!["tag".to_string()]);
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            vulnerabilities: Some(Vulnerabilities(vec![
+                Vulnerability::new(Some("vuln-1".to_string())),
+                Vulnerability::new(Some("vuln-2".to_string())),
+            ])),
+            ..Bom::default()
+        };
+
+        let (converted, warnings) = bom
+            .convert_to(SpecVersion::V1_3)
+            .expect("Failed to convert");
+
+        assert_eq!(converted.vulnerabilities, None);
+        assert_eq!(
+            converted.components.map(|c| c.0[0].tags.clone()),
+            Some(None)
+        );
+        let warnings: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "Bom.components[0] > Component.tags: Dropped, as it was added in version 1.6",
+                "Bom.vulnerabilities[0]: Dropped, as it was added in version 1.4",
+                "Bom.vulnerabilities[1]: Dropped, as it was added in version 1.4",
+            ]
+        );
+
+        let (converted, warnings) = bom
+            .convert_to(SpecVersion::V1_6)
+            .expect("Failed to convert");
+        assert_eq!(converted, bom);
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn it_should_downgrade_a_1_4_document_with_vulnerabilities_to_1_3() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "vulnerabilities": [
+    {
+      "bom-ref": "vuln-1",
+      "id": "CVE-2023-0001"
+    }
+  ]
+}"#;
+        let bom = Bom::parse_from_json_v1_4(input.as_bytes()).expect("Failed to parse JSON");
+
+        let (downgraded, warnings) = bom
+            .convert_to(SpecVersion::V1_3)
+            .expect("Failed to convert");
+
+        assert_eq!(downgraded.vulnerabilities, None);
+        let warnings: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            warnings,
+            vec!["Bom.vulnerabilities[0]: Dropped, as it was added in version 1.4"]
+        );
+
+        let output = downgraded
+            .to_cyclonedx_json_bytes(SpecVersion::V1_3)
+            .expect("Failed to write JSON");
+        let reparsed =
+            Bom::parse_from_json_v1_3(output.as_slice()).expect("Failed to parse 1.3 JSON");
+        assert_eq!(reparsed.vulnerabilities, None);
+    }
+
+    #[test]
+    fn it_should_round_trip_omnibor_ids_and_swhids_in_version_1_6() {
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.omnibor_ids =
+            vec!["gitoid:blob:sha1:261eeb9e9f8b2b4b0d119366dda99c6fd7d35c64".to_string()];
+        component.swhids = vec!["swh:1:cnt:94a9ed024d3859793618152ea559a168bbcbb5e2".to_string()];
+        let bom = Bom {
+            components: Some(Components(vec![component])),
+            ..Bom::default()
+        };
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_6)
+            .expect("Failed to write JSON");
+        let value: Value = serde_json::from_slice(&json).expect("Invalid JSON");
+        assert_eq!(
+            value["components"][0]["swhid"][0],
+            "swh:1:cnt:94a9ed024d3859793618152ea559a168bbcbb5e2"
+        );
+        assert_eq!(
+            Bom::parse_from_json_v1_6(json.as_slice()).expect("Failed to parse JSON"),
+            bom
+        );
+        let xml = bom
+            .clone()
+            .to_cyclonedx_xml_bytes(SpecVersion::V1_6)
+            .expect("Failed to write XML");
+        assert_eq!(
+            Bom::parse_from_xml_v1_6(xml.as_slice()).expect("Failed to parse XML"),
+            bom
+        );
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_4)
+            .expect("Failed to write JSON");
+        let value: Value = serde_json::from_slice(&json).expect("Invalid JSON");
+        assert!(value["components"][0].get("omniborId").is_none());
+        let from_1_4 = Bom::parse_from_json_v1_4(json.as_slice()).expect("Failed to parse JSON");
+        let components = from_1_4.components.expect("Missing components");
+        assert!(components.0[0].omnibor_ids.is_empty());
+        assert!(components.0[0].swhids.is_empty());
+    }
+
+    #[test]
+    fn it_should_round_trip_lifecycles_from_version_1_5() {
+        let bom = Bom::default().with_metadata(Metadata {
+            lifecycles: Some(Lifecycles(vec![
+                Lifecycle::Phase(Phase::PreBuild),
+                Lifecycle::Description {
+                    name: NormalizedString::new("platform-integration-testing"),
+                    description: None,
+                },
+            ])),
+            ..Metadata::default()
+        });
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_5)
+            .expect("Failed to write JSON");
+        let value: Value = serde_json::from_slice(&json).expect("Invalid JSON");
+        assert_eq!(value["specVersion"], "1.5");
+        assert_eq!(value["metadata"]["lifecycles"][0]["phase"], "pre-build");
+        assert_eq!(
+            Bom::parse_from_json(json.as_slice()).expect("Failed to parse JSON"),
+            bom
+        );
+
+        let xml = bom
+            .clone()
+            .to_cyclonedx_xml_bytes(SpecVersion::V1_5)
+            .expect("Failed to write XML");
+        assert!(String::from_utf8_lossy(&xml).contains("http://cyclonedx.org/schema/bom/1.5"));
+        assert_eq!(
+            Bom::parse_from_xml_v1_5(xml.as_slice()).expect("Failed to parse XML"),
+            bom
+        );
+
+        let (converted, warnings) = bom
+            .convert_to(SpecVersion::V1_4)
+            .expect("Failed to convert");
+        assert_eq!(converted.metadata.and_then(|m| m.lifecycles), None);
+        assert_eq!(
+            warnings
+                .iter()
+                .map(|w| format!("{}: {}", w.context, w.message))
+                .collect::<Vec<_>>(),
+            vec!["Bom.metadata > Metadata.lifecycles: Dropped, as it was added in version 1.5"]
+        );
+    }
+
+    #[test]
+    fn it_should_create_the_metadata_when_accessing_it_mutably() {
+        let mut bom = Bom {
+            metadata: None,
+            ..Bom::default()
+        };
+
+        bom.metadata_mut().component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            None,
+        ));
+
+        let metadata = bom.metadata.expect("Metadata was not created");
+        assert_eq!(metadata.timestamp, None);
+        assert_eq!(
+            metadata.component.map(|c| c.name.to_string()),
+            Some("app".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_replace_the_metadata() {
+        let metadata = Metadata {
+            authors: Some(vec![OrganizationalContact::new("author", None)]),
+            ..Metadata::default()
+        };
+
+        let bom = Bom::default().with_metadata(metadata.clone());
+
+        assert_eq!(bom.metadata, Some(metadata));
+    }
+
+    #[test]
+    fn it_should_round_trip_the_vulnerability_workaround() {
+        let mut vulnerability = Vulnerability::new(Some("vuln-1".to_string()));
+        vulnerability.workaround = Some("Disable the affected feature".to_string());
+        let bom = Bom {
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        };
+
+        let json = bom
+            .clone()
+            .to_cyclonedx_json_bytes(SpecVersion::V1_6)
+            .expect("Failed to write JSON");
+        let xml = bom
+            .to_cyclonedx_xml_bytes(SpecVersion::V1_6)
+            .expect("Failed to write XML");
+
+        let workaround = |bom: Bom| bom.vulnerabilities.and_then(|v| v.0[0].workaround.clone());
+        assert_eq!(
+            workaround(Bom::parse_from_json_v1_6(json.as_slice()).expect("Failed to parse JSON")),
+            Some("Disable the affected feature".to_string())
+        );
+        assert_eq!(
+            workaround(Bom::parse_from_xml_v1_6(xml.as_slice()).expect("Failed to parse XML")),
+            Some("Disable the affected feature".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_enumerate_external_references_with_their_owner() {
+        let reference = |url: &str| {
+            ExternalReferences(vec![ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri::try_from(url.to_string()).expect("Invalid URI"),
+            )])
+        };
+        let mut root = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app".into()),
+        );
+        root.external_references = Some(reference("https://example.com/app"));
+        let mut library =
+            Component::new(Classification::Library, "lib", "1.0.0", Some("lib".into()));
+        library.external_references = Some(reference("https://example.com/lib"));
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(root),
+                ..Metadata::default()
+            }),
+            components: Some(Components(vec![library])),
+            external_references: Some(reference("https://example.com/bom")),
+            ..Bom::default()
+        };
+
+        let references: Vec<(&str, String)> = bom
+            .all_external_references()
+            .map(|(owner, reference)| (owner, reference.url.to_string()))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("", "https://example.com/bom".to_string()),
+                ("app", "https://example.com/app".to_string()),
+                ("lib", "https://example.com/lib".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_require_a_metadata_component_in_strict_validation() {
+        let bom = Bom {
+            serial_number: None,
+            metadata: Some(Metadata::default()),
+            ..Bom::default()
+        };
+        assert_eq!(bom.validate(), Ok(ValidationResult::Passed));
+        assert_eq!(
+            bom.validate_strict(),
+            Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Metadata component describing the subject of the BOM is missing"
+                        .to_string(),
+                    context: ValidationContext(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "Bom".to_string(),
+                            field_name: "metadata".to_string(),
+                        },
+                        ValidationPathComponent::Struct {
+                            struct_name: "Metadata".to_string(),
+                            field_name: "component".to_string(),
+                        },
+                    ]),
+                }]
+            })
+        );
+
+        let bom = Bom {
+            metadata: Some(Metadata {
+                component: Some(Component::new(
+                    Classification::Application,
+                    "app",
+                    "1.0.0",
+                    None,
+                )),
+                ..Metadata::default()
+            }),
+            ..bom
+        };
+        assert_eq!(bom.validate_strict(), Ok(ValidationResult::Passed));
+    }
+
+    #[test]
+    fn it_should_collect_the_errors_of_several_bad_elements() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.3" version="1">
+  <metadata>
+    <component type="application"><version>1.0.0</version></component>
+  </metadata>
+  <components>
+    <component type="library">
+      <version>1.0.0</version>
+    </component>
+    <component type="library">
+      <name>good</name>
+      <version>1.0.0</version>
+    </component>
+    <component type="library">
+      <name>no-version</name>
+    </component>
+  </components>
+  <dependencies>
+    <dependency ref="good" />
+  </dependencies>
+</bom>"#;
+
+        let (bom, errors) =
+            Bom::parse_from_xml_collecting_errors(xml.as_bytes(), SpecVersion::V1_3)
+                .expect("Failed to parse the readable parts");
+
+        let positions: Vec<_> = errors
+            .iter()
+            .map(|error| error.position().map(|p| (p.line, p.column)))
+            .collect();
+        assert_eq!(positions, [Some((4, 59)), Some((9, 5)), Some((16, 5))]);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            crate::errors::XmlReadError::RequiredDataMissing { .. }
+        )));
+        assert!(errors[0].to_string().contains("name"));
+        assert!(errors[1].to_string().contains("name"));
+        assert!(errors[2].to_string().contains("version"));
+
+        assert_eq!(bom.metadata, None);
+        let components = bom.components.expect("Missing components").0;
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name.to_string(), "good");
+        assert_eq!(bom.dependencies.expect("Missing dependencies").0.len(), 1);
+    }
+
+    #[test]
+    fn it_should_abort_collecting_errors_on_a_structural_error() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1" />"#;
+
+        assert!(matches!(
+            Bom::parse_from_xml_collecting_errors(xml.as_bytes(), SpecVersion::V1_3),
+            Err(crate::errors::XmlReadError::InvalidNamespaceError { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_parse_the_version_as_a_number_or_a_numeric_string() {
+        let json = |version: &str| {
+            format!(
+                r#"{{"bomFormat": "CycloneDX", "specVersion": "1.4", "version": {}}}"#,
+                version
+            )
+        };
+
+        for version in ["7", r#""7""#] {
+            let bom = Bom::parse_from_json(json(version).as_bytes())
+                .unwrap_or_else(|e| panic!("Failed to parse version {}: {}", version, e));
+            assert_eq!(bom.version, 7);
+        }
+
+        assert!(Bom::parse_from_json(json(r#""seven""#).as_bytes()).is_err());
+        assert!(Bom::parse_from_json(json("-1").as_bytes()).is_err());
+    }
+
+    fn parse_json_in_mode(
+        input: &str,
+        mode: ParseMode,
+    ) -> Result<Bom, crate::errors::JsonReadError> {
+        let options = ParseOptions {
+            mode,
+            ..ParseOptions::default()
+        };
+        Bom::parse_from_json_with_options(input.as_bytes(), options).map(|parsed| parsed.bom)
+    }
+
+    fn parse_xml_in_mode(input: &str, mode: ParseMode) -> Result<Bom, crate::errors::XmlReadError> {
+        let options = ParseOptions {
+            mode,
+            ..ParseOptions::default()
+        };
+        Bom::parse_from_xml_with_options(input.as_bytes(), options).map(|parsed| parsed.bom)
+    }
+
+    #[test]
+    fn it_should_reject_a_non_urn_serial_number_in_strict_mode() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "serialNumber": "vendor-bom-42",
+            "version": 1
+        }"#;
+
+        let bom = parse_json_in_mode(json, ParseMode::Lax).expect("Failed to parse in lax mode");
+        assert_eq!(
+            bom.serial_number,
+            Some(UrnUuid("vendor-bom-42".to_string()))
+        );
+
+        let error = parse_json_in_mode(json, ParseMode::Strict)
+            .expect_err("Parsed an invalid serial number in strict mode");
+        assert!(error.to_string().contains("vendor-bom-42"));
+
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" serialNumber="vendor-bom-42" version="1" />"#;
+        assert!(parse_xml_in_mode(xml, ParseMode::Lax).is_ok());
+        let error = parse_xml_in_mode(xml, ParseMode::Strict)
+            .expect_err("Parsed an invalid serial number in strict mode");
+        assert!(error.to_string().contains("vendor-bom-42"));
+
+        let valid = json.replace(
+            "vendor-bom-42",
+            "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
+        );
+        assert!(parse_json_in_mode(&valid, ParseMode::Strict).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_invalid_timestamps_in_strict_mode_naming_the_field() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.4",
+            "version": 1,
+            "metadata": { "timestamp": "2024-01-01T00:00:00Z" },
+            "vulnerabilities": [
+                { "id": "CVE-1" },
+                { "id": "CVE-2", "published": "yesterday" }
+            ]
+        }"#;
+
+        let bom = parse_json_in_mode(json, ParseMode::Lax).expect("Failed to parse in lax mode");
+        let published = bom.vulnerabilities.as_ref().unwrap().0[1].published.clone();
+        assert_eq!(published, Some(DateTime::new_unchecked("yesterday")));
+
+        let error = parse_json_in_mode(json, ParseMode::Strict)
+            .expect_err("Parsed an invalid timestamp in strict mode");
+        assert!(matches!(
+            error,
+            crate::errors::JsonReadError::BomError {
+                error: BomError::InvalidDateTime(field, value)
+            } if field == "vulnerabilities[1].published" && value == "yesterday"
+        ));
+
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1">
+  <metadata><timestamp>01.01.2024</timestamp></metadata>
+</bom>"#;
+        let error = parse_xml_in_mode(xml, ParseMode::Strict)
+            .expect_err("Parsed an invalid timestamp in strict mode");
+        assert_eq!(
+            error.to_string(),
+            "Could not parse 01.01.2024 as dateTime on metadata.timestamp"
+        );
+    }
+
+    #[test]
+    fn it_should_return_the_bom_ref_of_the_metadata_component_as_root() {
+        let mut bom = Bom::default();
+        assert_eq!(bom.root_bom_ref(), None);
+
+        bom.metadata_mut().component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            None,
+        ));
+        assert_eq!(bom.root_bom_ref(), None);
+
+        bom.metadata_mut().component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app-ref".to_string()),
+        ));
+        assert_eq!(bom.root_bom_ref(), Some("app-ref"));
+    }
+
+    #[test]
+    fn it_should_compute_the_dependency_depths_from_the_root() {
+        let dependency = |dependency_ref: &str, dependencies: &[&str]| Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        };
+        let bom = Bom {
+            dependencies: Some(Dependencies(vec![
+                dependency("app", &["lib-a"]),
+                dependency("lib-a", &["lib-b"]),
+                dependency("lib-b", &[]),
+                dependency("unreachable", &["lib-b"]),
+            ])),
+            ..Bom::default()
+        }
+        .with_metadata(Metadata {
+            component: Some(Component::new(
+                Classification::Application,
+                "app",
+                "1.0.0",
+                Some("app".to_string()),
+            )),
+            ..Metadata::default()
+        });
+
+        let depths = bom.dependency_depths();
+
+        assert_eq!(
+            depths,
+            HashMap::from([
+                ("app".to_string(), 0),
+                ("lib-a".to_string(), 1),
+                ("lib-b".to_string(), 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_report_hashes_that_do_not_match_their_algorithm() {
+        let hashes = |alg, content: &str| {
+            Some(Hashes(vec![Hash {
+                alg,
+                content: HashValue(content.to_string()),
+            }]))
+        };
+        let mut app = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app".into()),
+        );
+        app.hashes = hashes(HashAlgorithm::MD5, "a3bf1f3d584747e2569483783ddee45b");
+        let mut nested = Component::new(
+            Classification::Library,
+            "app-lib",
+            "1.0.0",
+            Some("app-lib".into()),
+        );
+        nested.hashes = hashes(HashAlgorithm::SHA1, "a3bf1f3d584747e2569483783ddee45b");
+        app.components = Some(Components(vec![nested]));
+        let mut library = Component::new(
+            Classification::Library,
+            "serde",
+            "1.0.0",
+            Some("lib".into()),
+        );
+        library.hashes = hashes(
+            HashAlgorithm::SHA256,
+            "9F86D081884C7D659A2FEAA0C55AD015A3BF4F1B2B0B822CD15D6C15B0F00A08",
+        );
+        let bom = Bom {
+            components: Some(Components(vec![app, library])),
+            ..Bom::default()
+        };
+
+        let reasons = bom
+            .validate()
+            .unwrap()
+            .into_result()
+            .expect_err("Accepted hashes not matching their algorithm");
+        assert_eq!(
+            reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec![
+                "Bom.components[0] > Component.components[0] > Component.hashes[0] > Hash.content: SHA-1 hash has 32 hex digits instead of 40",
+                "Bom.components[1] > Component.hashes[0] > Hash.content: SHA-256 hash is not lowercase hex",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_list_duplicate_bom_refs_with_their_paths() {
+        let component = |name: &str, bom_ref: &str| {
+            Component::new(Classification::Library, name, "1.0.0", Some(bom_ref.into()))
+        };
+        let mut app = component("app", "a");
+        app.components = Some(Components(vec![component("app-lib", "b")]));
+        let bom = Bom {
+            components: Some(Components(vec![
+                component("serde", "b"),
+                component("rand", "c"),
+                component("log", "d"),
+            ])),
+            services: Some(Services(vec![Service::new("api", Some("a".into()))])),
+            vulnerabilities: Some(Vulnerabilities(vec![Vulnerability::new(Some("c".into()))])),
+            ..Bom::default()
+        }
+        .with_metadata(Metadata {
+            component: Some(app),
+            ..Metadata::default()
+        });
+
+        let duplicates: Vec<_> = bom
+            .duplicate_bom_refs()
+            .into_iter()
+            .map(|d| {
+                let paths: Vec<_> = d.paths.iter().map(|p| p.to_string()).collect();
+                (d.bom_ref, paths)
+            })
+            .collect();
+
+        assert_eq!(
+            duplicates,
+            vec![
+                (
+                    "a".to_string(),
+                    vec![
+                        "Bom.metadata > Metadata.component".to_string(),
+                        "Bom.services[0]".to_string(),
+                    ]
+                ),
+                (
+                    "b".to_string(),
+                    vec![
+                        "Bom.metadata > Metadata.component > Component.components[0]".to_string(),
+                        "Bom.components[0]".to_string(),
+                    ]
+                ),
+                (
+                    "c".to_string(),
+                    vec![
+                        "Bom.components[1]".to_string(),
+                        "Bom.vulnerabilities[0]".to_string(),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    fn bom_with_root(root: &str, components: Vec<Component>, dependencies: Vec<Dependency>) -> Bom {
+        let mut metadata = Metadata::new().unwrap();
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            root,
+            "1.0.0",
+            Some(root.to_string()),
+        ));
+        Bom {
+            metadata: Some(metadata),
+            components: Some(Components(components)),
+            dependencies: Some(Dependencies(dependencies)),
+            ..Bom::default()
+        }
+    }
+
+    fn library(bom_ref: &str, purl: &str) -> Component {
+        let purl = Purl::from_str(purl).unwrap();
+        let PurlComponents { name, version, .. } = purl.components().unwrap();
+        let mut component = Component::new(
+            Classification::Library,
+            &name,
+            &version.unwrap(),
+            Some(bom_ref.to_string()),
+        );
+        component.purl = Some(purl);
+        component
+    }
+
+    fn depends_on(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_merge_the_components_and_dependencies_of_two_boms() {
+        let first = bom_with_root(
+            "app-a",
+            vec![
+                library("lib-x-a", "pkg:cargo/lib-x@1.0.0"),
+                library("lib-y", "pkg:cargo/lib-y@1.0.0"),
+            ],
+            vec![depends_on("app-a", &["lib-x-a", "lib-y"])],
+        );
+        let second = bom_with_root(
+            "app-b",
+            vec![library("lib-x-b", "pkg:cargo/lib-x@1.0.0")],
+            vec![depends_on("app-b", &["lib-x-b"])],
+        );
+
+        let merged = first
+            .clone()
+            .merge(second.clone(), MergeStrategy::KeepFirst)
+            .expect("Failed to merge");
+        let refs: Vec<_> = merged
+            .components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .map(|c| c.bom_ref.clone().unwrap())
+            .collect();
+        assert_eq!(refs, vec!["lib-x-a", "lib-y", "app-b"]);
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                depends_on("app-a", &["lib-x-a", "lib-y"]),
+                depends_on("app-b", &["lib-x-a"]),
+            ]))
+        );
+        assert_eq!(merged.validate(), Ok(ValidationResult::Passed));
+
+        let merged = first
+            .clone()
+            .merge(second.clone(), MergeStrategy::KeepLast)
+            .expect("Failed to merge");
+        let refs: Vec<_> = merged
+            .components
+            .iter()
+            .flat_map(|c| c.0.iter())
+            .map(|c| c.bom_ref.clone().unwrap())
+            .collect();
+        assert_eq!(refs, vec!["lib-x-b", "lib-y", "app-b"]);
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![
+                depends_on("app-a", &["lib-x-b", "lib-y"]),
+                depends_on("app-b", &["lib-x-b"]),
+            ]))
+        );
+
+        assert_eq!(
+            first.merge(second, MergeStrategy::Error),
+            Err(MergeError::ComponentConflict("lib-x-b".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_merge_a_bom_of_the_same_root_into_it() {
+        let first = bom_with_root(
+            "app",
+            vec![library("lib-x", "pkg:cargo/lib-x@1.0.0")],
+            vec![depends_on("app", &["lib-x"])],
+        );
+        let second = bom_with_root(
+            "app",
+            vec![library("lib-y", "pkg:cargo/lib-y@1.0.0")],
+            vec![depends_on("app", &["lib-y"])],
+        );
+
+        let merged = first
+            .merge(second, MergeStrategy::Error)
+            .expect("Failed to merge");
+
+        assert_eq!(merged.components.map(|c| c.0.len()), Some(2));
+        assert_eq!(
+            merged.dependencies,
+            Some(Dependencies(vec![depends_on("app", &["lib-x", "lib-y"])]))
+        );
+    }
+
+    fn vulnerability_affecting(id: &str, bom_refs: &[&str]) -> Vulnerability {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.id = Some(NormalizedString::new(id));
+        vulnerability.vulnerability_targets = Some(VulnerabilityTargets(
+            bom_refs
+                .iter()
+                .map(|bom_ref| VulnerabilityTarget::new(bom_ref.to_string()))
+                .collect(),
+        ));
+        vulnerability
+    }
+
+    fn target_refs(bom: &Bom) -> Vec<Vec<String>> {
+        bom.vulnerabilities
+            .iter()
+            .flat_map(|v| v.0.iter())
+            .map(|v| {
+                v.vulnerability_targets
+                    .iter()
+                    .flat_map(|t| t.0.iter())
+                    .map(|t| t.bom_ref.clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn assembly_composition(bom_refs: &[&str]) -> Compositions {
+        Compositions(vec![Composition {
+            aggregate: AggregateType::Complete,
+            assemblies: Some(
+                bom_refs
+                    .iter()
+                    .map(|bom_ref| BomReference(bom_ref.to_string()))
+                    .collect(),
+            ),
+            dependencies: None,
+            signature: None,
+        }])
+    }
+
+    #[test]
+    fn it_should_move_the_references_to_a_replaced_component_when_merging() {
+        let mut first = bom_with_root(
+            "app-a",
+            vec![
+                library("lib-x-a", "pkg:cargo/lib-x@1.0.0"),
+                library("lib-y-a", "pkg:cargo/lib-y@1.0.0"),
+            ],
+            vec![depends_on("app-a", &["lib-x-a", "lib-y-a"])],
+        );
+        first.vulnerabilities = Some(Vulnerabilities(vec![vulnerability_affecting(
+            "CVE-2023-0001",
+            &["lib-x-a"],
+        )]));
+        first.compositions = Some(assembly_composition(&["lib-x-a", "lib-y-a"]));
+        let mut second = bom_with_root(
+            "app-b",
+            vec![
+                library("lib-x-b", "pkg:cargo/lib-x@1.0.0"),
+                library("lib-y-b", "pkg:cargo/lib-y@1.0.0"),
+            ],
+            vec![depends_on("app-b", &["lib-x-b", "lib-y-b"])],
+        );
+        second.vulnerabilities = Some(Vulnerabilities(vec![vulnerability_affecting(
+            "CVE-2023-0002",
+            &["lib-y-b"],
+        )]));
+
+        let merged = first
+            .clone()
+            .merge(second.clone(), MergeStrategy::KeepFirst)
+            .expect("Failed to merge");
+        assert_eq!(
+            target_refs(&merged),
+            vec![vec!["lib-x-a".to_string()], vec!["lib-y-a".to_string()]]
+        );
+        assert_eq!(
+            merged.compositions,
+            Some(assembly_composition(&["lib-x-a", "lib-y-a"]))
+        );
+
+        let merged = first
+            .merge(second, MergeStrategy::KeepLast)
+            .expect("Failed to merge");
+        assert_eq!(
+            target_refs(&merged),
+            vec![vec!["lib-x-b".to_string()], vec!["lib-y-b".to_string()]]
+        );
+        assert_eq!(
+            merged.compositions,
+            Some(assembly_composition(&["lib-x-b", "lib-y-b"]))
+        );
+        assert_eq!(merged.validate().unwrap(), ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_collapse_duplicate_components_into_the_first_one() {
+        let hash = |alg, content: &str| Hash {
+            alg,
+            content: HashValue(content.to_string()),
+        };
+        let mut first = library("lib-x-1", "pkg:cargo/lib-x@1.0.0");
+        first.hashes = Some(Hashes(vec![hash(HashAlgorithm::SHA1, "aa")]));
+        let mut duplicate = library("lib-x-2", "pkg:cargo/lib-x@1.0.0");
+        duplicate.hashes = Some(Hashes(vec![
+            hash(HashAlgorithm::SHA1, "aa"),
+            hash(HashAlgorithm::SHA256, "bb"),
+        ]));
+        duplicate.properties = Some(Properties(vec![Property::new("origin", "second")]));
+        let unversioned = |bom_ref: &str| {
+            Component::new(
+                Classification::Library,
+                "lib-y",
+                "1.0.0",
+                Some(bom_ref.to_string()),
+            )
+        };
+        let other_version = Component::new(
+            Classification::Library,
+            "lib-y",
+            "2.0.0",
+            Some("lib-y-3".to_string()),
+        );
+
+        let mut bom = bom_with_root(
+            "app",
+            vec![
+                first,
+                unversioned("lib-y-1"),
+                duplicate,
+                unversioned("lib-y-2"),
+                other_version,
+            ],
+            vec![
+                depends_on("app", &["lib-x-1", "lib-x-2", "lib-y-1"]),
+                depends_on("lib-x-2", &["lib-y-2"]),
+                depends_on("lib-x-1", &["lib-y-3"]),
+            ],
+        );
+
+        assert_eq!(bom.deduplicate(), 2);
+
+        let components = bom.components.as_ref().unwrap();
+        let refs: Vec<_> = components
+            .0
+            .iter()
+            .map(|c| c.bom_ref.clone().unwrap())
+            .collect();
+        assert_eq!(refs, vec!["lib-x-1", "lib-y-1", "lib-y-3"]);
+        assert_eq!(
+            components.0[0].hashes,
+            Some(Hashes(vec![
+                hash(HashAlgorithm::SHA1, "aa"),
+                hash(HashAlgorithm::SHA256, "bb"),
+            ]))
+        );
+        assert_eq!(
+            components.0[0].properties,
+            Some(Properties(vec![Property::new("origin", "second")]))
+        );
+        assert_eq!(
+            bom.dependencies,
+            Some(Dependencies(vec![
+                depends_on("app", &["lib-x-1", "lib-y-1"]),
+                depends_on("lib-x-1", &["lib-y-1", "lib-y-3"]),
+            ]))
+        );
+        assert_eq!(bom.deduplicate(), 0);
+    }
+
+    #[test]
+    fn it_should_move_the_references_to_a_removed_duplicate_to_the_survivor() {
+        let mut bom = bom_with_root(
+            "app",
+            vec![
+                library("lib-x-1", "pkg:cargo/lib-x@1.0.0"),
+                library("lib-x-2", "pkg:cargo/lib-x@1.0.0"),
+            ],
+            vec![depends_on("app", &["lib-x-1", "lib-x-2"])],
+        );
+        bom.vulnerabilities = Some(Vulnerabilities(vec![
+            vulnerability_affecting("CVE-2023-0001", &["lib-x-2"]),
+            vulnerability_affecting("CVE-2023-0002", &["lib-x-1", "lib-x-2"]),
+        ]));
+        bom.compositions = Some(assembly_composition(&["app", "lib-x-2"]));
+
+        assert_eq!(bom.deduplicate(), 1);
+
+        assert_eq!(
+            target_refs(&bom),
+            vec![vec!["lib-x-1".to_string()], vec!["lib-x-1".to_string()]]
+        );
+        assert_eq!(
+            bom.compositions,
+            Some(assembly_composition(&["app", "lib-x-1"]))
+        );
+        assert_eq!(bom.validate().unwrap(), ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_report_duplicate_bom_refs_after_merging() {
+        let mut outer = library("outer", "pkg:cargo/outer@1.0.0");
+        outer.components = Some(Components(vec![library("lib-x", "pkg:cargo/nested@1.0.0")]));
+        let first = bom_with_root("app-a", vec![outer], vec![]);
+        let second = bom_with_root(
+            "app-b",
+            vec![library("lib-x", "pkg:cargo/lib-x@1.0.0")],
+            vec![],
+        );
+
+        match first.merge(second, MergeStrategy::KeepFirst) {
+            Err(MergeError::DuplicateBomRefs(duplicates)) => {
+                assert_eq!(duplicates.len(), 1);
+                assert_eq!(duplicates[0].bom_ref, "lib-x");
+            }
+            result => panic!("Expected duplicate bom-refs, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn it_should_report_conflicting_analysis_states_when_merging() {
+        let vex = |state: ImpactAnalysisState| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new("CVE-2023-0001"));
+            vulnerability.vulnerability_analysis =
+                Some(VulnerabilityAnalysis::new(Some(state), None, None));
+            Bom {
+                vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+                ..Bom::default()
+            }
+        };
+        let first = vex(ImpactAnalysisState::Exploitable);
+        let second = vex(ImpactAnalysisState::NotAffected);
+
+        assert_eq!(
+            first.clone().merge(second.clone(), MergeStrategy::Error),
+            Err(MergeError::AnalysisStateConflicts(vec![
+                AnalysisStateConflict {
+                    id: NormalizedString::new("CVE-2023-0001"),
+                    existing: ImpactAnalysisState::Exploitable,
+                    incoming: ImpactAnalysisState::NotAffected,
+                }
+            ]))
+        );
+
+        let merged = first
+            .merge(second, MergeStrategy::KeepFirst)
+            .expect("Failed to merge");
+        let vulnerabilities = merged.vulnerabilities.expect("Missing vulnerabilities");
+        assert_eq!(vulnerabilities.0.len(), 1);
+        assert_eq!(
+            vulnerabilities.0[0]
+                .vulnerability_analysis
+                .as_ref()
+                .and_then(|a| a.state.clone()),
+            Some(ImpactAnalysisState::Exploitable)
+        );
+    }
+
+    #[test]
+    fn it_should_take_out_all_components_including_nested_ones() {
+        let component = |name: &str| Component::new(Classification::Library, name, "1.0.0", None);
+        let mut app_lib = component("app-lib");
+        app_lib.components = Some(Components(vec![component("app-lib-macros")]));
+        let mut app = component("app");
+        app.components = Some(Components(vec![app_lib, component("app-bin")]));
+        let bom = Bom {
+            components: Some(Components(vec![app, component("serde")])),
+            services: Some(Services(vec![Service::new("api", None)])),
+            ..Bom::default()
+        }
+        .with_metadata(Metadata {
+            component: Some(component("root")),
+            ..Metadata::default()
+        });
+
+        let parts = bom.clone().into_parts();
+        assert_eq!(parts.components, bom.components.clone().unwrap().0);
+        assert_eq!(parts.services.len(), 1);
+        assert!(parts.dependencies.is_empty());
+        assert_eq!(parts.metadata, bom.metadata);
+
+        let components = bom.into_components();
+        assert_eq!(
+            components
+                .iter()
+                .map(|c| c.name.to_string())
+                .collect::<Vec<_>>(),
+            vec!["app", "app-lib", "app-lib-macros", "app-bin", "serde"]
+        );
+        assert!(components.iter().all(|c| c.components.is_none()));
+    }
+
+    #[test]
+    fn it_should_resolve_bom_refs_to_components_and_services() {
+        let mut app = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("a".into()),
+        );
+        app.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "app-lib",
+            "1.0.0",
+            Some("b".into()),
+        )]));
+        let mut metadata = Metadata::new().unwrap();
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            "root",
+            "1.0.0",
+            Some("root".into()),
+        ));
+        let mut service = Service::new("api", Some("s".into()));
+        service.services = Some(Services(vec![Service::new("auth", Some("t".into()))]));
+        let bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![
+                app,
+                Component::new(Classification::Library, "first", "1.0.0", Some("c".into())),
+                Component::new(Classification::Library, "second", "1.0.0", Some("c".into())),
+            ])),
+            services: Some(Services(vec![service])),
+            ..Bom::default()
+        };
+
+        let name = |component: Option<&Component>| component.map(|c| c.name.to_string());
+        assert_eq!(name(bom.component_by_ref("root")), Some("root".into()));
+        assert_eq!(name(bom.component_by_ref("b")), Some("app-lib".into()));
+        assert_eq!(name(bom.component_by_ref("c")), Some("first".into()));
+        assert_eq!(name(bom.component_by_ref("s")), None);
+        assert_eq!(
+            bom.service_by_ref("t").map(|s| s.name.to_string()),
+            Some("auth".into())
+        );
+        assert!(bom.service_by_ref("a").is_none());
+    }
+
+    #[test]
+    fn it_should_find_components_by_name_and_version() {
+        let mut app = Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("a".into()),
+        );
+        app.components = Some(Components(vec![Component::new(
+            Classification::Library,
+            "app-lib",
+            "1.0.0",
+            Some("b".into()),
+        )]));
+        let bom = Bom {
+            components: Some(Components(vec![
+                Component::new(Classification::Library, "serde", "1.0.0", Some("c".into())),
+                app,
+                Component::new(Classification::Library, "serde", "2.0.0", Some("d".into())),
+            ])),
+            ..Bom::default()
+        };
+
+        let bom_ref = |component: Option<&Component>| component.and_then(|c| c.bom_ref.clone());
+        assert_eq!(bom_ref(bom.find_component("serde", None)), Some("c".into()));
+        assert_eq!(
+            bom_ref(bom.find_component("serde", Some("2.0.0"))),
+            Some("d".into())
+        );
+        assert_eq!(
+            bom_ref(bom.find_component("app-lib", None)),
+            Some("b".into())
+        );
+        assert_eq!(bom_ref(bom.find_component("serde", Some("3.0.0"))), None);
+        assert_eq!(bom_ref(bom.find_component("rand", None)), None);
+    }
+
+    #[test]
+    fn it_should_list_components_without_licenses() {
+        let mut licensed =
+            Component::new(Classification::Library, "serde", "1.0.0", Some("a".into()));
+        licensed.licenses = Some(Licenses(vec![LicenseChoice::License(
+            License::named_license("MIT"),
+        )]));
+        let unlicensed = Component::new(
+            Classification::Library,
+            "no-license",
+            "1.0.0",
+            Some("b".into()),
+        );
+        let mut empty_licenses = Component::new(
+            Classification::Library,
+            "test-helper",
+            "1.0.0",
+            Some("c".into()),
+        );
+        empty_licenses.licenses = Some(Licenses(vec![]));
+        empty_licenses.scope = Some(Scope::Excluded);
+        let bom = Bom {
+            components: Some(Components(vec![licensed, unlicensed, empty_licenses])),
+            ..Bom::default()
+        };
+
+        let names = |components: Vec<&Component>| -> Vec<String> {
+            components.iter().map(|c| c.name.to_string()).collect()
+        };
+        assert_eq!(names(bom.components_without_licenses()), vec!["no-license"]);
+        assert_eq!(
+            names(bom.components_without_licenses_in_any_scope()),
+            vec!["no-license", "test-helper"]
+        );
+    }
+
+    #[test]
+    fn it_should_detect_a_purl_that_disagrees_with_the_component_version() {
+        let mut consistent =
+            Component::new(Classification::Library, "serde", "1.0.0", Some("a".into()));
+        consistent.purl = Some(Purl("pkg:cargo/serde@1.0.0".to_string()));
+        let mut mismatched =
+            Component::new(Classification::Library, "serde", "2.0.0", Some("b".into()));
+        mismatched.purl = Some(Purl("pkg:cargo/serde@1.0.0".to_string()));
+        let mut unparseable =
+            Component::new(Classification::Library, "serde", "2.0.0", Some("c".into()));
+        unparseable.purl = Some(Purl("not a purl".to_string()));
+        let mut renamed = Component::new(
+            Classification::Library,
+            "serde-json",
+            "1.0.0",
+            Some("d".into()),
+        );
+        renamed.purl = Some(Purl("pkg:cargo/serde_json@1.0.0".to_string()));
+
+        let bom = Bom {
+            components: Some(Components(vec![
+                consistent,
+                mismatched,
+                unparseable,
+                renamed,
+            ])),
+            ..Bom::default()
+        };
+
+        let component_purl = |index| {
+            ValidationContext(vec![
+                ValidationPathComponent::Struct {
+                    struct_name: "Bom".to_string(),
+                    field_name: "components".to_string(),
+                },
+                ValidationPathComponent::Array { index },
+                ValidationPathComponent::Struct {
+                    struct_name: "Component".to_string(),
+                    field_name: "purl".to_string(),
+                },
+            ])
+        };
+        let mismatches = bom.validate_purl_consistency();
+        assert_eq!(
+            mismatches,
+            vec![
+                ValidationError::PurlMismatch {
+                    context: component_purl(1),
+                    field: "version".to_string(),
+                    purl_value: "1.0.0".to_string(),
+                    component_value: "2.0.0".to_string(),
+                },
+                ValidationError::PurlMismatch {
+                    context: component_purl(3),
+                    field: "name".to_string(),
+                    purl_value: "serde_json".to_string(),
+                    component_value: "serde-json".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            mismatches[0].to_string(),
+            r#"Bom.components[1] > Component.purl: Purl version "1.0.0" does not match component version "2.0.0""#
+        );
+    }
+
+    #[test]
+    fn it_should_report_a_purl_version_mismatch_alongside_other_failures() {
+        let mut mismatched =
+            Component::new(Classification::Library, "serde", "2.0.0", Some("a".into()));
+        mismatched.purl = Some(Purl("pkg:cargo/serde@1.0.0".to_string()));
+        let mut renamed = Component::new(
+            Classification::Library,
+            "serde-json",
+            "1.0.0",
+            Some("b".into()),
+        );
+        renamed.purl = Some(Purl("pkg:cargo/serde_json@1.0.0".to_string()));
+
+        let bom = Bom {
+            components: Some(Components(vec![mismatched, renamed])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "missing".to_string(),
+                dependencies: vec![],
+            }])),
+            ..Bom::default()
+        };
+
+        let reasons = bom
+            .validate()
+            .expect("Failed to validate bom")
+            .into_result()
+            .expect_err("Expected the validation to fail");
+        let messages: Vec<String> = reasons.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            vec![
+                r#"Bom.components[0] > Component.purl: Purl version "1.0.0" does not match component version "2.0.0""#,
+                "Bom.dependencies[0] > Dependency.dependency_ref: Dependency reference does not exist in the BOM",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_idempotently() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "bom-ref": "8f2c",
+      "name": "serde",
+      "version": "1.0.0",
+      "purl": "pkg:cargo/serde@1.0.0",
+      "hashes": [],
+      "properties": [
+        { "name": "b", "value": "2" },
+        { "name": "a", "value": "1" }
+      ]
+    },
+    {
+      "type": "library",
+      "bom-ref": "11aa",
+      "name": "anyhow",
+      "version": "1.0.0",
+      "externalReferences": []
+    },
+    {
+      "type": "library",
+      "bom-ref": "37d0",
+      "name": "anyhow",
+      "version": "1.0.0"
+    }
+  ],
+  "services": [],
+  "dependencies": [
+    { "ref": "8f2c", "dependsOn": ["37d0", "11aa", "11aa"] },
+    { "ref": "11aa", "dependsOn": [] },
+    { "ref": "37d0", "dependsOn": [] }
+  ]
+}"#;
+
+        let normalize = |input: &[u8]| {
+            let mut bom = Bom::parse_from_json_v1_4(input).expect("Failed to parse JSON");
+            bom.normalize();
+            bom.to_cyclonedx_json_bytes(SpecVersion::V1_4)
+                .expect("Failed to write JSON")
+        };
+
+        let once = normalize(input.as_bytes());
+        let twice = normalize(&once);
+        assert_eq!(
+            String::from_utf8_lossy(&once),
+            String::from_utf8_lossy(&twice)
+        );
+
+        let bom = Bom::parse_from_json_v1_4(once.as_slice()).expect("Failed to parse JSON");
+        assert_eq!(bom.services, None);
+
+        let components = bom.components.expect("Expected components").0;
+        let bom_refs: Vec<_> = components.iter().map(|c| c.bom_ref.clone()).collect();
+        assert_eq!(
+            bom_refs,
+            vec![
+                Some("anyhow@1.0.0".to_string()),
+                Some("anyhow@1.0.0-2".to_string()),
+                Some("pkg:cargo/serde@1.0.0".to_string()),
+            ]
+        );
+        assert_eq!(components[0].external_references, None);
+        assert_eq!(components[2].hashes, None);
+        let property_names: Vec<_> = components[2]
+            .properties
+            .iter()
+            .flat_map(|p| p.0.iter().map(|p| p.name.clone()))
+            .collect();
+        assert_eq!(property_names, vec!["a", "b"]);
+
+        let dependencies = bom.dependencies.expect("Expected dependencies").0;
+        assert_eq!(
+            dependencies[2],
+            Dependency {
+                dependency_ref: "pkg:cargo/serde@1.0.0".to_string(),
+                dependencies: vec!["anyhow@1.0.0".to_string(), "anyhow@1.0.0-2".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_check_contact_email_addresses_separately_from_validation() {
+        let mut vulnerability = Vulnerability::new(None);
+        vulnerability.vulnerability_credits = Some(VulnerabilityCredits::new().with_individual(
+            OrganizationalContact::new("Researcher", Some("researcher@example.com")),
+        ));
+        let mut service = Service::new("api", None);
+        service.provider = Some(OrganizationalEntity {
+            name: None,
+            url: None,
+            contact: Some(vec![OrganizationalContact::new(
+                "Support",
+                Some("support@partner"),
+            )]),
+        });
+        let bom = Bom {
+            metadata: Some(Metadata {
+                authors: Some(vec![
+                    OrganizationalContact::new("Jane Doe", Some("jane@example.com")),
+                    OrganizationalContact::new("John Doe", Some("john.example.com")),
+                ]),
+                ..Metadata::default()
+            }),
+            services: Some(Services(vec![service])),
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        };
+
+        assert_eq!(bom.validate().unwrap(), ValidationResult::Passed);
+        let reasons = bom
+            .validate_contact_emails()
+            .into_result()
+            .expect_err("Accepted invalid email addresses");
+        assert_eq!(
+            reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec![
+                "Bom.metadata > Metadata.authors[1] > OrganizationalContact.email: Email address is not valid",
+                "Bom.services[0] > Service.provider > OrganizationalEntity.contact[0] > OrganizationalContact.email: Email address is not valid",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_only_preserve_foreign_elements_when_asked_to() {
+        let input = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ex="https://example.com" version="1">
+  <annotations />
+  <ex:build id="42" note="a &amp; &quot;b&quot;">
+    <ex:host>ci &lt;1&gt;</ex:host>
+    <other xmlns="https://other.example.com" />
+  </ex:build>
+</bom>"#;
+
+        let dropped = Bom::parse_from_xml_v1_5(input.as_bytes()).expect("Failed to parse BOM");
+        assert_eq!(dropped.foreign_extensions, Vec::new());
+
+        let options = ParseOptions {
+            preserve_foreign: true,
+            ..ParseOptions::default()
+        };
+        let preserved = Bom::parse_from_xml_with_options(input.as_bytes(), options)
+            .expect("Failed to parse BOM")
+            .bom;
+        let extension = ForeignExtension {
+            name: "ex:build".to_string(),
+            namespace: Some("https://example.com".to_string()),
+            attributes: vec![
+                ("id".to_string(), "42".to_string()),
+                ("note".to_string(), r#"a & "b""#.to_string()),
+            ],
+            inner_xml: r#"<ex:host>ci &lt;1></ex:host><other xmlns="https://other.example.com" />"#
+                .to_string(),
+        };
+        assert_eq!(preserved.foreign_extensions, vec![extension]);
+
+        let mut output = Vec::new();
+        preserved
+            .clone()
+            .output_as_xml_v1_5(&mut output)
+            .expect("Failed to write BOM");
+        let reread = Bom::parse_from_xml_with_options(output.as_slice(), options)
+            .expect("Failed to parse written BOM")
+            .bom;
+        assert_eq!(reread.foreign_extensions, preserved.foreign_extensions);
+    }
+
+    #[test]
+    fn it_should_reject_unknown_xml_content_when_strict() {
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+            ..ParseOptions::default()
+        };
+        let parse = |input: &str, options| {
+            Bom::parse_from_xml_with_options(input.as_bytes(), options).map_err(|e| e.to_string())
+        };
+
+        let known = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library"><version>1.0</version><name>a</name></component>
+    <component type="library"><name>b</name></component>
+  </components>
+  <metadata><timestamp>2024-01-01T00:00:00Z</timestamp></metadata>
+</bom>"#;
+        assert!(parse(known, strict).is_ok());
+
+        let unknown_element = known.replace("<name>b</name>", "<name>b</name><color />");
+        assert!(parse(&unknown_element, ParseOptions::default()).is_ok());
+        assert_eq!(
+            parse(&unknown_element, strict).unwrap_err(),
+            "Got unknown element or attribute bom/components/component[1]/color"
+        );
+
+        let unknown_attribute = known.replace("<metadata>", r#"<metadata color="red">"#);
+        assert_eq!(
+            parse(&unknown_attribute, strict).unwrap_err(),
+            "Got unknown element or attribute bom/metadata/@color"
+        );
 
-    fn add_service_bom_ref(&mut self, bom_ref: impl ToString) {
-        self.service_bom_refs.insert(bom_ref.to_string());
+        let foreign = known.replace(
+            "</bom>",
+            r#"<ex:build xmlns:ex="https://example.com" /></bom>"#,
+        );
+        assert_eq!(
+            parse(&foreign, strict).unwrap_err(),
+            "Got unknown element or attribute bom/ex:build"
+        );
+        let preserving = ParseOptions {
+            preserve_foreign: true,
+            ..strict
+        };
+        assert!(parse(&foreign, preserving).is_ok());
     }
-}
 
-fn validate_component_bom_refs(
-    component: &Component,
-    bom_refs: &mut BomReferencesContext,
-    context: &ValidationContext,
-    results: &mut Vec<ValidationResult>,
-) {
-    if let Some(bom_ref) = &component.bom_ref {
-        if bom_refs.contains(bom_ref) {
-            let context = context.extend_context_with_struct_field("Component", "bom_ref");
-            results.push(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
-                    context,
-                }],
-            });
+    #[test]
+    fn it_should_reject_nested_entity_expansion() {
+        let mut input =
+            String::from("<?xml version=\"1.0\"?>\n<!DOCTYPE bom [\n<!ENTITY lol0 \"lol\">\n");
+        for level in 1..10 {
+            let previous = format!("&lol{};", level - 1);
+            input.push_str(&format!(
+                "<!ENTITY lol{level} \"{}\">\n",
+                previous.repeat(10)
+            ));
         }
-        bom_refs.add_component_bom_ref(bom_ref);
-    }
+        input.push_str(
+            r#"]>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <metadata><component type="library"><name>&lol9;</name></component></metadata>
+</bom>"#,
+        );
 
-    if let Some(components) = &component.components {
-        let context = context.extend_context_with_struct_field("Component", "components");
-        validate_components(components, bom_refs, &context, results);
+        let error = Bom::parse_from_xml_v1_5(input.as_bytes()).expect_err("Should be rejected");
+        assert!(
+            matches!(error, crate::errors::XmlReadError::LimitExceeded { .. }),
+            "Unexpected error: {error}"
+        );
     }
-}
 
-fn validate_components(
-    components: &Components,
-    bom_refs: &mut BomReferencesContext,
-    context: &ValidationContext,
-    results: &mut Vec<ValidationResult>,
-) {
-    // record the component references
-    for (component_index, component) in components.0.iter().enumerate() {
-        let context = context.extend_context(vec![ValidationPathComponent::Array {
-            index: component_index,
-        }]);
+    #[test]
+    fn it_should_reject_documents_exceeding_the_configured_limits() {
+        let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library">
+      <name>a</name>
+      <components><component type="library"><name>b</name></component></components>
+    </component>
+  </components>
+</bom>"#;
+        let parse = |limits| {
+            let options = ParseOptions {
+                limits,
+                ..ParseOptions::default()
+            };
+            Bom::parse_from_xml_with_options(input.as_bytes(), options)
+                .map_err(|error| error.to_string())
+        };
 
-        validate_component_bom_refs(component, bom_refs, &context, results);
+        assert!(parse(XmlLimits::default()).is_ok());
+        assert_eq!(
+            parse(XmlLimits {
+                max_depth: 5,
+                ..XmlLimits::default()
+            })
+            .unwrap_err(),
+            "Document exceeds the limit of 5 nested elements at line 5, column 45"
+        );
+        assert_eq!(
+            parse(XmlLimits {
+                max_bytes: 100,
+                ..XmlLimits::default()
+            })
+            .unwrap_err(),
+            "Document exceeds the limit of 100 bytes"
+        );
     }
-}
 
-fn validate_service_bom_refs(
-    service: &Service,
-    bom_refs: &mut BomReferencesContext,
-    context: &ValidationContext,
-    results: &mut Vec<ValidationResult>,
-) {
-    if let Some(bom_ref) = &service.bom_ref {
-        if bom_refs.contains(bom_ref) {
-            let context = context.extend_context_with_struct_field("Service", "bom_ref");
-            results.push(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: format!(r#"Bom ref "{bom_ref}" is not unique"#),
-                    context,
-                }],
-            });
+    fn nested_xml_components(depth: usize) -> String {
+        format!(
+            r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">{}{}</bom>"#,
+            r#"<components><component type="library"><name>a</name>"#.repeat(depth),
+            "</component></components>".repeat(depth)
+        )
+    }
+
+    fn nested_json_services(depth: usize) -> String {
+        let mut service = r#"{"name": "a"}"#.to_string();
+        for _ in 1..depth {
+            service = format!(r#"{{"name": "a", "services": [{service}]}}"#);
         }
-        bom_refs.add_service_bom_ref(bom_ref);
+        format!(r#"{{"bomFormat": "CycloneDX", "specVersion": "1.5", "services": [{service}]}}"#)
     }
 
-    if let Some(services) = &service.services {
-        let context = context.extend_context_with_struct_field("Service", "services");
-        validate_services(services, bom_refs, &context, results);
+    #[test]
+    fn it_should_read_components_nested_up_to_the_default_limit() {
+        let input = nested_xml_components(DEFAULT_MAX_NESTING);
+        Bom::parse_from_xml_v1_5(input.as_bytes()).expect("Failed to parse BOM");
+        let input = nested_json_services(DEFAULT_MAX_NESTING);
+        Bom::parse_from_json(input.as_bytes()).expect("Failed to parse BOM");
     }
-}
 
-fn validate_services(
-    services: &Services,
-    bom_refs: &mut BomReferencesContext,
-    context: &ValidationContext,
-    results: &mut Vec<ValidationResult>,
-) {
-    // record the service references
-    for (service_index, service) in services.0.iter().enumerate() {
-        let context = context.extend_context(vec![ValidationPathComponent::Array {
-            index: service_index,
-        }]);
+    #[test]
+    fn it_should_reject_deeply_nested_xml_components() {
+        let error = Bom::parse_from_xml_with_options(
+            nested_xml_components(1000).as_bytes(),
+            ParseOptions::default(),
+        )
+        .expect_err("Should be rejected");
+        assert!(
+            matches!(
+                error,
+                crate::errors::XmlReadError::MaxDepthExceeded { depth: 51, .. }
+            ),
+            "Unexpected error: {error}"
+        );
 
-        validate_service_bom_refs(service, bom_refs, &context, results);
+        let options = ParseOptions {
+            max_nesting: 3,
+            ..ParseOptions::default()
+        };
+        let parse = |depth: usize| {
+            Bom::parse_from_xml_with_options(nested_xml_components(depth).as_bytes(), options)
+        };
+        assert!(parse(3).is_ok());
+        assert_eq!(
+            parse(4).expect_err("Should be rejected").to_string(),
+            "Components or services are nested 4 levels deep, more than allowed at line 1, column 230"
+        );
+
+        let foreign = nested_xml_components(3).replace(
+            "</bom>",
+            &format!(
+                r#"<ex:build xmlns:ex="https://example.com">{}{}</ex:build></bom>"#,
+                "<ex:component>".repeat(4),
+                "</ex:component>".repeat(4)
+            ),
+        );
+        assert!(
+            Bom::parse_from_xml_with_options(foreign.as_bytes(), options).is_ok(),
+            "Counted components from another namespace"
+        );
     }
-}
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct UrnUuid(pub(crate) String);
+    #[test]
+    fn it_should_reject_deeply_nested_json_services() {
+        // Rejected by serde_json's own recursion limit before it is counted
+        assert!(Bom::parse_from_json(nested_json_services(1000).as_bytes()).is_err());
 
-impl UrnUuid {
-    pub fn new(value: String) -> Result<Self, UrnUuidError> {
-        match matches_urn_uuid_regex(&value) {
-            true => Ok(Self(value)),
-            false => Err(UrnUuidError::InvalidUrnUuid(
-                "UrnUuid does not match regular expression".to_string(),
-            )),
-        }
-    }
+        let error = Bom::parse_from_json(nested_json_services(60).as_bytes())
+            .expect_err("Should be rejected");
+        assert!(
+            matches!(
+                error,
+                crate::errors::JsonReadError::MaxDepthExceeded { depth: 60 }
+            ),
+            "Unexpected error: {error}"
+        );
 
-    pub fn generate() -> Self {
-        Self::from(uuid::Uuid::new_v4())
+        let options = ParseOptions {
+            max_nesting: 3,
+            ..ParseOptions::default()
+        };
+        let parse = |depth: usize| {
+            Bom::parse_from_json_with_options(nested_json_services(depth).as_bytes(), options)
+        };
+        assert!(parse(3).is_ok());
+        assert_eq!(
+            parse(4).expect_err("Should be rejected").to_string(),
+            "Components or services are nested 4 levels deep, more than allowed"
+        );
     }
-}
 
-impl fmt::Display for UrnUuid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+    #[test]
+    fn it_should_report_the_position_of_xml_read_errors() {
+        let truncated = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library">
+      <name>a"#;
+        let error = Bom::parse_from_xml_v1_5(truncated.as_bytes()).expect_err("Should fail");
+        assert_eq!(
+            error.position(),
+            Some(crate::errors::XmlPosition {
+                line: 5,
+                column: 14
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "Failed to deserialize XML while reading {http://cyclonedx.org/schema/bom/1.5}name: Unexpected end of stream: still inside the root element at line 5, column 14"
+        );
 
-impl From<uuid::Uuid> for UrnUuid {
-    fn from(uuid: uuid::Uuid) -> Self {
-        Self(format!("urn:uuid:{}", uuid))
+        let invalid = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library">
+      <name>a</name>
+    </component>
+    <component type="gadget" />
+  </components>
+</bom>"#;
+        let error = Bom::parse_from_xml_v1_5(invalid.as_bytes()).expect_err("Should fail");
+        assert_eq!(
+            error.position(),
+            Some(crate::errors::XmlPosition { line: 6, column: 5 })
+        );
     }
-}
 
-impl Validate for UrnUuid {
-    fn validate_with_context(
-        &self,
-        context: ValidationContext,
-    ) -> Result<ValidationResult, ValidationError> {
-        match matches_urn_uuid_regex(&self.0) {
-            true => Ok(ValidationResult::Passed),
-            false => Ok(ValidationResult::Failed {
-                reasons: vec![FailureReason {
-                    message: "UrnUuid does not match regular expression".to_string(),
-                    context,
-                }],
-            }),
-        }
+    #[test]
+    fn it_should_collect_warnings_when_parsing_json() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "metadata": {"component": {"type": "gadget", "name": "app"}},
+  "components": [
+    {"type": "library", "name": "a"},
+    {"type": "library", "name": "b", "color": "red", "components": [
+      {"type": "library", "name": "c", "scope": "sometimes"}
+    ]}
+  ]
+}"#;
+        let parsed = Bom::parse_from_json_with_options(input.as_bytes(), ParseOptions::default())
+            .expect("Failed to parse BOM");
+        let warnings: Vec<String> = parsed.warnings.iter().map(|w| w.to_string()).collect();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "version: Missing version, assuming 1",
+                "components[1].color: Unknown field, ignored",
+                "metadata.component.type: Unknown component type 'gadget'",
+                "components[1].components[0].scope: Unknown scope 'sometimes'",
+            ]
+        );
+        assert_eq!(
+            parsed.bom,
+            Bom::parse_from_json(input.as_bytes()).expect("Failed to parse BOM")
+        );
     }
-}
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub enum UrnUuidError {
-    InvalidUrnUuid(String),
-}
+    #[test]
+    fn it_should_collect_warnings_when_parsing_xml() {
+        let input = r#"<bom xmlns="http://cyclonedx.org/schema/bom/1.5" xmlns:ex="https://example.com">
+  <components>
+    <component type="library"><name>a</name></component>
+    <component type="library" color="red"><name>b</name><scope>sometimes</scope></component>
+  </components>
+  <ex:build />
+</bom>"#;
+        let parsed = Bom::parse_from_xml_with_options(input.as_bytes(), ParseOptions::default())
+            .expect("Failed to parse BOM");
+        let warnings: Vec<String> = parsed.warnings.iter().map(|w| w.to_string()).collect();
 
-fn matches_urn_uuid_regex(value: &str) -> bool {
-    static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"^urn:uuid:[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$")
-            .expect("Failed to compile regex.")
-    });
-    UUID_REGEX.is_match(value)
-}
+        assert_eq!(
+            warnings,
+            vec![
+                "bom/@version: Missing version, assuming 1",
+                "bom/components/component[1]/@color: Unknown element or attribute, ignored",
+                "bom/ex:build: Element from another namespace, ignored",
+                "bom/components/component[1]/scope: Unknown scope 'sometimes'",
+            ]
+        );
+        assert_eq!(parsed.bom.foreign_extensions, Vec::new());
+        assert_eq!(parsed.spec_version, SpecVersion::V1_5);
+    }
 
-#[cfg(test)]
-mod test {
-    use crate::{
-        external_models::{date_time::DateTime, normalized_string::NormalizedString, uri::Uri},
-        models::{
-            component::{Classification, Component},
-            composition::{AggregateType, BomReference, Composition},
-            dependency::Dependency,
-            external_reference::{ExternalReference, ExternalReferenceType},
-            property::Property,
-            service::Service,
-            vulnerability::Vulnerability,
-        },
-        validation::ValidationPathComponent,
-    };
+    #[test]
+    fn it_should_reject_unknown_json_fields_when_strict() {
+        let strict = ParseOptions {
+            mode: ParseMode::Strict,
+            ..ParseOptions::default()
+        };
+        let parse = |input: &str, options| {
+            Bom::parse_from_json_with_options(input.as_bytes(), options).map_err(|e| e.to_string())
+        };
 
-    use super::*;
-    use pretty_assertions::assert_eq;
+        let known = r#"{
+  "$schema": "http://cyclonedx.org/schema/bom-1.5.schema.json",
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1,
+  "serialNumber": null,
+  "components": [
+    {"type": "library", "name": "a"},
+    {"type": "library", "name": "b", "hashes": [{"alg": "SHA-1", "content": "a94a8fe5ccb19ba61c4c0873d391e987982fbbd3"}]}
+  ]
+}"#;
+        assert!(parse(known, strict).is_ok());
 
-    #[test]
-    fn it_should_parse_json_using_function_without_suffix() {
-        let input = r#"{
-            "bomFormat": "CycloneDX",
-            "specVersion": "1.3",
-            "serialNumber": "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79",
-            "version": 1,
-            "components": []
-        }"#;
-        let result = Bom::parse_from_json(input.as_bytes());
-        assert!(result.is_ok());
+        let unknown = known.replace(r#""alg": "SHA-1""#, r#""alg": "SHA-1", "color": "red""#);
+        assert!(parse(&unknown, ParseOptions::default()).is_ok());
+        assert_eq!(
+            parse(&unknown, strict).unwrap_err(),
+            "Invalid input format found: Unknown field 'components[1].hashes[0].color'"
+        );
     }
 
     #[test]
@@ -616,6 +5401,7 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         };
 
         let actual = bom
@@ -642,6 +5428,7 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -684,6 +5471,161 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_resolve_dependency_refs_to_the_root_components_and_services() {
+        // The dependencies come before the components and services they refer to
+        let json = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.4",
+  "version": 1,
+  "dependencies": [
+    { "ref": "app", "dependsOn": ["app-lib", "lib", "api"] },
+    { "ref": "lib", "dependsOn": ["dangling"] }
+  ],
+  "metadata": {
+    "component": {
+      "type": "application",
+      "name": "app",
+      "version": "1.0.0",
+      "bom-ref": "app",
+      "components": [
+        { "type": "library", "name": "app-lib", "version": "1.0.0", "bom-ref": "app-lib" }
+      ]
+    }
+  },
+  "components": [
+    { "type": "library", "name": "lib", "version": "1.0.0", "bom-ref": "lib" }
+  ],
+  "services": [
+    { "name": "api", "bom-ref": "api" }
+  ]
+}"#;
+        let bom = Bom::parse_from_json_v1_4(json.as_bytes()).expect("Failed to parse BOM");
+
+        let reasons = bom
+            .validate()
+            .expect("Failed to validate bom")
+            .into_result()
+            .expect_err("Validated a dangling dependency ref");
+
+        assert_eq!(
+            reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec![
+                "Bom.dependencies[1] > Dependency.dependencies[0]: Dependency reference does not exist in the BOM",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_flag_unknown_spdx_license_ids_read_from_a_document() {
+        let input = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "name": "known",
+      "licenses": [{ "license": { "id": "MIT" } }]
+    },
+    {
+      "type": "library",
+      "name": "unknown",
+      "licenses": [{ "license": { "id": "MITT" } }]
+    }
+  ]
+}"#;
+        let bom = Bom::parse_from_json(input.as_bytes()).expect("Failed to parse BOM");
+
+        let reasons = bom
+            .validate()
+            .expect("Failed to validate bom")
+            .into_result()
+            .expect_err("Validated an unknown license id");
+
+        assert_eq!(
+            reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec!["Bom.components[1] > Component.licenses[0]::License > License.license_identifier::SpdxId: SPDX identifier is not valid"]
+        );
+    }
+
+    #[test]
+    fn it_should_normalize_a_multi_line_license_name_on_read() {
+        let json = r#"{
+  "bomFormat": "CycloneDX",
+  "specVersion": "1.5",
+  "version": 1,
+  "components": [
+    {
+      "type": "library",
+      "name": "lib",
+      "licenses": [{ "license": { "name": "Example\r\nLicense\t1.0" } }]
+    }
+  ]
+}"#;
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.5" version="1">
+  <components>
+    <component type="library">
+      <name>lib</name>
+      <licenses>
+        <license>
+          <name>Example
+License&#9;1.0</name>
+        </license>
+      </licenses>
+    </component>
+  </components>
+</bom>"#;
+
+        for bom in [
+            Bom::parse_from_json(json.as_bytes()).expect("Failed to parse JSON"),
+            Bom::parse_from_xml_v1_5(xml.as_bytes()).expect("Failed to parse XML"),
+        ] {
+            let licenses = bom.components.as_ref().unwrap().0[0].licenses.as_ref();
+            assert_eq!(
+                licenses.unwrap().0,
+                vec![LicenseChoice::License(License::named_license(
+                    "Example License 1.0"
+                ))]
+            );
+            assert_eq!(bom.validate().unwrap(), ValidationResult::Passed);
+        }
+    }
+
+    #[test]
+    fn it_should_list_every_validation_problem_with_its_path() {
+        let mut licensed =
+            Component::new(Classification::Library, "serde", "1.0.0", Some("a".into()));
+        licensed.licenses = Some(Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+            "MIT OR".to_string(),
+        ))]));
+        let duplicate = Component::new(Classification::Library, "rand", "1.0.0", Some("a".into()));
+        let bom = Bom {
+            components: Some(Components(vec![licensed, duplicate])),
+            dependencies: Some(Dependencies(vec![Dependency {
+                dependency_ref: "a".to_string(),
+                dependencies: vec!["missing".to_string()],
+            }])),
+            ..Bom::default()
+        };
+
+        let reasons = bom
+            .validate()
+            .expect("Failed to validate bom")
+            .into_result()
+            .expect_err("Validated an invalid bom");
+
+        assert_eq!(
+            reasons.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec![
+                "Bom.components[0] > Component.licenses[0]::Expression: SPDX expression is not valid",
+                "Bom.components[1] > Component.bom_ref: Bom ref \"a\" is not unique",
+                "Bom.dependencies[0] > Dependency.dependencies[0]: Dependency reference does not exist in the BOM",
+            ]
+        );
+    }
+
     #[test]
     fn it_should_validate_broken_composition_refs_as_failed() {
         let bom = Bom {
@@ -703,6 +5645,7 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         };
 
         let actual = bom.validate().expect("Failed to validate bom");
@@ -760,6 +5703,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![Component {
                 component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -786,6 +5730,9 @@ mod test {
                 components: None,
                 evidence: None,
                 signature: None,
+                tags: None,
+                omnibor_ids: Vec::new(),
+                swhids: Vec::new(),
             }])),
             services: Some(Services(vec![Service {
                 bom_ref: None,
@@ -836,6 +5783,8 @@ mod test {
                 description: None,
                 detail: None,
                 recommendation: None,
+                workaround: None,
+                proof_of_concept: None,
                 advisories: None,
                 created: None,
                 published: None,
@@ -847,6 +5796,7 @@ mod test {
                 properties: None,
             }])),
             signature: None,
+            foreign_extensions: Vec::new(),
         };
 
         let actual = bom
@@ -988,6 +5938,7 @@ mod test {
                 supplier: None,
                 licenses: None,
                 properties: None,
+                lifecycles: None,
             }),
             components: Some(Components(vec![
                 component_builder("metadata-component"),
@@ -1008,6 +5959,7 @@ mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -1124,6 +6076,50 @@ mod test {
         assert_eq!(validation_result, ValidationResult::Passed);
     }
 
+    #[test]
+    fn it_should_derive_the_same_uuid_from_the_same_content() {
+        let namespace = uuid::Uuid::NAMESPACE_URL;
+        let first = UrnUuid::v5_from_content(&namespace, b"pkg:cargo/serde@1.0.0");
+        let second = UrnUuid::v5_from_content(&namespace, b"pkg:cargo/serde@1.0.0");
+        let other = UrnUuid::v5_from_content(&namespace, b"pkg:cargo/serde@1.0.1");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(
+            first.validate_with_context(ValidationContext::default()),
+            Ok(ValidationResult::Passed)
+        );
+    }
+
+    #[test]
+    fn it_should_only_create_canonical_rfc_4122_urn_uuids() {
+        let valid = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79";
+        assert_eq!(
+            UrnUuid::new(valid.to_string()),
+            Ok(UrnUuid(valid.to_string()))
+        );
+        assert_eq!(UrnUuid::generate().validate(), Ok(ValidationResult::Passed));
+
+        for invalid in [
+            "fake-uuid",
+            "3e671687-395b-41f5-a30f-a58921a69b79",
+            "urn:uuid:3E671687-395B-41F5-A30F-A58921A69B79",
+            "urn:uuid:3e671687-395b-01f5-a30f-a58921a69b79",
+            "urn:uuid:3e671687-395b-41f5-c30f-a58921a69b79",
+            "urn:uuid:3e671687395b41f5a30fa58921a69b79",
+        ] {
+            assert!(UrnUuid::new(invalid.to_string()).is_err(), "{}", invalid);
+            assert!(
+                matches!(
+                    UrnUuid(invalid.to_string()).validate(),
+                    Ok(ValidationResult::Failed { .. })
+                ),
+                "{}",
+                invalid
+            );
+        }
+    }
+
     #[test]
     fn invalid_uuids_should_fail_validation() {
         let validation_result = UrnUuid("invalid uuid".to_string())
@@ -18,12 +18,16 @@
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::fmt;
 use std::str::FromStr;
+use xml::{EmitterConfig, EventWriter};
+
+use crate::errors::{JsonWriteError, XmlWriteError};
 
 use crate::models::attached_text::AttachedText;
 use crate::models::code::{Commits, Patches};
-use crate::models::external_reference::ExternalReferences;
-use crate::models::hash::Hashes;
+use crate::models::external_reference::{ExternalReferenceType, ExternalReferences};
+use crate::models::hash::{Hash, HashAlgorithm, Hashes};
 use crate::models::license::Licenses;
 use crate::models::organization::OrganizationalEntity;
 use crate::models::property::Properties;
@@ -36,6 +40,8 @@ use crate::{
     validation::{Validate, ValidationContext, ValidationError, ValidationResult},
 };
 
+use crate::xml::ToXml;
+
 use super::signature::Signature;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,6 +71,12 @@ pub struct Component {
     pub evidence: Option<ComponentEvidence>,
     /// Added in version 1.4
     pub signature: Option<Signature>,
+    /// Added in version 1.6
+    pub tags: Option<Vec<String>>,
+    /// Added in version 1.6
+    pub omnibor_ids: Vec<String>,
+    /// Added in version 1.6
+    pub swhids: Vec<String>,
 }
 
 impl Component {
@@ -99,8 +111,61 @@ impl Component {
             components: None,
             evidence: None,
             signature: None,
+            tags: None,
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
         }
     }
+
+    /// Output the component as a standalone `<component>` element conforming to
+    /// [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/xml/), without the
+    /// surrounding `<bom>` document
+    pub fn to_xml_string(&self) -> Result<String, XmlWriteError> {
+        let config = EmitterConfig::default()
+            .perform_indent(true)
+            .write_document_declaration(false);
+        let mut output = Vec::new();
+        let mut event_writer = EventWriter::new_with_config(&mut output, config);
+
+        let component: crate::specs::v1_4::component::Component = self.clone().into();
+        component.write_xml_element(&mut event_writer)?;
+
+        Ok(String::from_utf8_lossy(&output).to_string())
+    }
+
+    /// Output the component as a standalone JSON object conforming to
+    /// [version 1.4 of the specification](https://cyclonedx.org/docs/1.4/json/), without the
+    /// surrounding BOM document
+    pub fn to_json_value(&self) -> Result<serde_json::Value, JsonWriteError> {
+        let component: crate::specs::v1_4::component::Component = self.clone().into();
+        Ok(serde_json::to_value(component)?)
+    }
+
+    /// The URL of the first `website` external reference of the component
+    pub fn website(&self) -> Option<&str> {
+        self.external_reference_url(&ExternalReferenceType::Website)
+    }
+
+    /// The URL of the first `vcs` external reference of the component
+    pub fn repository(&self) -> Option<&str> {
+        self.external_reference_url(&ExternalReferenceType::Vcs)
+    }
+
+    /// The first hash of the component computed with the given algorithm
+    pub fn hash_for(&self, algorithm: HashAlgorithm) -> Option<&Hash> {
+        self.hashes
+            .iter()
+            .flat_map(|hashes| hashes.0.iter())
+            .find(|hash| hash.alg == algorithm)
+    }
+
+    fn external_reference_url(&self, reference_type: &ExternalReferenceType) -> Option<&str> {
+        self.external_references
+            .iter()
+            .flat_map(|references| references.0.iter())
+            .find(|reference| &reference.external_reference_type == reference_type)
+            .map(|reference| reference.url.0.as_str())
+    }
 }
 
 impl Validate for Component {
@@ -246,6 +311,57 @@ impl Validate for Component {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Components(pub Vec<Component>);
 
+impl Components {
+    /// Iterate over the components and their nested components, depth-first,
+    /// each component coming before its nested components
+    pub fn iter_recursive(&self) -> impl Iterator<Item = &Component> {
+        RecursiveComponents {
+            stack: vec![self.0.iter()],
+        }
+    }
+
+    /// Call `f` on the components and their nested components in the order of [Self::iter_recursive].
+    ///
+    /// There is no mutable iterator, as it would hand out a component along with
+    /// its nested components, which the component already borrows.
+    pub fn for_each_recursive_mut(&mut self, mut f: impl FnMut(&mut Component)) {
+        fn visit(components: &mut Components, f: &mut impl FnMut(&mut Component)) {
+            for component in &mut components.0 {
+                f(component);
+                if let Some(nested) = &mut component.components {
+                    visit(nested, f);
+                }
+            }
+        }
+        visit(self, &mut f);
+    }
+}
+
+struct RecursiveComponents<'a> {
+    stack: Vec<std::slice::Iter<'a, Component>>,
+}
+
+impl<'a> Iterator for RecursiveComponents<'a> {
+    type Item = &'a Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let iter = self.stack.last_mut()?;
+            match iter.next() {
+                Some(component) => {
+                    if let Some(nested) = &component.components {
+                        self.stack.push(nested.0.iter());
+                    }
+                    return Some(component);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
 impl Validate for Components {
     fn validate_with_context(
         &self,
@@ -264,6 +380,7 @@ impl Validate for Components {
     }
 }
 
+/// The type of a component
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Classification {
     Application,
@@ -274,13 +391,23 @@ pub enum Classification {
     Device,
     Firmware,
     File,
+    /// Added in version 1.5
+    MachineLearningModel,
+    /// Added in version 1.5
+    Data,
+    /// Added in version 1.6
+    Platform,
+    /// Added in version 1.6
+    DeviceDriver,
+    /// Added in version 1.6
+    CryptographicAsset,
     #[doc(hidden)]
     UnknownClassification(String),
 }
 
-impl ToString for Classification {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for Classification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             Classification::Application => "application",
             Classification::Framework => "framework",
             Classification::Library => "library",
@@ -289,25 +416,52 @@ impl ToString for Classification {
             Classification::Device => "device",
             Classification::Firmware => "firmware",
             Classification::File => "file",
+            Classification::MachineLearningModel => "machine-learning-model",
+            Classification::Data => "data",
+            Classification::Platform => "platform",
+            Classification::DeviceDriver => "device-driver",
+            Classification::CryptographicAsset => "cryptographic-asset",
             Classification::UnknownClassification(uc) => uc,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for Classification {
+    type Err = ClassificationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "application" => Ok(Self::Application),
+            "framework" => Ok(Self::Framework),
+            "library" => Ok(Self::Library),
+            "container" => Ok(Self::Container),
+            "operating-system" => Ok(Self::OperatingSystem),
+            "device" => Ok(Self::Device),
+            "firmware" => Ok(Self::Firmware),
+            "file" => Ok(Self::File),
+            "machine-learning-model" => Ok(Self::MachineLearningModel),
+            "data" => Ok(Self::Data),
+            "platform" => Ok(Self::Platform),
+            "device-driver" => Ok(Self::DeviceDriver),
+            "cryptographic-asset" => Ok(Self::CryptographicAsset),
+            _ => Err(ClassificationError::UnknownClassification(s.to_string())),
         }
-        .to_string()
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ClassificationError {
+    #[error("Unknown component type: {0}")]
+    UnknownClassification(String),
+}
+
 impl Classification {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "application" => Self::Application,
-            "framework" => Self::Framework,
-            "library" => Self::Library,
-            "container" => Self::Container,
-            "operating-system" => Self::OperatingSystem,
-            "device" => Self::Device,
-            "firmware" => Self::Firmware,
-            "file" => Self::File,
-            unknown => Self::UnknownClassification(unknown.to_string()),
-        }
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UnknownClassification(value.as_ref().to_string()))
     }
 }
 
@@ -337,26 +491,43 @@ pub enum Scope {
     UnknownScope(String),
 }
 
-impl ToString for Scope {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             Scope::Required => "required",
             Scope::Optional => "optional",
             Scope::Excluded => "excluded",
             Scope::UnknownScope(us) => us,
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for Scope {
+    type Err = ScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "required" => Ok(Self::Required),
+            "optional" => Ok(Self::Optional),
+            "excluded" => Ok(Self::Excluded),
+            _ => Err(ScopeError::UnknownScope(s.to_string())),
         }
-        .to_string()
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ScopeError {
+    #[error("Unknown scope: {0}")]
+    UnknownScope(String),
+}
+
 impl Scope {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "required" => Self::Required,
-            "optional" => Self::Optional,
-            "excluded" => Self::Excluded,
-            unknown => Self::UnknownScope(unknown.to_string()),
-        }
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UnknownScope(value.as_ref().to_string()))
     }
 }
 
@@ -437,16 +608,124 @@ impl Validate for Swid {
     }
 }
 
+/// A [Common Platform Enumeration](https://nvd.nist.gov/products/cpe) name, either in the
+/// CPE 2.3 formatted string binding or in the older URI binding
+/// ```
+/// use cyclonedx_bom::models::component::Cpe;
+///
+/// let cpe = Cpe::parse("cpe:2.3:a:serde-rs:serde:1.0.0:*:*:*:*:rust:*:*")?;
+/// assert_eq!(cpe.to_string(), "cpe:2.3:a:serde-rs:serde:1.0.0:*:*:*:*:rust:*:*");
+/// assert!(Cpe::parse("cpe:2.3:a:serde-rs:serde:1.0.0").is_err());
+/// # Ok::<(), cyclonedx_bom::models::component::CpeError>(())
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Cpe(pub(crate) String);
 
+/// The attributes of a CPE 2.3 formatted string, in the order they are bound
+const CPE_ATTRIBUTES: [&str; 11] = [
+    "part",
+    "vendor",
+    "product",
+    "version",
+    "update",
+    "edition",
+    "language",
+    "sw_edition",
+    "target_sw",
+    "target_hw",
+    "other",
+];
+
+impl Cpe {
+    /// Parses a CPE, checking the attribute count and escaping of the CPE 2.3 formatted
+    /// string binding, or the shape of the URI binding
+    pub fn parse(value: &str) -> Result<Self, CpeError> {
+        static URI_BINDING_REGEX: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"^[c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6}$")
+                .expect("Failed to compile regex.")
+        });
+
+        if let Some(bound) = value.strip_prefix("cpe:2.3:") {
+            let attributes = split_cpe_attributes(bound);
+            if attributes.len() != CPE_ATTRIBUTES.len() {
+                return Err(CpeError::AttributeCount(attributes.len()));
+            }
+            for (attribute, value) in CPE_ATTRIBUTES.iter().zip(attributes) {
+                if !is_valid_cpe_attribute(attribute, value) {
+                    return Err(CpeError::InvalidAttribute {
+                        attribute,
+                        value: value.to_string(),
+                    });
+                }
+            }
+        } else if value.get(..5).map(str::to_lowercase).as_deref() == Some("cpe:/") {
+            if !URI_BINDING_REGEX.is_match(value) {
+                return Err(CpeError::InvalidUriBinding(value.to_string()));
+            }
+        } else {
+            return Err(CpeError::UnknownBinding(value.to_string()));
+        }
+
+        Ok(Self(value.to_string()))
+    }
+
+    /// Wraps the value without checking it, e.g. to keep what a document contains
+    /// when reading it leniently. [Validate] reports it if it is invalid.
+    pub fn new_unchecked(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+}
+
+/// Splits a formatted string on the colons that are not escaped with a backslash
+fn split_cpe_attributes(bound: &str) -> Vec<&str> {
+    let mut attributes = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, c) in bound.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ':' => {
+                attributes.push(&bound[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    attributes.push(&bound[start..]);
+    attributes
+}
+
+fn is_valid_cpe_attribute(attribute: &str, value: &str) -> bool {
+    static LANGUAGE_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^[a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?$").expect("Failed to compile regex.")
+    });
+    static VALUE_REGEX: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(
+            r##"^(\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~\-]))+(\?*|\*?)$"##,
+        )
+        .expect("Failed to compile regex.")
+    });
+
+    match (attribute, value) {
+        (_, "*" | "-") => true,
+        ("part", value) => matches!(value, "a" | "h" | "o"),
+        ("language", value) => LANGUAGE_REGEX.is_match(value),
+        (_, value) => VALUE_REGEX.is_match(value),
+    }
+}
+
 impl FromStr for Cpe {
-    type Err = ValidationError;
+    type Err = CpeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let result = Cpe(s.to_string());
-        result.validate()?;
-        Ok(result)
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Cpe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -455,25 +734,36 @@ impl Validate for Cpe {
         &self,
         context: ValidationContext,
     ) -> Result<ValidationResult, ValidationError> {
-        static UUID_REGEX: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(
-                r##"([c][pP][eE]:/[AHOaho]?(:[A-Za-z0-9\._\-~%]*){0,6})|(cpe:2\.3:[aho\*\-](:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){5}(:(([a-zA-Z]{2,3}(-([a-zA-Z]{2}|[0-9]{3}))?)|[\*\-]))(:(((\?*|\*?)([a-zA-Z0-9\-\._]|(\\[\\\*\?!"#$$%&'\(\)\+,/:;<=>@\[\]\^`\{\|}~]))+(\?*|\*?))|[\*\-])){4})"##,
-            ).expect("Failed to compile regex.")
-        });
-
-        if UUID_REGEX.is_match(&self.0) {
-            Ok(ValidationResult::Passed)
-        } else {
-            Ok(ValidationResult::Failed {
+        match Self::parse(&self.0) {
+            Ok(_) => Ok(ValidationResult::Passed),
+            Err(e) => Ok(ValidationResult::Failed {
                 reasons: vec![FailureReason {
-                    message: "Cpe does not match regular expression".to_string(),
+                    message: format!("Cpe does not conform to CPE spec: {}", e),
                     context,
                 }],
-            })
+            }),
         }
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CpeError {
+    #[error(r#"Expected a "cpe:2.3:" or "cpe:/" prefix: {0}"#)]
+    UnknownBinding(String),
+
+    #[error("Expected 11 attributes in a CPE 2.3 formatted string, found {0}")]
+    AttributeCount(usize),
+
+    #[error(r#"Invalid {attribute} attribute: "{value}""#)]
+    InvalidAttribute {
+        attribute: &'static str,
+        value: String,
+    },
+
+    #[error("Invalid CPE URI binding: {0}")]
+    InvalidUriBinding(String),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ComponentEvidence {
     pub licenses: Option<Licenses>,
@@ -599,8 +889,8 @@ mod test {
         external_models::spdx::SpdxExpression,
         models::{
             code::{Commit, Patch, PatchClassification},
-            external_reference::{ExternalReference, ExternalReferenceType},
-            hash::{Hash, HashAlgorithm, HashValue},
+            external_reference::ExternalReference,
+            hash::HashValue,
             license::LicenseChoice,
             property::Property,
             signature::Algorithm,
@@ -611,6 +901,214 @@ mod test {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn it_should_round_trip_every_classification_through_its_string_form() {
+        for classification in [
+            Classification::Application,
+            Classification::Framework,
+            Classification::Library,
+            Classification::Container,
+            Classification::OperatingSystem,
+            Classification::Device,
+            Classification::Firmware,
+            Classification::File,
+            Classification::MachineLearningModel,
+            Classification::Data,
+            Classification::Platform,
+            Classification::DeviceDriver,
+            Classification::CryptographicAsset,
+        ] {
+            let string = classification.to_string();
+            assert_eq!(
+                Classification::from_str(&string),
+                Ok(classification.clone())
+            );
+            assert_eq!(Classification::new_unchecked(&string), classification);
+        }
+
+        assert_eq!(
+            Classification::from_str("component type"),
+            Err(ClassificationError::UnknownClassification(
+                "component type".to_string()
+            ))
+        );
+        let unknown = Classification::new_unchecked("component type");
+        assert_eq!(unknown.to_string(), "component type");
+        assert!(matches!(
+            unknown.validate(),
+            Ok(ValidationResult::Failed { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_round_trip_every_scope_through_its_string_form() {
+        for scope in [Scope::Required, Scope::Optional, Scope::Excluded] {
+            let string = scope.to_string();
+            assert_eq!(Scope::from_str(&string), Ok(scope.clone()));
+            assert_eq!(Scope::new_unchecked(&string), scope);
+        }
+
+        assert_eq!(
+            Scope::from_str("Required"),
+            Err(ScopeError::UnknownScope("Required".to_string()))
+        );
+        let unknown = Scope::new_unchecked("Required");
+        assert_eq!(unknown, Scope::UnknownScope("Required".to_string()));
+        assert_eq!(unknown.to_string(), "Required");
+        assert!(matches!(
+            unknown.validate(),
+            Ok(ValidationResult::Failed { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_parse_both_cpe_bindings() {
+        for valid in [
+            "cpe:2.3:a:serde-rs:serde:1.0.0:*:*:*:*:rust:*:*",
+            "cpe:2.3:o:microsoft:windows_10:-:*:*:en-us:*:*:x64:*",
+            r"cpe:2.3:a:example:my\:lib:1.0.0*:*:*:*:*:*:*:*",
+            "cpe:/a:example:mylibrary:1.0.0",
+            "cpe:/o:linux",
+        ] {
+            assert_eq!(
+                Cpe::parse(valid).map(|cpe| cpe.to_string()),
+                Ok(valid.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_point_at_the_problem_in_an_invalid_cpe() {
+        assert_eq!(
+            Cpe::parse("cpe:2.3:a:serde-rs:serde:1.0.0"),
+            Err(CpeError::AttributeCount(4))
+        );
+        assert_eq!(
+            Cpe::parse("cpe:2.3:x:serde-rs:serde:1.0.0:*:*:*:*:*:*:*"),
+            Err(CpeError::InvalidAttribute {
+                attribute: "part",
+                value: "x".to_string()
+            })
+        );
+        assert_eq!(
+            Cpe::parse("cpe:2.3:a:serde rs:serde:1.0.0:*:*:*:*:*:*:*"),
+            Err(CpeError::InvalidAttribute {
+                attribute: "vendor",
+                value: "serde rs".to_string()
+            })
+        );
+        assert_eq!(
+            Cpe::parse("cpe:2.3:a:serde-rs:serde:1.0.0:*:*:english:*:*:*:*"),
+            Err(CpeError::InvalidAttribute {
+                attribute: "language",
+                value: "english".to_string()
+            })
+        );
+        assert_eq!(
+            Cpe::parse("cpe:/a:example:my library"),
+            Err(CpeError::InvalidUriBinding(
+                "cpe:/a:example:my library".to_string()
+            ))
+        );
+        assert_eq!(
+            Cpe::from_str("serde cpe:/a:serde"),
+            Err(CpeError::UnknownBinding("serde cpe:/a:serde".to_string()))
+        );
+
+        let unchecked = Cpe::new_unchecked("serde cpe:/a:serde");
+        assert_eq!(unchecked.to_string(), "serde cpe:/a:serde");
+        assert!(unchecked.validate().unwrap().into_result().is_err());
+    }
+
+    #[test]
+    fn it_should_walk_nested_components_depth_first() {
+        let component = |name: &str, nested: Vec<Component>| {
+            let mut component = Component::new(Classification::Library, name, "1.0.0", None);
+            if !nested.is_empty() {
+                component.components = Some(Components(nested));
+            }
+            component
+        };
+        let mut components = Components(vec![
+            component(
+                "a",
+                vec![
+                    component("a1", vec![component("a1x", vec![])]),
+                    component("a2", vec![]),
+                ],
+            ),
+            component("b", vec![component("b1", vec![])]),
+        ]);
+
+        let names: Vec<String> = components
+            .iter_recursive()
+            .map(|c| c.name.to_string())
+            .collect();
+        assert_eq!(names, vec!["a", "a1", "a1x", "a2", "b", "b1"]);
+
+        components.for_each_recursive_mut(|c| c.version = Some(NormalizedString::new("2.0.0")));
+        assert!(components
+            .iter_recursive()
+            .all(|c| c.version == Some(NormalizedString::new("2.0.0"))));
+        assert_eq!(components.iter_recursive().count(), 6);
+    }
+
+    #[test]
+    fn it_should_look_up_the_hash_for_an_algorithm() {
+        let mut component = Component::new(Classification::Library, "serde", "1.0.0", None);
+        assert_eq!(component.hash_for(HashAlgorithm::SHA256), None);
+
+        let sha256 = Hash {
+            alg: HashAlgorithm::SHA256,
+            content: HashValue(
+                "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_string(),
+            ),
+        };
+        component.hashes = Some(Hashes(vec![
+            Hash {
+                alg: HashAlgorithm::MD5,
+                content: HashValue("a3bf1f3d584747e2569483783ddee45b".to_string()),
+            },
+            sha256.clone(),
+        ]));
+        assert_eq!(component.hash_for(HashAlgorithm::SHA256), Some(&sha256));
+        assert_eq!(component.hash_for(HashAlgorithm::SHA512), None);
+    }
+
+    #[test]
+    fn it_should_look_up_the_website_and_repository() {
+        let mut component = Component::new(Classification::Library, "serde", "1.0.0", None);
+        assert_eq!(component.website(), None);
+        assert_eq!(component.repository(), None);
+
+        component.external_references = Some(ExternalReferences(vec![
+            ExternalReference::new(
+                ExternalReferenceType::Documentation,
+                Uri("https://docs.rs/serde".to_string()),
+            ),
+            ExternalReference::new(
+                ExternalReferenceType::Vcs,
+                Uri("https://github.com/serde-rs/serde".to_string()),
+            ),
+        ]));
+        assert_eq!(component.website(), None);
+        assert_eq!(
+            component.repository(),
+            Some("https://github.com/serde-rs/serde")
+        );
+
+        component
+            .external_references
+            .as_mut()
+            .unwrap()
+            .0
+            .push(ExternalReference::new(
+                ExternalReferenceType::Website,
+                Uri("https://serde.rs".to_string()),
+            ));
+        assert_eq!(component.website(), Some("https://serde.rs"));
+    }
+
     #[test]
     fn valid_components_should_pass_validation() {
         let validation_result = Components(vec![Component {
@@ -692,6 +1190,9 @@ mod test {
                 algorithm: Algorithm::HS512,
                 value: "abcdefgh".to_string(),
             }),
+            tags: None,
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -784,6 +1285,9 @@ mod test {
                 algorithm: Algorithm::HS512,
                 value: "abcdefgh".to_string(),
             }),
+            tags: None,
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
         }])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -952,7 +1456,9 @@ mod test {
                         ])
                     },
                     FailureReason {
-                        message: "Cpe does not match regular expression".to_string(),
+                        message:
+                            r#"Cpe does not conform to CPE spec: Expected a "cpe:2.3:" or "cpe:/" prefix: invalid cpe"#
+                                .to_string(),
                         context: ValidationContext(vec![
                             ValidationPathComponent::Array { index: 0 },
                             ValidationPathComponent::Struct {
@@ -1173,6 +1679,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn it_should_serialize_a_standalone_component() {
+        let mut component = Component::new(
+            Classification::Library,
+            "lib-x",
+            "v0.1.0",
+            Some("lib-x".to_string()),
+        );
+        component.purl = Some(Purl("pkg:cargo/lib-x@0.1.0".to_string()));
+
+        let xml = component.to_xml_string().expect("Failed to write XML");
+        assert!(xml.starts_with("<component "));
+        let parsed: crate::specs::v1_4::component::Component =
+            crate::xml::test::read_element_from_string(&xml);
+        assert_eq!(Component::from(parsed), component);
+
+        let json = component.to_json_value().expect("Failed to write JSON");
+        assert_eq!(json["name"], "lib-x");
+        let parsed: crate::specs::v1_4::component::Component =
+            serde_json::from_value(json).expect("Failed to read JSON");
+        assert_eq!(Component::from(parsed), component);
+    }
+
     fn invalid_component() -> Component {
         Component {
             component_type: Classification::UnknownClassification("unknown".to_string()),
@@ -1199,6 +1728,9 @@ mod test {
             components: None,
             evidence: None,
             signature: None,
+            tags: None,
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
         }
     }
 }
@@ -16,6 +16,8 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Dependencies(pub Vec<Dependency>);
 
@@ -24,3 +26,182 @@ pub struct Dependency {
     pub dependency_ref: String,
     pub dependencies: Vec<String>,
 }
+
+impl Dependencies {
+    /// The bom-refs the given bom-ref directly depends on
+    pub fn direct_dependencies_of(&self, bom_ref: &str) -> &[String] {
+        self.0
+            .iter()
+            .find(|dependency| dependency.dependency_ref == bom_ref)
+            .map(|dependency| dependency.dependencies.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The bom-refs that directly depend on the given bom-ref
+    pub fn dependents_of(&self, bom_ref: &str) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|dependency| dependency.dependencies.iter().any(|d| d == bom_ref))
+            .map(|dependency| dependency.dependency_ref.as_str())
+            .collect()
+    }
+
+    /// The bom-refs reachable from the given bom-ref by following the dependencies, directly
+    /// or indirectly. See [Self::direct_dependencies_of] for the direct ones only.
+    ///
+    /// The bom-ref itself is only included if it depends on itself through a cycle.
+    /// An unknown bom-ref, or one without dependencies, yields an empty set.
+    pub fn transitive_closure(&self, root: &str) -> BTreeSet<String> {
+        let mut reachable = BTreeSet::new();
+        let mut queue = vec![root];
+        while let Some(bom_ref) = queue.pop() {
+            for dependency in self.direct_dependencies_of(bom_ref) {
+                if reachable.insert(dependency.clone()) {
+                    queue.push(dependency);
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Cycles in the dependency graph, each as the bom-refs along it in order
+    ///
+    /// The graph is searched depth-first, starting from the entries in document order, and every
+    /// edge leading back to a bom-ref on the current path closes one cycle. A bom-ref depending
+    /// on itself is a cycle of its own. Every cyclic part of the graph is reported this way,
+    /// though not every possible cycle through it.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        let mut search = CycleSearch {
+            edges: HashMap::new(),
+            visited: HashSet::new(),
+            path: Vec::new(),
+            on_path: HashSet::new(),
+            cycles: Vec::new(),
+        };
+        for dependency in &self.0 {
+            search
+                .edges
+                .entry(dependency.dependency_ref.as_str())
+                .or_insert(dependency.dependencies.as_slice());
+        }
+        for dependency in &self.0 {
+            search.visit(&dependency.dependency_ref);
+        }
+        search.cycles
+    }
+}
+
+struct CycleSearch<'a> {
+    edges: HashMap<&'a str, &'a [String]>,
+    visited: HashSet<&'a str>,
+    path: Vec<&'a str>,
+    on_path: HashSet<&'a str>,
+    cycles: Vec<Vec<String>>,
+}
+
+impl<'a> CycleSearch<'a> {
+    fn visit(&mut self, bom_ref: &'a str) {
+        if !self.visited.insert(bom_ref) {
+            return;
+        }
+        self.path.push(bom_ref);
+        self.on_path.insert(bom_ref);
+
+        let dependencies = self.edges.get(bom_ref).copied().unwrap_or_default();
+        for dependency in dependencies {
+            if self.on_path.contains(dependency.as_str()) {
+                let start = self
+                    .path
+                    .iter()
+                    .position(|r| r == dependency)
+                    .expect("bom-ref on the path");
+                self.cycles
+                    .push(self.path[start..].iter().map(|r| r.to_string()).collect());
+            } else {
+                self.visit(dependency);
+            }
+        }
+
+        self.path.pop();
+        self.on_path.remove(bom_ref);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dependency(dependency_ref: &str, dependencies: &[&str]) -> Dependency {
+        Dependency {
+            dependency_ref: dependency_ref.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn it_should_find_dependencies_in_both_directions() {
+        let dependencies = Dependencies(vec![
+            dependency("app", &["lib-a", "lib-b"]),
+            dependency("lib-a", &["lib-b"]),
+            dependency("lib-b", &[]),
+        ]);
+
+        assert_eq!(
+            dependencies.direct_dependencies_of("app"),
+            &["lib-a".to_string(), "lib-b".to_string()]
+        );
+        assert!(dependencies.direct_dependencies_of("lib-b").is_empty());
+        assert!(dependencies.direct_dependencies_of("unknown").is_empty());
+
+        assert_eq!(dependencies.dependents_of("lib-b"), vec!["app", "lib-a"]);
+        assert!(dependencies.dependents_of("app").is_empty());
+    }
+
+    #[test]
+    fn it_should_find_all_transitive_dependencies() {
+        let dependencies = Dependencies(vec![
+            dependency("app", &["lib-a"]),
+            dependency("lib-a", &["lib-b", "lib-c"]),
+            dependency("lib-b", &["lib-a"]),
+            dependency("lib-c", &[]),
+        ]);
+
+        let refs = |refs: &[&str]| refs.iter().map(|r| r.to_string()).collect::<BTreeSet<_>>();
+        assert_eq!(
+            dependencies.transitive_closure("app"),
+            refs(&["lib-a", "lib-b", "lib-c"])
+        );
+        assert_eq!(
+            dependencies.transitive_closure("lib-b"),
+            refs(&["lib-a", "lib-b", "lib-c"])
+        );
+        assert!(dependencies.transitive_closure("lib-c").is_empty());
+        assert!(dependencies.transitive_closure("unknown").is_empty());
+    }
+
+    #[test]
+    fn it_should_find_cycles() {
+        let dependencies = Dependencies(vec![
+            dependency("app", &["lib-a", "lib-c"]),
+            dependency("lib-a", &["lib-b"]),
+            dependency("lib-b", &["lib-a", "lib-c"]),
+            dependency("lib-c", &["lib-c", "lib-b"]),
+            dependency("lib-d", &[]),
+        ]);
+
+        assert_eq!(
+            dependencies.find_cycles(),
+            vec![
+                vec!["lib-a".to_string(), "lib-b".to_string()],
+                vec!["lib-c".to_string()],
+                vec!["lib-b".to_string(), "lib-c".to_string()],
+            ]
+        );
+
+        let acyclic = Dependencies(vec![
+            dependency("app", &["lib-a", "lib-b"]),
+            dependency("lib-a", &["lib-b"]),
+        ]);
+        assert!(acyclic.find_cycles().is_empty());
+    }
+}
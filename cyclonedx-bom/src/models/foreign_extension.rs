@@ -0,0 +1,34 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+/// An element from a namespace other than the CycloneDX one, such as a vendor extension,
+/// which is kept as written so that it can be written back.
+///
+/// Only elements that are direct children of `bom` are kept, and only when parsing with
+/// [ParseOptions::preserve_foreign](crate::models::bom::ParseOptions::preserve_foreign).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignExtension {
+    /// The qualified name of the element, e.g. `example:laxValidation`
+    pub name: String,
+    /// The namespace the element belongs to, e.g. `https://example.com`
+    pub namespace: Option<String>,
+    /// The attributes of the element by qualified name
+    pub attributes: Vec<(String, String)>,
+    /// The content of the element as XML
+    pub inner_xml: String,
+}
@@ -16,8 +16,12 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::fmt;
+use std::str::FromStr;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
+use thiserror::Error;
 
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
@@ -46,7 +50,14 @@ impl Validate for Hash {
 
         let content_context = context.extend_context_with_struct_field("Hash", "content");
 
-        results.push(self.content.validate_with_context(content_context)?);
+        let content_result = self
+            .content
+            .validate_with_context(content_context.clone())?;
+        if content_result == ValidationResult::Passed {
+            results.push(self.validate_digest(content_context));
+        } else {
+            results.push(content_result);
+        }
 
         Ok(results
             .into_iter()
@@ -54,6 +65,35 @@ impl Validate for Hash {
     }
 }
 
+impl Hash {
+    /// Checks that the content is a lowercase hex digest of the length produced by the algorithm.
+    /// The schema accepts any hash value of one of the digest lengths, so this catches digests
+    /// that cannot have been produced by the declared algorithm.
+    fn validate_digest(&self, context: ValidationContext) -> ValidationResult {
+        let content = &self.content.0;
+        let message = if !content
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        {
+            format!("{} hash is not lowercase hex", self.alg)
+        } else {
+            match self.alg.digest_length() {
+                Some(expected) if expected != content.len() => format!(
+                    "{} hash has {} hex digits instead of {}",
+                    self.alg,
+                    content.len(),
+                    expected
+                ),
+                _ => return ValidationResult::Passed,
+            }
+        };
+
+        ValidationResult::Failed {
+            reasons: vec![FailureReason { message, context }],
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Hashes(pub Vec<Hash>);
 
@@ -98,9 +138,10 @@ pub enum HashAlgorithm {
     UnknownHashAlgorithm(String),
 }
 
-impl ToString for HashAlgorithm {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for HashAlgorithm {
+    /// Formats the algorithm with the spelling of the schema
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let algorithm = match self {
             HashAlgorithm::MD5 => "MD5",
             HashAlgorithm::SHA1 => "SHA-1",
             HashAlgorithm::SHA256 => "SHA-256",
@@ -114,27 +155,66 @@ impl ToString for HashAlgorithm {
             HashAlgorithm::BLAKE2b_512 => "BLAKE2b-512",
             HashAlgorithm::BLAKE3 => "BLAKE3",
             HashAlgorithm::UnknownHashAlgorithm(un) => un,
+        };
+        f.write_str(algorithm)
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = HashAlgorithmError;
+
+    /// Parses one of the algorithms of the schema, ignoring the case of its spelling
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "MD5" => Ok(Self::MD5),
+            "SHA-1" => Ok(Self::SHA1),
+            "SHA-256" => Ok(Self::SHA256),
+            "SHA-384" => Ok(Self::SHA384),
+            "SHA-512" => Ok(Self::SHA512),
+            "SHA3-256" => Ok(Self::SHA3_256),
+            "SHA3-384" => Ok(Self::SHA3_384),
+            "SHA3-512" => Ok(Self::SHA3_512),
+            "BLAKE2B-256" => Ok(Self::BLAKE2b_256),
+            "BLAKE2B-384" => Ok(Self::BLAKE2b_384),
+            "BLAKE2B-512" => Ok(Self::BLAKE2b_512),
+            "BLAKE3" => Ok(Self::BLAKE3),
+            _ => Err(HashAlgorithmError::UnknownHashAlgorithm(s.to_string())),
         }
-        .to_string()
     }
 }
 
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HashAlgorithmError {
+    #[error("Unknown hash algorithm: {0}")]
+    UnknownHashAlgorithm(String),
+}
+
 impl HashAlgorithm {
+    /// Parses the algorithm like [FromStr], keeping an unknown algorithm as it is
+    /// so that documents can be read leniently and the algorithm reported by validation
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "MD5" => Self::MD5,
-            "SHA-1" => Self::SHA1,
-            "SHA-256" => Self::SHA256,
-            "SHA-384" => Self::SHA384,
-            "SHA-512" => Self::SHA512,
-            "SHA3-256" => Self::SHA3_256,
-            "SHA3-384" => Self::SHA3_384,
-            "SHA3-512" => Self::SHA3_512,
-            "BLAKE2b-256" => Self::BLAKE2b_256,
-            "BLAKE2b-384" => Self::BLAKE2b_384,
-            "BLAKE2b-512" => Self::BLAKE2b_512,
-            "BLAKE3" => Self::BLAKE3,
-            unknown => Self::UnknownHashAlgorithm(unknown.to_string()),
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UnknownHashAlgorithm(value.as_ref().to_string()))
+    }
+
+    /// The number of hex digits of a digest produced by the algorithm,
+    /// `None` if the length is not fixed, as for BLAKE3, or the algorithm is unknown
+    pub fn digest_length(&self) -> Option<usize> {
+        match self {
+            HashAlgorithm::MD5 => Some(32),
+            HashAlgorithm::SHA1 => Some(40),
+            HashAlgorithm::SHA256 | HashAlgorithm::SHA3_256 | HashAlgorithm::BLAKE2b_256 => {
+                Some(64)
+            }
+            HashAlgorithm::SHA384 | HashAlgorithm::SHA3_384 | HashAlgorithm::BLAKE2b_384 => {
+                Some(96)
+            }
+            HashAlgorithm::SHA512 | HashAlgorithm::SHA3_512 | HashAlgorithm::BLAKE2b_512 => {
+                Some(128)
+            }
+            HashAlgorithm::BLAKE3 | HashAlgorithm::UnknownHashAlgorithm(_) => None,
         }
     }
 }
@@ -238,4 +318,60 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_parse_and_display_hash_algorithms() {
+        assert_eq!("SHA3-512".parse(), Ok(HashAlgorithm::SHA3_512));
+        assert_eq!("blake2b-256".parse(), Ok(HashAlgorithm::BLAKE2b_256));
+        assert_eq!(HashAlgorithm::BLAKE2b_256.to_string(), "BLAKE2b-256");
+        assert_eq!(
+            "SHA-999".parse::<HashAlgorithm>(),
+            Err(HashAlgorithmError::UnknownHashAlgorithm(
+                "SHA-999".to_string()
+            ))
+        );
+        assert_eq!(
+            HashAlgorithm::new_unchecked("SHA-999"),
+            HashAlgorithm::UnknownHashAlgorithm("SHA-999".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_check_the_hash_content_against_the_algorithm() {
+        let validate = |alg, content: &str| {
+            Hash {
+                alg,
+                content: HashValue(content.to_string()),
+            }
+            .validate_with_context(ValidationContext::default())
+            .expect("Failed to validate hash")
+        };
+        let failed = |message: &str| ValidationResult::Failed {
+            reasons: vec![FailureReason {
+                message: message.to_string(),
+                context: ValidationContext(vec![ValidationPathComponent::Struct {
+                    struct_name: "Hash".to_string(),
+                    field_name: "content".to_string(),
+                }]),
+            }],
+        };
+        let sha256 = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+        assert_eq!(
+            validate(HashAlgorithm::SHA256, sha256),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            validate(HashAlgorithm::BLAKE3, sha256),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            validate(HashAlgorithm::SHA256, &sha256[..40]),
+            failed("SHA-256 hash has 40 hex digits instead of 64")
+        );
+        assert_eq!(
+            validate(HashAlgorithm::SHA256, &sha256.to_uppercase()),
+            failed("SHA-256 hash is not lowercase hex")
+        );
+    }
 }
@@ -26,7 +26,8 @@ use crate::external_models::{
 };
 use crate::models::attached_text::AttachedText;
 use crate::validation::{
-    Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
 };
 
 /// Represents whether a license is a named license or an SPDX license expression
@@ -86,6 +87,8 @@ pub struct License {
     pub license_identifier: LicenseIdentifier,
     pub text: Option<AttachedText>,
     pub url: Option<Uri>,
+    /// Added in version 1.6
+    pub acknowledgement: Option<LicenseAcknowledgement>,
 }
 
 impl License {
@@ -100,6 +103,7 @@ impl License {
             license_identifier: LicenseIdentifier::Name(NormalizedString::new(license)),
             text: None,
             url: None,
+            acknowledgement: None,
         }
     }
 
@@ -116,6 +120,7 @@ impl License {
             )?),
             text: None,
             url: None,
+            acknowledgement: None,
         })
     }
 }
@@ -147,15 +152,87 @@ impl Validate for License {
             results.push(url.validate_with_context(context)?);
         }
 
+        if let Some(acknowledgement) = &self.acknowledgement {
+            let context = context.extend_context_with_struct_field("License", "acknowledgement");
+
+            results.push(acknowledgement.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
     }
 }
 
+/// Whether a license was declared by the authors of a component or concluded by analysing it
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.6/xml/#type_licenseAcknowledgementEnumerationType)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LicenseAcknowledgement {
+    Declared,
+    Concluded,
+    #[doc(hidden)]
+    UnknownLicenseAcknowledgement(String),
+}
+
+impl ToString for LicenseAcknowledgement {
+    fn to_string(&self) -> String {
+        match self {
+            LicenseAcknowledgement::Declared => "declared",
+            LicenseAcknowledgement::Concluded => "concluded",
+            LicenseAcknowledgement::UnknownLicenseAcknowledgement(ua) => ua,
+        }
+        .to_string()
+    }
+}
+
+impl LicenseAcknowledgement {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        match value.as_ref() {
+            "declared" => Self::Declared,
+            "concluded" => Self::Concluded,
+            unknown => Self::UnknownLicenseAcknowledgement(unknown.to_string()),
+        }
+    }
+}
+
+impl Validate for LicenseAcknowledgement {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            LicenseAcknowledgement::UnknownLicenseAcknowledgement(_) => {
+                Ok(ValidationResult::Failed {
+                    reasons: vec![FailureReason {
+                        message: "Unknown license acknowledgement".to_string(),
+                        context,
+                    }],
+                })
+            }
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Licenses(pub Vec<LicenseChoice>);
 
+impl Licenses {
+    /// Whether any of the licenses is the given SPDX license, either as the identifier of a
+    /// license or as part of an expression
+    pub fn contains_spdx(&self, id: &SpdxIdentifier) -> bool {
+        self.0.iter().any(|license| match license {
+            LicenseChoice::License(License {
+                license_identifier: LicenseIdentifier::SpdxId(license_id),
+                ..
+            }) => license_id.0.eq_ignore_ascii_case(&id.0),
+            LicenseChoice::License(_) => false,
+            LicenseChoice::Expression(expression) => expression.contains_identifier(id),
+        })
+    }
+}
+
 impl Validate for Licenses {
     fn validate_with_context(
         &self,
@@ -233,6 +310,7 @@ mod test {
             )),
             text: None,
             url: None,
+            acknowledgement: None,
         })])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -267,6 +345,7 @@ mod test {
             license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier("Apache=2.0".to_string())),
             text: None,
             url: None,
+            acknowledgement: None,
         })])
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -325,6 +404,7 @@ mod test {
                 license_identifier: LicenseIdentifier::Name(NormalizedString("MIT".to_string())),
                 text: None,
                 url: None,
+                acknowledgement: None,
             }),
             LicenseChoice::License(License {
                 license_identifier: LicenseIdentifier::Name(NormalizedString(
@@ -332,6 +412,7 @@ mod test {
                 )),
                 text: None,
                 url: None,
+                acknowledgement: None,
             }),
             LicenseChoice::License(License {
                 license_identifier: LicenseIdentifier::SpdxId(SpdxIdentifier(
@@ -339,6 +420,7 @@ mod test {
                 )),
                 text: None,
                 url: None,
+                acknowledgement: None,
             }),
         ])
         .validate_with_context(ValidationContext::default())
@@ -423,4 +505,25 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_find_spdx_licenses_in_licenses_and_expressions() {
+        let gpl = SpdxIdentifier("GPL-3.0-only".to_string());
+
+        let expression = Licenses(vec![LicenseChoice::Expression(SpdxExpression(
+            "GPL-3.0-only OR MIT".to_string(),
+        ))]);
+        assert!(expression.contains_spdx(&gpl));
+
+        let license = Licenses(vec![LicenseChoice::License(
+            License::license_id("GPL-3.0-only").expect("Invalid license"),
+        )]);
+        assert!(license.contains_spdx(&gpl));
+
+        let others = Licenses(vec![
+            LicenseChoice::License(License::named_license("GPL-3.0-only")),
+            LicenseChoice::Expression(SpdxExpression("GPL-3.0-or-later AND MIT".to_string())),
+        ]);
+        assert!(!others.contains_spdx(&gpl));
+    }
 }
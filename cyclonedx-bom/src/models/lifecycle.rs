@@ -0,0 +1,236 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::external_models::normalized_string::NormalizedString;
+use crate::validation::{
+    FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+    ValidationResult,
+};
+
+/// The stages of the product lifecycle that a BOM describes
+///
+/// Defined via the [CycloneDX XML schema](https://cyclonedx.org/docs/1.5/xml/#type_metadata)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lifecycles(pub Vec<Lifecycle>);
+
+impl Validate for Lifecycles {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        for (index, lifecycle) in self.0.iter().enumerate() {
+            let lifecycle_context =
+                context.extend_context(vec![ValidationPathComponent::Array { index }]);
+            results.push(lifecycle.validate_with_context(lifecycle_context)?);
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+/// A stage of the product lifecycle, either one of the pre-defined phases or a custom one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lifecycle {
+    Phase(Phase),
+    Description {
+        name: NormalizedString,
+        description: Option<NormalizedString>,
+    },
+}
+
+impl Validate for Lifecycle {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Lifecycle::Phase(phase) => {
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Phase".to_string(),
+                }]);
+                phase.validate_with_context(context)
+            }
+            Lifecycle::Description { name, description } => {
+                let mut results: Vec<ValidationResult> = vec![];
+                let context = context.extend_context(vec![ValidationPathComponent::EnumVariant {
+                    variant_name: "Description".to_string(),
+                }]);
+
+                let name_context = context.extend_context_with_struct_field("Description", "name");
+                results.push(name.validate_with_context(name_context)?);
+
+                if let Some(description) = description {
+                    let description_context =
+                        context.extend_context_with_struct_field("Description", "description");
+                    results.push(description.validate_with_context(description_context)?);
+                }
+
+                Ok(results
+                    .into_iter()
+                    .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+            }
+        }
+    }
+}
+
+/// A pre-defined phase of the product lifecycle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Design,
+    PreBuild,
+    Build,
+    PostBuild,
+    Operations,
+    Discovery,
+    Decommission,
+    #[doc(hidden)]
+    UnknownPhase(String),
+}
+
+impl ToString for Phase {
+    fn to_string(&self) -> String {
+        match self {
+            Phase::Design => "design",
+            Phase::PreBuild => "pre-build",
+            Phase::Build => "build",
+            Phase::PostBuild => "post-build",
+            Phase::Operations => "operations",
+            Phase::Discovery => "discovery",
+            Phase::Decommission => "decommission",
+            Phase::UnknownPhase(phase) => phase,
+        }
+        .to_string()
+    }
+}
+
+impl FromStr for Phase {
+    type Err = PhaseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "design" => Ok(Self::Design),
+            "pre-build" => Ok(Self::PreBuild),
+            "build" => Ok(Self::Build),
+            "post-build" => Ok(Self::PostBuild),
+            "operations" => Ok(Self::Operations),
+            "discovery" => Ok(Self::Discovery),
+            "decommission" => Ok(Self::Decommission),
+            _ => Err(PhaseError::UnknownPhase(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PhaseError {
+    #[error("Unknown lifecycle phase: {0}")]
+    UnknownPhase(String),
+}
+
+impl Phase {
+    pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UnknownPhase(value.as_ref().to_string()))
+    }
+}
+
+impl Validate for Phase {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        match self {
+            Phase::UnknownPhase(_) => Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Unknown lifecycle phase".to_string(),
+                    context,
+                }],
+            }),
+            _ => Ok(ValidationResult::Passed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pass_validation() {
+        let validation_result = Lifecycles(vec![
+            Lifecycle::Phase(Phase::Build),
+            Lifecycle::Description {
+                name: NormalizedString::new("platform-integration-testing"),
+                description: Some(NormalizedString::new(
+                    "Integration testing specific to the runtime platform",
+                )),
+            },
+        ])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn it_should_fail_validation() {
+        let validation_result = Lifecycles(vec![Lifecycle::Phase(Phase::UnknownPhase(
+            "unknown phase".to_string(),
+        ))])
+        .validate()
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Unknown lifecycle phase".to_string(),
+                    context: ValidationContext(vec![
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::EnumVariant {
+                            variant_name: "Phase".to_string()
+                        }
+                    ])
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_parse_phases() {
+        assert_eq!("pre-build".parse(), Ok(Phase::PreBuild));
+        assert_eq!(
+            "testing".parse::<Phase>(),
+            Err(PhaseError::UnknownPhase("testing".to_string()))
+        );
+        assert_eq!(
+            Phase::new_unchecked("testing"),
+            Phase::UnknownPhase("testing".to_string())
+        );
+    }
+}
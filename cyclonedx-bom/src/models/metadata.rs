@@ -21,6 +21,7 @@ use thiserror::Error;
 use crate::external_models::date_time::{DateTime, DateTimeError};
 use crate::models::component::Component;
 use crate::models::license::Licenses;
+use crate::models::lifecycle::Lifecycles;
 use crate::models::organization::{OrganizationalContact, OrganizationalEntity};
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
@@ -41,6 +42,8 @@ pub struct Metadata {
     pub supplier: Option<OrganizationalEntity>,
     pub licenses: Option<Licenses>,
     pub properties: Option<Properties>,
+    /// Added in version 1.5
+    pub lifecycles: Option<Lifecycles>,
 }
 
 impl Metadata {
@@ -127,6 +130,12 @@ impl Validate for Metadata {
             results.push(properties.validate_with_context(context)?);
         }
 
+        if let Some(lifecycles) = &self.lifecycles {
+            let context = context.extend_context_with_struct_field("Metadata", "lifecycles");
+
+            results.push(lifecycles.validate_with_context(context)?);
+        }
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -144,7 +153,11 @@ mod test {
     use crate::{
         external_models::{normalized_string::NormalizedString, spdx::SpdxExpression},
         models::{
-            component::Classification, license::LicenseChoice, property::Property, tool::Tool,
+            component::Classification,
+            license::LicenseChoice,
+            lifecycle::{Lifecycle, Phase},
+            property::Property,
+            tool::Tool,
         },
         validation::FailureReason,
     };
@@ -192,6 +205,9 @@ mod test {
                 components: None,
                 evidence: None,
                 signature: None,
+                tags: None,
+                omnibor_ids: Vec::new(),
+                swhids: Vec::new(),
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString::new("name")),
@@ -210,6 +226,7 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString::new("value"),
             }])),
+            lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::Build)])),
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -257,6 +274,9 @@ mod test {
                 components: None,
                 evidence: None,
                 signature: None,
+                tags: None,
+                omnibor_ids: Vec::new(),
+                swhids: Vec::new(),
             }),
             manufacture: Some(OrganizationalEntity {
                 name: Some(NormalizedString("invalid\tname".to_string())),
@@ -275,6 +295,9 @@ mod test {
                 name: "name".to_string(),
                 value: NormalizedString("invalid\tvalue".to_string()),
             }])),
+            lifecycles: Some(Lifecycles(vec![Lifecycle::Phase(Phase::UnknownPhase(
+                "unknown".to_string(),
+            ))])),
         }
         .validate_with_context(ValidationContext::default())
         .expect("Error while validating");
@@ -394,6 +417,19 @@ mod test {
                             }
                         ])
                     },
+                    FailureReason {
+                        message: "Unknown lifecycle phase".to_string(),
+                        context: ValidationContext(vec![
+                            ValidationPathComponent::Struct {
+                                struct_name: "Metadata".to_string(),
+                                field_name: "lifecycles".to_string()
+                            },
+                            ValidationPathComponent::Array { index: 0 },
+                            ValidationPathComponent::EnumVariant {
+                                variant_name: "Phase".to_string()
+                            }
+                        ])
+                    },
                 ]
             }
         );
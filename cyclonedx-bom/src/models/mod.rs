@@ -24,8 +24,10 @@ pub mod component;
 pub mod composition;
 pub mod dependency;
 pub mod external_reference;
+pub mod foreign_extension;
 pub mod hash;
 pub mod license;
+pub mod lifecycle;
 pub mod metadata;
 pub mod organization;
 pub mod property;
@@ -35,6 +37,7 @@ pub mod tool;
 pub mod vulnerability;
 pub mod vulnerability_analysis;
 pub mod vulnerability_credits;
+pub mod vulnerability_proof_of_concept;
 pub mod vulnerability_rating;
 pub mod vulnerability_reference;
 pub mod vulnerability_source;
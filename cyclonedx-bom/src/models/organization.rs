@@ -19,7 +19,8 @@
 use crate::{
     external_models::{normalized_string::NormalizedString, uri::Uri},
     validation::{
-        Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
+        FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
+        ValidationResult,
     },
 };
 
@@ -47,6 +48,47 @@ impl OrganizationalContact {
             phone: None,
         }
     }
+
+    /// Whether the contact has an email address of the form `local@domain` with a dot in the
+    /// domain. This is only a syntactic check, no lookup of the domain is made.
+    /// ```
+    /// use cyclonedx_bom::models::organization::OrganizationalContact;
+    ///
+    /// assert!(OrganizationalContact::new("Support", Some("support@example.com")).has_valid_email());
+    /// assert!(!OrganizationalContact::new("Support", Some("support.example.com")).has_valid_email());
+    /// ```
+    pub fn has_valid_email(&self) -> bool {
+        self.email.as_deref().is_some_and(is_valid_email)
+    }
+
+    /// Check the email address with [Self::has_valid_email], if there is one. This is
+    /// not part of [Validate] because the schema accepts addresses such as `support@partner`.
+    pub fn validate_email(&self, context: &ValidationContext) -> ValidationResult {
+        match &self.email {
+            Some(email) if !is_valid_email(email) => ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Email address is not valid".to_string(),
+                    context: context
+                        .extend_context_with_struct_field("OrganizationalContact", "email"),
+                }],
+            },
+            _ => ValidationResult::Passed,
+        }
+    }
+}
+
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.contains(char::is_whitespace)
+        }
+        None => false,
+    }
 }
 
 impl Validate for OrganizationalContact {
@@ -92,6 +134,26 @@ pub struct OrganizationalEntity {
     pub contact: Option<Vec<OrganizationalContact>>,
 }
 
+impl OrganizationalEntity {
+    /// Check the email addresses of the contacts, see [OrganizationalContact::validate_email]
+    pub fn validate_contact_emails(&self, context: &ValidationContext) -> ValidationResult {
+        self.contact
+            .iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, contact)| {
+                contact.validate_email(&context.extend_context(vec![
+                    ValidationPathComponent::Struct {
+                        struct_name: "OrganizationalEntity".to_string(),
+                        field_name: "contact".to_string(),
+                    },
+                    ValidationPathComponent::Array { index },
+                ]))
+            })
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result))
+    }
+}
+
 impl Validate for OrganizationalEntity {
     fn validate_with_context(
         &self,
@@ -234,6 +296,47 @@ mod test {
         )
     }
 
+    #[test]
+    fn it_should_validate_email_addresses() {
+        let valid = OrganizationalContact::new("Jane Doe", Some("jane.doe@example.com"));
+        assert!(valid.has_valid_email());
+        assert_eq!(
+            valid.validate_email(&ValidationContext::default()),
+            ValidationResult::Passed
+        );
+
+        let missing_at = OrganizationalContact::new("Jane Doe", Some("jane.doe.example.com"));
+        assert!(!missing_at.has_valid_email());
+        assert_eq!(
+            missing_at.validate_with_context(ValidationContext::default()),
+            Ok(ValidationResult::Passed)
+        );
+        assert_eq!(
+            missing_at.validate_email(&ValidationContext::default()),
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Email address is not valid".to_string(),
+                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        struct_name: "OrganizationalContact".to_string(),
+                        field_name: "email".to_string()
+                    }])
+                }]
+            }
+        );
+
+        for invalid in [
+            "@example.com",
+            "jane@example",
+            "jane@.com",
+            "jane@example.",
+            "jane doe@example.com",
+            "jane@doe@example.com",
+        ] {
+            assert!(!is_valid_email(invalid), "{} should be invalid", invalid);
+        }
+        assert!(!OrganizationalContact::new("Jane Doe", None).has_valid_email());
+    }
+
     #[test]
     fn it_should_validate_an_invalid_entity_as_failed() {
         let entity = OrganizationalEntity {
@@ -20,9 +20,10 @@ use crate::external_models::{date_time::DateTime, normalized_string::NormalizedS
 use crate::models::advisory::Advisories;
 use crate::models::property::Properties;
 use crate::models::tool::Tools;
-use crate::models::vulnerability_analysis::VulnerabilityAnalysis;
+use crate::models::vulnerability_analysis::{ImpactAnalysisState, VulnerabilityAnalysis};
 use crate::models::vulnerability_credits::VulnerabilityCredits;
-use crate::models::vulnerability_rating::VulnerabilityRatings;
+use crate::models::vulnerability_proof_of_concept::ProofOfConcept;
+use crate::models::vulnerability_rating::{Severity, VulnerabilityRatings};
 use crate::models::vulnerability_reference::VulnerabilityReferences;
 use crate::models::vulnerability_source::VulnerabilitySource;
 use crate::models::vulnerability_target::VulnerabilityTargets;
@@ -44,6 +45,10 @@ pub struct Vulnerability {
     pub description: Option<String>,
     pub detail: Option<String>,
     pub recommendation: Option<String>,
+    /// Added in version 1.5
+    pub workaround: Option<String>,
+    /// Added in version 1.5
+    pub proof_of_concept: Option<ProofOfConcept>,
     pub advisories: Option<Advisories>,
     pub created: Option<DateTime>,
     pub published: Option<DateTime>,
@@ -73,6 +78,8 @@ impl Vulnerability {
             description: None,
             detail: None,
             recommendation: None,
+            workaround: None,
+            proof_of_concept: None,
             advisories: None,
             created: None,
             published: None,
@@ -84,6 +91,48 @@ impl Vulnerability {
             properties: None,
         }
     }
+
+    /// Links to the [CWE](https://cwe.mitre.org/) definitions of the weaknesses of this
+    /// vulnerability
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::Vulnerability;
+    ///
+    /// let mut vulnerability = Vulnerability::new(None);
+    /// vulnerability.cwes = Some(vec![79]);
+    ///
+    /// assert_eq!(
+    ///     vulnerability.cwe_urls(),
+    ///     vec!["https://cwe.mitre.org/data/definitions/79.html".to_string()]
+    /// );
+    /// ```
+    pub fn cwe_urls(&self) -> Vec<String> {
+        self.cwes
+            .iter()
+            .flatten()
+            .map(|cwe| format!("https://cwe.mitre.org/data/definitions/{}.html", cwe))
+            .collect()
+    }
+
+    /// The most severe rating of this vulnerability
+    pub fn max_severity(&self) -> Option<&Severity> {
+        self.vulnerability_ratings
+            .iter()
+            .flat_map(|ratings| ratings.0.iter())
+            .filter_map(|rating| rating.severity.as_ref())
+            .max()
+    }
+
+    /// Whether the analysis of this vulnerability, e.g. from a VEX document, concluded that
+    /// the affected components are not affected
+    pub fn is_not_affected(&self) -> bool {
+        matches!(
+            self.vulnerability_analysis,
+            Some(VulnerabilityAnalysis {
+                state: Some(ImpactAnalysisState::NotAffected),
+                ..
+            })
+        )
+    }
 }
 
 impl Validate for Vulnerability {
@@ -120,6 +169,13 @@ impl Validate for Vulnerability {
             results.push(vulnerability_ratings.validate_with_context(context)?);
         }
 
+        if let Some(proof_of_concept) = &self.proof_of_concept {
+            let context =
+                context.extend_context_with_struct_field("Vulnerability", "proof_of_concept");
+
+            results.push(proof_of_concept.validate_with_context(context)?);
+        }
+
         if let Some(advisories) = &self.advisories {
             let context = context.extend_context_with_struct_field("Vulnerability", "advisories");
 
@@ -186,6 +242,130 @@ impl Validate for Vulnerability {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Vulnerabilities(pub Vec<Vulnerability>);
 
+impl Vulnerabilities {
+    /// Merge the vulnerabilities of `other` into this list.
+    ///
+    /// Vulnerabilities are considered to be the same if they share the same `id` and the same
+    /// source name. Matching entries are merged by taking the union of their affected targets and
+    /// ratings, all other vulnerabilities are appended. Vulnerabilities without an `id` are
+    /// always appended.
+    ///
+    /// If two matching vulnerabilities carry a different analysis state, the existing state is
+    /// kept and the conflict is returned to the caller.
+    pub fn merge(&mut self, other: Vulnerabilities) -> Vec<AnalysisStateConflict> {
+        let mut conflicts = Vec::new();
+
+        for incoming in other.0 {
+            let existing = incoming.id.as_ref().and_then(|id| {
+                self.0.iter_mut().find(|existing| {
+                    existing.id.as_ref() == Some(id)
+                        && source_name(existing) == source_name(&incoming)
+                })
+            });
+
+            match existing {
+                Some(existing) => {
+                    if let Some(conflict) = existing.merge(incoming) {
+                        conflicts.push(conflict);
+                    }
+                }
+                None => self.0.push(incoming),
+            }
+        }
+
+        conflicts
+    }
+
+    /// The vulnerabilities whose most severe rating is at least as severe as `min`, in the
+    /// order of [Severity]. Vulnerabilities without a rated severity count as `unknown`.
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability::{Vulnerabilities, Vulnerability};
+    /// use cyclonedx_bom::models::vulnerability_rating::{Severity, VulnerabilityRating, VulnerabilityRatings};
+    ///
+    /// let mut vulnerability = Vulnerability::new(None);
+    /// vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(vec![
+    ///     VulnerabilityRating::new(None, Some(Severity::High), None),
+    /// ]));
+    /// let vulnerabilities = Vulnerabilities(vec![vulnerability, Vulnerability::new(None)]);
+    ///
+    /// assert_eq!(vulnerabilities.filter_by_severity(Severity::High).len(), 1);
+    /// ```
+    pub fn filter_by_severity(&self, min: Severity) -> Vec<&Vulnerability> {
+        self.0
+            .iter()
+            .filter(|vulnerability| {
+                *vulnerability.max_severity().unwrap_or(&Severity::Unknown) >= min
+            })
+            .collect()
+    }
+}
+
+impl Vulnerability {
+    fn merge(&mut self, other: Vulnerability) -> Option<AnalysisStateConflict> {
+        if let Some(targets) = other.vulnerability_targets {
+            let existing = self
+                .vulnerability_targets
+                .get_or_insert_with(|| VulnerabilityTargets(Vec::new()));
+            for target in targets.0 {
+                if !existing.0.contains(&target) {
+                    existing.0.push(target);
+                }
+            }
+        }
+
+        if let Some(ratings) = other.vulnerability_ratings {
+            let existing = self
+                .vulnerability_ratings
+                .get_or_insert_with(|| VulnerabilityRatings(Vec::new()));
+            for rating in ratings.0 {
+                if !existing.0.contains(&rating) {
+                    existing.0.push(rating);
+                }
+            }
+        }
+
+        let existing_state = self
+            .vulnerability_analysis
+            .as_ref()
+            .and_then(|a| a.state.as_ref());
+        let incoming_state = other
+            .vulnerability_analysis
+            .as_ref()
+            .and_then(|a| a.state.as_ref());
+
+        match (existing_state, incoming_state) {
+            (Some(existing_state), Some(incoming_state)) if existing_state != incoming_state => {
+                Some(AnalysisStateConflict {
+                    id: self.id.clone().unwrap_or_default(),
+                    existing: existing_state.clone(),
+                    incoming: incoming_state.clone(),
+                })
+            }
+            (None, Some(_)) => {
+                self.vulnerability_analysis = other.vulnerability_analysis;
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+fn source_name(vulnerability: &Vulnerability) -> Option<&NormalizedString> {
+    vulnerability
+        .vulnerability_source
+        .as_ref()
+        .and_then(|source| source.name.as_ref())
+}
+
+/// Reported by [`Vulnerabilities::merge`] when two matching vulnerabilities disagree on their
+/// analysis state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnalysisStateConflict {
+    pub id: NormalizedString,
+    pub existing: ImpactAnalysisState,
+    pub incoming: ImpactAnalysisState,
+}
+
 impl Validate for Vulnerabilities {
     fn validate_with_context(
         &self,
@@ -254,6 +434,8 @@ mod test {
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(Advisories(vec![Advisory {
                 title: Some(NormalizedString::new("title")),
                 url: Uri("https://example.com".to_string()),
@@ -325,6 +507,8 @@ mod test {
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(Advisories(vec![Advisory {
                 title: Some(NormalizedString("invalid\ttitle".to_string())),
                 url: Uri("invalid url".to_string()),
@@ -578,4 +762,142 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_find_the_most_severe_rating() {
+        let mut vulnerability = Vulnerability::new(None);
+        assert_eq!(vulnerability.max_severity(), None);
+
+        vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(vec![
+            VulnerabilityRating::new(None, Some(Severity::Medium), None),
+            VulnerabilityRating::new(None, Some(Severity::Unknown), None),
+            VulnerabilityRating::new(None, Some(Severity::High), None),
+            VulnerabilityRating::new(None, Some(Severity::Low), None),
+        ]));
+        assert_eq!(vulnerability.max_severity(), Some(&Severity::High));
+        assert!(!vulnerability.is_not_affected());
+
+        vulnerability.vulnerability_analysis = Some(VulnerabilityAnalysis::new(
+            Some(ImpactAnalysisState::NotAffected),
+            None,
+            None,
+        ));
+        assert!(vulnerability.is_not_affected());
+    }
+
+    #[test]
+    fn it_should_filter_vulnerabilities_by_their_most_severe_rating() {
+        let vulnerability = |id: &str, severities: Vec<Severity>| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new(id));
+            vulnerability.vulnerability_ratings = Some(VulnerabilityRatings(
+                severities
+                    .into_iter()
+                    .map(|severity| VulnerabilityRating::new(None, Some(severity), None))
+                    .collect(),
+            ));
+            vulnerability
+        };
+        let mut unrated = Vulnerability::new(None);
+        unrated.id = Some(NormalizedString::new("UNRATED"));
+
+        let vulnerabilities = Vulnerabilities(vec![
+            vulnerability("LOW", vec![Severity::Low]),
+            vulnerability("MIXED", vec![Severity::Low, Severity::Critical]),
+            unrated,
+            vulnerability("HIGH", vec![Severity::High, Severity::Info]),
+        ]);
+        let ids = |min: Severity| -> Vec<String> {
+            vulnerabilities
+                .filter_by_severity(min)
+                .into_iter()
+                .filter_map(|v| v.id.as_ref().map(|id| id.to_string()))
+                .collect()
+        };
+
+        assert_eq!(ids(Severity::Critical), vec!["MIXED"]);
+        assert_eq!(ids(Severity::High), vec!["MIXED", "HIGH"]);
+        assert_eq!(ids(Severity::Low), vec!["LOW", "MIXED", "HIGH"]);
+        assert_eq!(
+            ids(Severity::Unknown),
+            vec!["LOW", "MIXED", "UNRATED", "HIGH"]
+        );
+    }
+
+    #[test]
+    fn it_should_merge_vulnerabilities_with_the_same_id_and_source() {
+        let vulnerability = |id: &str, source: &str, target: &str, state: ImpactAnalysisState| {
+            let mut vulnerability = Vulnerability::new(None);
+            vulnerability.id = Some(NormalizedString::new(id));
+            vulnerability.vulnerability_source =
+                Some(VulnerabilitySource::new(Some(source.to_string()), None));
+            vulnerability.vulnerability_ratings =
+                Some(VulnerabilityRatings(vec![VulnerabilityRating::new(
+                    Score::from_f32(9.8),
+                    Some(Severity::Critical),
+                    None,
+                )]));
+            vulnerability.vulnerability_targets =
+                Some(VulnerabilityTargets(vec![VulnerabilityTarget::new(
+                    target.to_string(),
+                )]));
+            vulnerability.vulnerability_analysis =
+                Some(VulnerabilityAnalysis::new(Some(state), None, None));
+            vulnerability
+        };
+
+        let mut vulnerabilities = Vulnerabilities(vec![vulnerability(
+            "CVE-2023-0001",
+            "NVD",
+            "component-a",
+            ImpactAnalysisState::Exploitable,
+        )]);
+        let other = Vulnerabilities(vec![
+            vulnerability(
+                "CVE-2023-0001",
+                "NVD",
+                "component-b",
+                ImpactAnalysisState::NotAffected,
+            ),
+            vulnerability(
+                "CVE-2023-0001",
+                "GHSA",
+                "component-b",
+                ImpactAnalysisState::Exploitable,
+            ),
+        ]);
+
+        let conflicts = vulnerabilities.merge(other);
+
+        assert_eq!(
+            conflicts,
+            vec![AnalysisStateConflict {
+                id: NormalizedString::new("CVE-2023-0001"),
+                existing: ImpactAnalysisState::Exploitable,
+                incoming: ImpactAnalysisState::NotAffected,
+            }]
+        );
+        assert_eq!(vulnerabilities.0.len(), 2);
+        assert_eq!(
+            vulnerabilities.0[0].vulnerability_targets,
+            Some(VulnerabilityTargets(vec![
+                VulnerabilityTarget::new("component-a".to_string()),
+                VulnerabilityTarget::new("component-b".to_string()),
+            ]))
+        );
+        assert_eq!(
+            vulnerabilities.0[0]
+                .vulnerability_ratings
+                .as_ref()
+                .map(|ratings| ratings.0.len()),
+            Some(1)
+        );
+        assert_eq!(
+            vulnerabilities.0[0]
+                .vulnerability_analysis
+                .as_ref()
+                .and_then(|analysis| analysis.state.clone()),
+            Some(ImpactAnalysisState::Exploitable)
+        );
+    }
 }
@@ -16,6 +16,9 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::fmt;
+use std::str::FromStr;
+
 use crate::validation::{
     FailureReason, Validate, ValidationContext, ValidationError, ValidationPathComponent,
     ValidationResult,
@@ -114,18 +117,37 @@ pub enum ImpactAnalysisState {
 
 impl ImpactAnalysisState {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "resolved" => Self::Resolved,
-            "resolved_with_pedigree" => Self::ResolvedWithPedigree,
-            "exploitable" => Self::Exploitable,
-            "in_triage" => Self::InTriage,
-            "false_positive" => Self::FalsePositive,
-            "not_affected" => Self::NotAffected,
-            undefined => Self::UndefinedImpactAnalysisState(undefined.to_string()),
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UndefinedImpactAnalysisState(value.as_ref().to_string()))
+    }
+}
+
+impl FromStr for ImpactAnalysisState {
+    type Err = ImpactAnalysisStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "resolved" => Ok(Self::Resolved),
+            "resolved_with_pedigree" => Ok(Self::ResolvedWithPedigree),
+            "exploitable" => Ok(Self::Exploitable),
+            "in_triage" => Ok(Self::InTriage),
+            "false_positive" => Ok(Self::FalsePositive),
+            "not_affected" => Ok(Self::NotAffected),
+            _ => Err(ImpactAnalysisStateError::UndefinedImpactAnalysisState(
+                s.to_string(),
+            )),
         }
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ImpactAnalysisStateError {
+    #[error("Undefined impact analysis state: {0}")]
+    UndefinedImpactAnalysisState(String),
+}
+
 impl Validate for ImpactAnalysisState {
     fn validate_with_context(
         &self,
@@ -143,9 +165,9 @@ impl Validate for ImpactAnalysisState {
     }
 }
 
-impl ToString for ImpactAnalysisState {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for ImpactAnalysisState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             ImpactAnalysisState::Resolved => "resolved",
             ImpactAnalysisState::ResolvedWithPedigree => "resolved_with_pedigree",
             ImpactAnalysisState::Exploitable => "exploitable",
@@ -153,8 +175,8 @@ impl ToString for ImpactAnalysisState {
             ImpactAnalysisState::FalsePositive => "false_positive",
             ImpactAnalysisState::NotAffected => "not_affected",
             ImpactAnalysisState::UndefinedImpactAnalysisState(undefined) => undefined,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -178,21 +200,41 @@ pub enum ImpactAnalysisJustification {
 
 impl ImpactAnalysisJustification {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "code_not_present" => Self::CodeNotPresent,
-            "code_not_reachable" => Self::CodeNotReachable,
-            "requires_configuration" => Self::RequiresConfiguration,
-            "requires_dependency" => Self::RequiresDependency,
-            "requires_environment" => Self::RequiresEnvironment,
-            "protected_by_compiler" => Self::ProtectedByCompiler,
-            "protected_at_runtime" => Self::ProtectedAtRuntime,
-            "protected_at_perimeter" => Self::ProtectedAtPerimeter,
-            "protected_by_mitigating_control" => Self::ProtectedByMitigatingControl,
-            undefined => Self::UndefinedImpactAnalysisJustification(undefined.to_string()),
+        value.as_ref().parse().unwrap_or_else(|_| {
+            Self::UndefinedImpactAnalysisJustification(value.as_ref().to_string())
+        })
+    }
+}
+
+impl FromStr for ImpactAnalysisJustification {
+    type Err = ImpactAnalysisJustificationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "code_not_present" => Ok(Self::CodeNotPresent),
+            "code_not_reachable" => Ok(Self::CodeNotReachable),
+            "requires_configuration" => Ok(Self::RequiresConfiguration),
+            "requires_dependency" => Ok(Self::RequiresDependency),
+            "requires_environment" => Ok(Self::RequiresEnvironment),
+            "protected_by_compiler" => Ok(Self::ProtectedByCompiler),
+            "protected_at_runtime" => Ok(Self::ProtectedAtRuntime),
+            "protected_at_perimeter" => Ok(Self::ProtectedAtPerimeter),
+            "protected_by_mitigating_control" => Ok(Self::ProtectedByMitigatingControl),
+            _ => Err(
+                ImpactAnalysisJustificationError::UndefinedImpactAnalysisJustification(
+                    s.to_string(),
+                ),
+            ),
         }
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ImpactAnalysisJustificationError {
+    #[error("Undefined impact analysis justification: {0}")]
+    UndefinedImpactAnalysisJustification(String),
+}
+
 impl Validate for ImpactAnalysisJustification {
     fn validate_with_context(
         &self,
@@ -212,9 +254,9 @@ impl Validate for ImpactAnalysisJustification {
     }
 }
 
-impl ToString for ImpactAnalysisJustification {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for ImpactAnalysisJustification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             ImpactAnalysisJustification::CodeNotPresent => "code_not_present",
             ImpactAnalysisJustification::CodeNotReachable => "code_not_reachable",
             ImpactAnalysisJustification::RequiresConfiguration => "requires_configuration",
@@ -229,8 +271,8 @@ impl ToString for ImpactAnalysisJustification {
             ImpactAnalysisJustification::UndefinedImpactAnalysisJustification(undefined) => {
                 undefined
             }
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -250,17 +292,36 @@ pub enum ImpactAnalysisResponse {
 
 impl ImpactAnalysisResponse {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "can_not_fix" => Self::CanNotFix,
-            "will_not_fix" => Self::WillNotFix,
-            "update" => Self::Update,
-            "rollback" => Self::Rollback,
-            "workaround_available" => Self::WorkaroundAvailable,
-            undefined => Self::UndefinedResponse(undefined.to_string()),
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UndefinedResponse(value.as_ref().to_string()))
+    }
+}
+
+impl FromStr for ImpactAnalysisResponse {
+    type Err = ImpactAnalysisResponseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "can_not_fix" => Ok(Self::CanNotFix),
+            "will_not_fix" => Ok(Self::WillNotFix),
+            "update" => Ok(Self::Update),
+            "rollback" => Ok(Self::Rollback),
+            "workaround_available" => Ok(Self::WorkaroundAvailable),
+            _ => Err(ImpactAnalysisResponseError::UndefinedResponse(
+                s.to_string(),
+            )),
         }
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ImpactAnalysisResponseError {
+    #[error("Undefined response: {0}")]
+    UndefinedResponse(String),
+}
+
 impl Validate for ImpactAnalysisResponse {
     fn validate_with_context(
         &self,
@@ -278,17 +339,17 @@ impl Validate for ImpactAnalysisResponse {
     }
 }
 
-impl ToString for ImpactAnalysisResponse {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for ImpactAnalysisResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             ImpactAnalysisResponse::CanNotFix => "can_not_fix",
             ImpactAnalysisResponse::WillNotFix => "will_not_fix",
             ImpactAnalysisResponse::Update => "update",
             ImpactAnalysisResponse::Rollback => "rollback",
             ImpactAnalysisResponse::WorkaroundAvailable => "workaround_available",
             ImpactAnalysisResponse::UndefinedResponse(undefined) => undefined,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -362,4 +423,73 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn analysis_values_should_round_trip_through_strings() {
+        for state in [
+            "resolved",
+            "resolved_with_pedigree",
+            "exploitable",
+            "in_triage",
+            "false_positive",
+            "not_affected",
+        ] {
+            assert_eq!(
+                ImpactAnalysisState::from_str(state).unwrap().to_string(),
+                state
+            );
+        }
+        for justification in [
+            "code_not_present",
+            "code_not_reachable",
+            "requires_configuration",
+            "requires_dependency",
+            "requires_environment",
+            "protected_by_compiler",
+            "protected_at_runtime",
+            "protected_at_perimeter",
+            "protected_by_mitigating_control",
+        ] {
+            assert_eq!(
+                ImpactAnalysisJustification::from_str(justification)
+                    .unwrap()
+                    .to_string(),
+                justification
+            );
+        }
+        for response in [
+            "can_not_fix",
+            "will_not_fix",
+            "update",
+            "rollback",
+            "workaround_available",
+        ] {
+            assert_eq!(
+                ImpactAnalysisResponse::from_str(response)
+                    .unwrap()
+                    .to_string(),
+                response
+            );
+        }
+    }
+
+    #[test]
+    fn undefined_analysis_values_should_be_kept() {
+        assert_eq!(
+            ImpactAnalysisState::from_str("fixed"),
+            Err(ImpactAnalysisStateError::UndefinedImpactAnalysisState(
+                "fixed".to_string()
+            ))
+        );
+        assert_eq!(
+            ImpactAnalysisJustification::new_unchecked("not_used"),
+            ImpactAnalysisJustification::UndefinedImpactAnalysisJustification(
+                "not_used".to_string()
+            )
+        );
+        assert_eq!(
+            ImpactAnalysisResponse::new_unchecked("patched").to_string(),
+            "patched"
+        );
+    }
 }
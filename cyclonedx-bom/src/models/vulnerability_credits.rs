@@ -28,6 +28,64 @@ pub struct VulnerabilityCredits {
     pub individuals: Option<Vec<OrganizationalContact>>,
 }
 
+impl VulnerabilityCredits {
+    /// Construct `VulnerabilityCredits` without any organizations or individuals
+    /// ```
+    /// use cyclonedx_bom::models::organization::{OrganizationalContact, OrganizationalEntity};
+    /// use cyclonedx_bom::models::vulnerability_credits::VulnerabilityCredits;
+    /// use cyclonedx_bom::external_models::normalized_string::NormalizedString;
+    ///
+    /// let credits = VulnerabilityCredits::new()
+    ///     .with_organization(OrganizationalEntity {
+    ///         name: Some(NormalizedString::new("Example Org")),
+    ///         url: None,
+    ///         contact: None,
+    ///     })
+    ///     .with_individual(OrganizationalContact::new("Jane Doe", Some("jane@example.com")));
+    ///
+    /// assert_eq!(credits.organizations().len(), 1);
+    /// assert_eq!(credits.individuals().len(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            organizations: None,
+            individuals: None,
+        }
+    }
+
+    /// Adds an organization to the credits
+    pub fn with_organization(mut self, organization: OrganizationalEntity) -> Self {
+        self.organizations
+            .get_or_insert_with(Vec::new)
+            .push(organization);
+        self
+    }
+
+    /// Adds an individual to the credits
+    pub fn with_individual(mut self, individual: OrganizationalContact) -> Self {
+        self.individuals
+            .get_or_insert_with(Vec::new)
+            .push(individual);
+        self
+    }
+
+    /// The credited organizations, empty if there are none
+    pub fn organizations(&self) -> &[OrganizationalEntity] {
+        self.organizations.as_deref().unwrap_or_default()
+    }
+
+    /// The credited individuals, empty if there are none
+    pub fn individuals(&self) -> &[OrganizationalContact] {
+        self.individuals.as_deref().unwrap_or_default()
+    }
+}
+
+impl Default for VulnerabilityCredits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Validate for VulnerabilityCredits {
     fn validate_with_context(
         &self,
@@ -69,7 +127,14 @@ impl Validate for VulnerabilityCredits {
 
 #[cfg(test)]
 mod test {
-    use crate::{external_models::normalized_string::NormalizedString, validation::FailureReason};
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        models::{
+            bom::{Bom, SpecVersion},
+            vulnerability::{Vulnerabilities, Vulnerability},
+        },
+        validation::FailureReason,
+    };
 
     use super::*;
     use pretty_assertions::assert_eq;
@@ -151,4 +216,56 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn it_should_build_and_serialize_credits() {
+        let credits = VulnerabilityCredits::new()
+            .with_organization(OrganizationalEntity {
+                name: Some(NormalizedString::new("Example Org")),
+                url: None,
+                contact: None,
+            })
+            .with_individual(OrganizationalContact::new(
+                "Jane Doe",
+                Some("jane@example.com"),
+            ));
+
+        assert_eq!(
+            credits.organizations()[0].name,
+            Some(NormalizedString::new("Example Org"))
+        );
+        assert_eq!(
+            credits.individuals()[0].email,
+            Some(NormalizedString::new("jane@example.com"))
+        );
+
+        let mut vulnerability = Vulnerability::new(Some("vuln-1".to_string()));
+        vulnerability.vulnerability_credits = Some(credits.clone());
+        let bom = Bom {
+            vulnerabilities: Some(Vulnerabilities(vec![vulnerability])),
+            ..Bom::default()
+        };
+
+        let json = bom
+            .to_cyclonedx_json_bytes(SpecVersion::V1_4)
+            .expect("Failed to write JSON");
+        let value: serde_json::Value = serde_json::from_slice(&json).expect("Invalid JSON");
+        let written = &value["vulnerabilities"][0]["credits"];
+        assert_eq!(written["organizations"][0]["name"], "Example Org");
+        assert_eq!(written["individuals"][0]["name"], "Jane Doe");
+
+        let parsed = Bom::parse_from_json_v1_4(json.as_slice()).expect("Failed to parse JSON");
+        let parsed_credits = parsed
+            .vulnerabilities
+            .and_then(|v| v.0[0].vulnerability_credits.clone());
+        assert_eq!(parsed_credits, Some(credits));
+    }
+
+    #[test]
+    fn empty_credits_should_have_no_organizations_or_individuals() {
+        let credits = VulnerabilityCredits::new();
+
+        assert!(credits.organizations().is_empty());
+        assert!(credits.individuals().is_empty());
+    }
 }
@@ -0,0 +1,118 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::models::attached_text::AttachedText;
+use crate::validation::{
+    Validate, ValidationContext, ValidationError, ValidationPathComponent, ValidationResult,
+};
+
+/// Evidence confirming that a vulnerability can be exploited
+///
+/// Defined via the [XML schema](https://cyclonedx.org/docs/1.5/xml/#type_vulnerabilityType_proofOfConcept).
+/// Added in version 1.5
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProofOfConcept {
+    /// Precise steps to reproduce the vulnerability
+    pub reproduction_steps: Option<String>,
+    /// A description of the environment in which reproduction was possible
+    pub environment: Option<String>,
+    /// Supporting material such as screenshots, payloads or packet captures
+    pub supporting_material: Option<Vec<AttachedText>>,
+}
+
+impl Validate for ProofOfConcept {
+    fn validate_with_context(
+        &self,
+        context: ValidationContext,
+    ) -> Result<ValidationResult, ValidationError> {
+        let mut results: Vec<ValidationResult> = vec![];
+
+        if let Some(supporting_material) = &self.supporting_material {
+            for (index, attachment) in supporting_material.iter().enumerate() {
+                let context = context
+                    .extend_context_with_struct_field("ProofOfConcept", "supporting_material")
+                    .extend_context(vec![ValidationPathComponent::Array { index }]);
+
+                results.push(attachment.validate_with_context(context)?);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        external_models::normalized_string::NormalizedString,
+        models::attached_text::Encoding,
+        validation::{FailureReason, ValidationPathComponent},
+    };
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid_proof_of_concept_should_pass_validation() {
+        let validation_result = ProofOfConcept {
+            reproduction_steps: Some("steps".to_string()),
+            environment: Some("environment".to_string()),
+            supporting_material: Some(vec![AttachedText::new(None, "payload")]),
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(validation_result, ValidationResult::Passed);
+    }
+
+    #[test]
+    fn invalid_proof_of_concept_should_fail_validation() {
+        let validation_result = ProofOfConcept {
+            reproduction_steps: None,
+            environment: None,
+            supporting_material: Some(vec![AttachedText {
+                content_type: Some(NormalizedString("text/plain".to_string())),
+                encoding: Some(Encoding::UnknownEncoding("unknown".to_string())),
+                content: "payload".to_string(),
+            }]),
+        }
+        .validate_with_context(ValidationContext::default())
+        .expect("Error while validating");
+
+        assert_eq!(
+            validation_result,
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Unknown encoding".to_string(),
+                    context: ValidationContext(vec![
+                        ValidationPathComponent::Struct {
+                            struct_name: "ProofOfConcept".to_string(),
+                            field_name: "supporting_material".to_string()
+                        },
+                        ValidationPathComponent::Array { index: 0 },
+                        ValidationPathComponent::Struct {
+                            struct_name: "AttachedText".to_string(),
+                            field_name: "encoding".to_string()
+                        },
+                    ])
+                }]
+            }
+        );
+    }
+}
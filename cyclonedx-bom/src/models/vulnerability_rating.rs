@@ -16,6 +16,9 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::fmt;
+use std::str::FromStr;
+
 use ordered_float::OrderedFloat;
 
 use crate::external_models::normalized_string::NormalizedString;
@@ -59,6 +62,40 @@ impl VulnerabilityRating {
             justification: None,
         }
     }
+
+    /// Check that a CVSS v3.1 `vector` can be parsed, and that the `score` is the base score
+    /// it gives. Other vectors are not checked.
+    pub fn validate_cvss_score(&self, context: &ValidationContext) -> ValidationResult {
+        let vector = match &self.vector {
+            Some(vector) if vector.starts_with(CvssVector::PREFIX) => vector,
+            _ => return ValidationResult::Passed,
+        };
+
+        let failed = |field_name: &str, message: String| ValidationResult::Failed {
+            reasons: vec![FailureReason {
+                message,
+                context: context
+                    .extend_context_with_struct_field("VulnerabilityRating", field_name),
+            }],
+        };
+        match CvssVector::parse(vector) {
+            Err(error) => failed(
+                "vector",
+                format!("Vector is not a valid CVSS v3.1 vector: {error}"),
+            ),
+            Ok(vector) => match &self.score {
+                Some(score) if (score.to_f32() - vector.base_score()).abs() > 0.05 => failed(
+                    "score",
+                    format!(
+                        "Score {} does not match the base score {:.1} of the vector",
+                        score.to_f32(),
+                        vector.base_score()
+                    ),
+                ),
+                _ => ValidationResult::Passed,
+            },
+        }
+    }
 }
 
 // todo: how to decide what to validate, check this
@@ -89,6 +126,8 @@ impl Validate for VulnerabilityRating {
             results.push(vector.validate_with_context(context)?);
         }
 
+        results.push(self.validate_cvss_score(&context));
+
         Ok(results
             .into_iter()
             .fold(ValidationResult::default(), |acc, result| acc.merge(result)))
@@ -155,6 +194,17 @@ impl From<Score> for f32 {
 /// Specifies a vulnerability's severity adopted by the analysis method.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_severityType)
+///
+/// Severities are ordered from the least to the most severe, with `critical` the greatest,
+/// followed by `high`, `medium`, `low`, `info`, `unknown` and `none`. Undefined severities
+/// are the least of all, so that sorting in reverse lists the worst first.
+/// ```
+/// use cyclonedx_bom::models::vulnerability_rating::Severity;
+///
+/// let mut severities = vec![Severity::Low, Severity::Critical, Severity::None];
+/// severities.sort_by(|a, b| b.cmp(a));
+/// assert_eq!(severities, vec![Severity::Critical, Severity::Low, Severity::None]);
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Severity {
     Critical,
@@ -170,19 +220,84 @@ pub enum Severity {
 
 impl Severity {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "critical" => Self::Critical,
-            "high" => Self::High,
-            "medium" => Self::Medium,
-            "low" => Self::Low,
-            "info" => Self::Info,
-            "none" => Self::None,
-            "unknown" => Self::Unknown,
-            undefined => Self::UndefinedSeverity(undefined.to_string()),
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::UndefinedSeverity(value.as_ref().to_string()))
+    }
+
+    /// Whether this severity is as severe as `threshold` or more.
+    /// `none`, `unknown` and undefined severities never reach a threshold.
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability_rating::Severity;
+    ///
+    /// assert!(Severity::Critical.is_at_least(&Severity::High));
+    /// assert!(!Severity::Low.is_at_least(&Severity::Medium));
+    /// ```
+    pub fn is_at_least(&self, threshold: &Severity) -> bool {
+        self.rank() > 0 && self.rank() >= threshold.rank()
+    }
+
+    pub(crate) fn rank(&self) -> u8 {
+        match self {
+            Severity::Critical => 5,
+            Severity::High => 4,
+            Severity::Medium => 3,
+            Severity::Low => 2,
+            Severity::Info => 1,
+            Severity::None | Severity::Unknown | Severity::UndefinedSeverity(_) => 0,
+        }
+    }
+
+    /// The position in the order of severities, see [Ord]
+    fn order(&self) -> u8 {
+        match self {
+            Severity::UndefinedSeverity(_) => 0,
+            Severity::None => 1,
+            Severity::Unknown => 2,
+            severity => severity.rank() + 2,
+        }
+    }
+}
+
+impl Ord for Severity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Severity::UndefinedSeverity(a), Severity::UndefinedSeverity(b)) => a.cmp(b),
+            _ => self.order().cmp(&other.order()),
+        }
+    }
+}
+
+impl PartialOrd for Severity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Severity {
+    type Err = SeverityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "critical" => Ok(Self::Critical),
+            "high" => Ok(Self::High),
+            "medium" => Ok(Self::Medium),
+            "low" => Ok(Self::Low),
+            "info" => Ok(Self::Info),
+            "none" => Ok(Self::None),
+            "unknown" => Ok(Self::Unknown),
+            _ => Err(SeverityError::UndefinedSeverity(s.to_string())),
         }
     }
 }
 
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum SeverityError {
+    #[error("Undefined severity: {0}")]
+    UndefinedSeverity(String),
+}
+
 impl Validate for Severity {
     fn validate_with_context(
         &self,
@@ -200,9 +315,9 @@ impl Validate for Severity {
     }
 }
 
-impl ToString for Severity {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             Severity::Critical => "critical",
             Severity::High => "high",
             Severity::Medium => "medium",
@@ -211,8 +326,8 @@ impl ToString for Severity {
             Severity::None => "none",
             Severity::Unknown => "unknown",
             Severity::UndefinedSeverity(undefined) => undefined,
-        }
-        .to_string()
+        };
+        f.write_str(s)
     }
 }
 
@@ -225,34 +340,242 @@ pub enum ScoreMethod {
     CVSSv3,
     CVSSv31,
     OWASP,
+    SSVC,
     Other(String),
 }
 
 impl ScoreMethod {
     pub(crate) fn new_unchecked<A: AsRef<str>>(value: A) -> Self {
-        match value.as_ref() {
-            "CVSSv2" => Self::CVSSv2,
-            "CVSSv3" => Self::CVSSv3,
-            "CVSSv31" => Self::CVSSv31,
-            "OWASP" => Self::OWASP,
-            score_method => Self::Other(score_method.to_string()),
+        value
+            .as_ref()
+            .parse()
+            .unwrap_or_else(|_| Self::Other(value.as_ref().to_string()))
+    }
+}
+
+impl FromStr for ScoreMethod {
+    type Err = ScoreMethodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CVSSv2" => Ok(Self::CVSSv2),
+            "CVSSv3" => Ok(Self::CVSSv3),
+            "CVSSv31" => Ok(Self::CVSSv31),
+            "OWASP" => Ok(Self::OWASP),
+            "SSVC" => Ok(Self::SSVC),
+            "other" => Ok(Self::Other(s.to_string())),
+            _ => Err(ScoreMethodError::UnknownScoreMethod(s.to_string())),
         }
     }
 }
 
-impl ToString for ScoreMethod {
-    fn to_string(&self) -> String {
-        match self {
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ScoreMethodError {
+    #[error("Unknown score method: {0}")]
+    UnknownScoreMethod(String),
+}
+
+impl fmt::Display for ScoreMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
             ScoreMethod::CVSSv2 => "CVSSv2",
             ScoreMethod::CVSSv3 => "CVSSv3",
             ScoreMethod::CVSSv31 => "CVSSv31",
             ScoreMethod::OWASP => "OWASP",
+            ScoreMethod::SSVC => "SSVC",
             ScoreMethod::Other(score_method) => score_method,
+        };
+        f.write_str(s)
+    }
+}
+
+/// A [CVSS v3.1](https://www.first.org/cvss/v3.1/specification-document) vector, e.g.
+/// `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`, from which the base score can be computed.
+/// It keeps the string it was parsed from, which is what [Display](fmt::Display) renders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CvssVector {
+    vector: String,
+    /// The values of the base metrics, in the order of [Self::BASE_METRICS]
+    base: [char; 8],
+}
+
+impl CvssVector {
+    const PREFIX: &'static str = "CVSS:3.1/";
+
+    /// The base metrics and their values, all of which a vector has to contain
+    const BASE_METRICS: [(&'static str, &'static str); 8] = [
+        ("AV", "NALP"),
+        ("AC", "LH"),
+        ("PR", "NLH"),
+        ("UI", "NR"),
+        ("S", "UC"),
+        ("C", "HLN"),
+        ("I", "HLN"),
+        ("A", "HLN"),
+    ];
+
+    /// The temporal and environmental metrics and their values, which a vector may contain
+    const OTHER_METRICS: [(&'static str, &'static str); 14] = [
+        ("E", "XUPFH"),
+        ("RL", "XOTWU"),
+        ("RC", "XURC"),
+        ("CR", "XLMH"),
+        ("IR", "XLMH"),
+        ("AR", "XLMH"),
+        ("MAV", "XNALP"),
+        ("MAC", "XLH"),
+        ("MPR", "XNLH"),
+        ("MUI", "XNR"),
+        ("MS", "XUC"),
+        ("MC", "XNLH"),
+        ("MI", "XNLH"),
+        ("MA", "XNLH"),
+    ];
+
+    /// Parses a vector, which has to contain every base metric, and may contain temporal and
+    /// environmental metrics, each at most once and in any order
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability_rating::CvssVector;
+    ///
+    /// let vector = CvssVector::parse("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")?;
+    /// assert_eq!(vector.base_score(), 9.8);
+    /// # Ok::<(), cyclonedx_bom::models::vulnerability_rating::CvssError>(())
+    /// ```
+    pub fn parse(vector: &str) -> Result<Self, CvssError> {
+        let metrics = vector
+            .strip_prefix(Self::PREFIX)
+            .ok_or_else(|| CvssError::UnsupportedVersion(vector.to_string()))?;
+
+        let mut base = [None; 8];
+        let mut seen = Vec::new();
+        for metric in metrics.split('/') {
+            let invalid = || CvssError::InvalidMetric(metric.to_string());
+            let (name, value) = metric.split_once(':').ok_or_else(invalid)?;
+            let value = match value.chars().collect::<Vec<_>>()[..] {
+                [value] => value,
+                _ => return Err(invalid()),
+            };
+            if seen.contains(&name) {
+                return Err(CvssError::DuplicateMetric(name.to_string()));
+            }
+            seen.push(name);
+
+            let base_index = Self::BASE_METRICS.iter().position(|(n, _)| *n == name);
+            let values = Self::BASE_METRICS
+                .iter()
+                .chain(Self::OTHER_METRICS.iter())
+                .find(|(n, _)| *n == name)
+                .map(|(_, values)| values)
+                .ok_or_else(invalid)?;
+            if !values.contains(value) {
+                return Err(invalid());
+            }
+            if let Some(index) = base_index {
+                base[index] = Some(value);
+            }
+        }
+
+        let mut values = [' '; 8];
+        for (index, value) in base.into_iter().enumerate() {
+            values[index] = value.ok_or(CvssError::MissingMetric(Self::BASE_METRICS[index].0))?;
         }
-        .to_string()
+        Ok(Self {
+            vector: vector.to_string(),
+            base: values,
+        })
+    }
+
+    /// The vector as it was parsed
+    pub fn as_str(&self) -> &str {
+        &self.vector
+    }
+
+    /// The base score, from 0.0 to 10.0, computed as defined in section 7.1 of the specification
+    pub fn base_score(&self) -> f32 {
+        let [av, ac, pr, ui, s, c, i, a] = self.base;
+        let scope_changed = s == 'C';
+        let cia = |value| match value {
+            'H' => 0.56,
+            'L' => 0.22,
+            _ => 0.0,
+        };
+        let attack_vector = match av {
+            'N' => 0.85,
+            'A' => 0.62,
+            'L' => 0.55,
+            _ => 0.2,
+        };
+        let attack_complexity = if ac == 'L' { 0.77 } else { 0.44 };
+        let privileges_required = match (pr, scope_changed) {
+            ('N', _) => 0.85,
+            ('L', false) => 0.62,
+            ('L', true) => 0.68,
+            (_, false) => 0.27,
+            (_, true) => 0.5,
+        };
+        let user_interaction = if ui == 'N' { 0.85 } else { 0.62 };
+
+        let iss: f64 = 1.0 - (1.0 - cia(c)) * (1.0 - cia(i)) * (1.0 - cia(a));
+        let impact = if scope_changed {
+            7.52 * (iss - 0.029) - 3.25 * (iss - 0.02).powi(15)
+        } else {
+            6.42 * iss
+        };
+        let exploitability =
+            8.22 * attack_vector * attack_complexity * privileges_required * user_interaction;
+
+        if impact <= 0.0 {
+            return 0.0;
+        }
+        let score = if scope_changed {
+            1.08 * (impact + exploitability)
+        } else {
+            impact + exploitability
+        };
+        round_up(score.min(10.0)) as f32
+    }
+}
+
+/// Rounds up to one decimal place as defined in Appendix A of the specification, which avoids
+/// rounding up values that are only above a decimal due to floating point errors
+fn round_up(value: f64) -> f64 {
+    let int_input = (value * 100_000.0).round() as i64;
+    if int_input % 10_000 == 0 {
+        int_input as f64 / 100_000.0
+    } else {
+        ((int_input / 10_000) + 1) as f64 / 10.0
+    }
+}
+
+impl FromStr for CvssVector {
+    type Err = CvssError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
     }
 }
 
+impl fmt::Display for CvssVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.vector)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CvssError {
+    #[error("Expected a vector starting with CVSS:3.1/, got '{0}'")]
+    UnsupportedVersion(String),
+
+    #[error("Invalid metric '{0}'")]
+    InvalidMetric(String),
+
+    #[error("Metric {0} is given more than once")]
+    DuplicateMetric(String),
+
+    #[error("Base metric {0} is missing")]
+    MissingMetric(&'static str),
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -358,4 +681,155 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn cvss_vectors_should_give_their_base_score() {
+        for (vector, score) in [
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", 9.8),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:C/C:H/I:H/A:H", 10.0),
+            ("CVSS:3.1/AV:L/AC:L/PR:L/UI:N/S:U/C:H/I:N/A:N", 5.5),
+            ("CVSS:3.1/AV:N/AC:H/PR:N/UI:R/S:U/C:L/I:N/A:N", 3.1),
+            ("CVSS:3.1/AV:N/AC:L/PR:L/UI:R/S:C/C:L/I:L/A:N", 5.4),
+            ("CVSS:3.1/AV:P/AC:H/PR:H/UI:R/S:U/C:L/I:N/A:N", 1.6),
+            ("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:N", 0.0),
+            (
+                "CVSS:3.1/A:H/I:H/C:H/S:U/UI:N/PR:N/AC:L/AV:N/E:P/MAV:L",
+                9.8,
+            ),
+        ] {
+            let parsed = CvssVector::parse(vector).expect("Failed to parse vector");
+            assert_eq!(parsed.base_score(), score, "{vector}");
+            assert_eq!(parsed.to_string(), vector);
+        }
+    }
+
+    #[test]
+    fn invalid_cvss_vectors_should_not_parse() {
+        for (vector, error) in [
+            (
+                "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                CvssError::UnsupportedVersion(
+                    "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H".to_string(),
+                ),
+            ),
+            (
+                "CVSS:3.1/AV:X/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                CvssError::InvalidMetric("AV:X".to_string()),
+            ),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H/XX:N",
+                CvssError::InvalidMetric("XX:N".to_string()),
+            ),
+            (
+                "CVSS:3.1/AV:N/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H",
+                CvssError::DuplicateMetric("AV".to_string()),
+            ),
+            (
+                "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H",
+                CvssError::MissingMetric("A"),
+            ),
+        ] {
+            assert_eq!(CvssVector::parse(vector), Err(error));
+        }
+    }
+
+    #[test]
+    fn cvss_scores_should_match_their_vector() {
+        let rating = |score: f32, vector: &str| VulnerabilityRating {
+            vulnerability_source: None,
+            score: Score::from_f32(score),
+            severity: None,
+            score_method: Some(ScoreMethod::CVSSv31),
+            vector: Some(NormalizedString::new(vector)),
+            justification: None,
+        };
+        let context = ValidationContext::default();
+
+        assert_eq!(
+            rating(9.8, "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+                .validate_cvss_score(&context),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            rating(7.5, "AV:N/AC:L/Au:N/C:P/I:P/A:P").validate_cvss_score(&context),
+            ValidationResult::Passed
+        );
+        assert_eq!(
+            rating(7.5, "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H")
+                .validate_with_context(context.clone()),
+            Ok(ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Score 7.5 does not match the base score 9.8 of the vector"
+                        .to_string(),
+                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityRating".to_string(),
+                        field_name: "score".to_string()
+                    }])
+                }]
+            })
+        );
+        assert_eq!(
+            rating(7.5, "CVSS:3.1/AV:N").validate_cvss_score(&context),
+            ValidationResult::Failed {
+                reasons: vec![FailureReason {
+                    message: "Vector is not a valid CVSS v3.1 vector: Base metric AC is missing"
+                        .to_string(),
+                    context: ValidationContext(vec![ValidationPathComponent::Struct {
+                        struct_name: "VulnerabilityRating".to_string(),
+                        field_name: "vector".to_string()
+                    }])
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn severities_should_be_ordered_by_how_severe_they_are() {
+        let mut severities = vec![
+            Severity::Info,
+            Severity::UndefinedSeverity("extreme".to_string()),
+            Severity::Critical,
+            Severity::None,
+            Severity::Medium,
+            Severity::Unknown,
+            Severity::High,
+            Severity::Low,
+        ];
+        severities.sort_by(|a, b| b.cmp(a));
+
+        assert_eq!(
+            severities,
+            vec![
+                Severity::Critical,
+                Severity::High,
+                Severity::Medium,
+                Severity::Low,
+                Severity::Info,
+                Severity::Unknown,
+                Severity::None,
+                Severity::UndefinedSeverity("extreme".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn severities_and_score_methods_should_round_trip_through_strings() {
+        for severity in [
+            "critical", "high", "medium", "low", "info", "none", "unknown",
+        ] {
+            assert_eq!(Severity::from_str(severity).unwrap().to_string(), severity);
+        }
+        assert_eq!(
+            Severity::from_str("extreme"),
+            Err(SeverityError::UndefinedSeverity("extreme".to_string()))
+        );
+
+        for method in ["CVSSv2", "CVSSv3", "CVSSv31", "OWASP", "SSVC", "other"] {
+            assert_eq!(ScoreMethod::from_str(method).unwrap().to_string(), method);
+        }
+        assert_eq!(
+            ScoreMethod::new_unchecked("EPSS"),
+            ScoreMethod::Other("EPSS".to_string())
+        );
+    }
 }
@@ -16,6 +16,10 @@
  * SPDX-License-Identifier: Apache-2.0
  */
 
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
 use once_cell::sync::Lazy;
 use regex::Regex;
 
@@ -176,6 +180,24 @@ impl VersionRange {
             false => VersionRange::Version(NormalizedString::new(value)),
         }
     }
+
+    /// Whether `version` is this version or within this range, see [VersRange::contains].
+    /// Returns `None` for a range that cannot be parsed.
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability_target::VersionRange;
+    ///
+    /// assert_eq!(VersionRange::new("vers:npm/>=2.0.0|<5.0.0").contains("2.4.1"), Some(true));
+    /// assert_eq!(VersionRange::new("1.0.0").contains("1.0.1"), Some(false));
+    /// ```
+    pub fn contains(&self, version: &str) -> Option<bool> {
+        match self {
+            VersionRange::Version(own) => Some(compare_versions(own, version) == Ordering::Equal),
+            VersionRange::Range(range) => VersRange::parse(range)
+                .ok()
+                .map(|range| range.contains(version)),
+            VersionRange::UndefinedVersionRange(_) => None,
+        }
+    }
 }
 
 impl Validate for VersionRange {
@@ -212,6 +234,253 @@ fn matches_purl_version_range_regex(value: &str) -> bool {
     PURL_VERSION_RANGE_REGEX.is_match(value)
 }
 
+/// A version range in the [vers](https://github.com/package-url/purl-spec/blob/version-range-spec/VERSION-RANGE-SPEC.rst)
+/// syntax, e.g. `vers:npm/1.2.3|>=2.0.0|<5.0.0`. It keeps the string it was parsed from,
+/// which is what [Display](fmt::Display) renders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersRange {
+    range: String,
+    scheme: String,
+    constraints: Vec<VersConstraint>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum VersConstraint {
+    /// `*`, which matches every version
+    Any,
+    Compare(Comparator, String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparator {
+    fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            Comparator::Equal => ordering == Ordering::Equal,
+            Comparator::NotEqual => ordering != Ordering::Equal,
+            Comparator::Less => ordering == Ordering::Less,
+            Comparator::LessOrEqual => ordering != Ordering::Greater,
+            Comparator::Greater => ordering == Ordering::Greater,
+            Comparator::GreaterOrEqual => ordering != Ordering::Less,
+        }
+    }
+
+    fn is_lower_bound(self) -> bool {
+        matches!(self, Comparator::Greater | Comparator::GreaterOrEqual)
+    }
+
+    fn is_upper_bound(self) -> bool {
+        matches!(self, Comparator::Less | Comparator::LessOrEqual)
+    }
+}
+
+impl VersRange {
+    /// Parses a range of the form `vers:<scheme>/<constraint>|<constraint>|...`, where each
+    /// constraint is a version, optionally preceded by one of `=`, `!=`, `<`, `<=`, `>` or
+    /// `>=`, or `*` on its own to match every version
+    /// ```
+    /// use cyclonedx_bom::models::vulnerability_target::VersRange;
+    ///
+    /// let range = VersRange::parse("vers:npm/1.2.3|>=2.0.0|<5.0.0")?;
+    /// assert!(range.contains("1.2.3"));
+    /// assert!(range.contains("4.9.9"));
+    /// assert!(!range.contains("1.2.4"));
+    /// # Ok::<(), cyclonedx_bom::models::vulnerability_target::VersRangeError>(())
+    /// ```
+    pub fn parse(range: &str) -> Result<Self, VersRangeError> {
+        let (scheme, constraints) = range
+            .strip_prefix("vers:")
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or_else(|| VersRangeError::InvalidFormat(range.to_string()))?;
+        if scheme.is_empty() {
+            return Err(VersRangeError::InvalidFormat(range.to_string()));
+        }
+
+        let constraints: String = constraints.chars().filter(|c| !c.is_whitespace()).collect();
+        let constraints = match constraints.as_str() {
+            "*" => vec![VersConstraint::Any],
+            constraints => constraints
+                .split('|')
+                .map(parse_constraint)
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Self {
+            range: range.to_string(),
+            scheme: scheme.to_string(),
+            constraints,
+        })
+    }
+
+    /// The versioning scheme, e.g. `npm`
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// The range as it was parsed
+    pub fn as_str(&self) -> &str {
+        &self.range
+    }
+
+    /// Whether `version` is within the range, checked as described in the vers specification.
+    ///
+    /// Versions are compared segment by segment, splitting them where digits and letters meet
+    /// and at any other character. Numbers are compared by value and text case-insensitively,
+    /// and a version followed by text, like `1.0.0-beta`, is a pre-release of that version.
+    /// This fits the common schemes, but not every rule of each of them.
+    pub fn contains(&self, version: &str) -> bool {
+        let mut bounds = Vec::new();
+        for constraint in &self.constraints {
+            match constraint {
+                VersConstraint::Any => return true,
+                VersConstraint::Compare(comparator, bound) => {
+                    let ordering = compare_versions(version, bound);
+                    match comparator {
+                        Comparator::Equal if ordering == Ordering::Equal => return true,
+                        Comparator::NotEqual if ordering == Ordering::Equal => return false,
+                        Comparator::Equal | Comparator::NotEqual => {}
+                        comparator => bounds.push((*comparator, bound.as_str(), ordering)),
+                    }
+                }
+            }
+        }
+
+        bounds.sort_by(|(_, a, _), (_, b, _)| compare_versions(a, b));
+        let matches =
+            |(comparator, _, ordering): (Comparator, &str, Ordering)| comparator.matches(ordering);
+        match bounds[..] {
+            [] => false,
+            [bound] => matches(bound),
+            _ => bounds.windows(2).enumerate().any(|(index, pair)| {
+                let (current, next) = (pair[0], pair[1]);
+                (index == 0 && current.0.is_upper_bound() && matches(current))
+                    || (index == bounds.len() - 2 && next.0.is_lower_bound() && matches(next))
+                    || (current.0.is_lower_bound()
+                        && next.0.is_upper_bound()
+                        && matches(current)
+                        && matches(next))
+            }),
+        }
+    }
+}
+
+fn parse_constraint(constraint: &str) -> Result<VersConstraint, VersRangeError> {
+    let (comparator, version) = [
+        ("!=", Comparator::NotEqual),
+        ("<=", Comparator::LessOrEqual),
+        (">=", Comparator::GreaterOrEqual),
+        ("<", Comparator::Less),
+        (">", Comparator::Greater),
+        ("=", Comparator::Equal),
+    ]
+    .into_iter()
+    .find_map(|(prefix, comparator)| {
+        constraint
+            .strip_prefix(prefix)
+            .map(|version| (comparator, version))
+    })
+    .unwrap_or((Comparator::Equal, constraint));
+
+    if version.is_empty() || version.contains(['<', '>', '=', '!', '*']) {
+        return Err(VersRangeError::InvalidConstraint(constraint.to_string()));
+    }
+    Ok(VersConstraint::Compare(comparator, version.to_string()))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum VersionSegment<'a> {
+    Number(&'a str),
+    Text(&'a str),
+}
+
+fn version_segments(version: &str) -> Vec<VersionSegment<'_>> {
+    let mut segments = Vec::new();
+    let mut start = None;
+    for (index, c) in version.char_indices().chain([(version.len(), '.')]) {
+        if let Some(segment_start) = start {
+            let segment = &version[segment_start..index];
+            let is_number = segment.starts_with(|c: char| c.is_ascii_digit());
+            if !c.is_ascii_alphanumeric() || c.is_ascii_digit() != is_number {
+                segments.push(if is_number {
+                    VersionSegment::Number(segment.trim_start_matches('0'))
+                } else {
+                    VersionSegment::Text(segment)
+                });
+                start = None;
+            }
+        }
+        if start.is_none() && c.is_ascii_alphanumeric() {
+            start = Some(index);
+        }
+    }
+    segments
+}
+
+/// Compares versions as described in [VersRange::contains]
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let (a, b) = (version_segments(a), version_segments(b));
+    for index in 0..a.len().max(b.len()) {
+        let ordering = match (a.get(index), b.get(index)) {
+            (Some(VersionSegment::Number(a)), Some(VersionSegment::Number(b))) => {
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+            (Some(VersionSegment::Text(a)), Some(VersionSegment::Text(b))) => {
+                a.to_ascii_lowercase().cmp(&b.to_ascii_lowercase())
+            }
+            (Some(VersionSegment::Number(_)), Some(VersionSegment::Text(_))) => Ordering::Greater,
+            (Some(VersionSegment::Text(_)), Some(VersionSegment::Number(_))) => Ordering::Less,
+            (Some(segment), None) => compare_to_missing(segment),
+            (None, Some(segment)) => compare_to_missing(segment).reverse(),
+            (None, None) => Ordering::Equal,
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compares a segment with the end of a shorter version, where a zero is the same as the
+/// end, e.g. in `1.0` and `1.0.0`, and text makes a pre-release, e.g. in `1.0` and `1.0-rc1`
+fn compare_to_missing(segment: &VersionSegment) -> Ordering {
+    match segment {
+        VersionSegment::Number("") => Ordering::Equal,
+        VersionSegment::Number(_) => Ordering::Greater,
+        VersionSegment::Text(_) => Ordering::Less,
+    }
+}
+
+impl FromStr for VersRange {
+    type Err = VersRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for VersRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.range)
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum VersRangeError {
+    #[error("Expected a range of the form vers:<scheme>/<constraints>, got '{0}'")]
+    InvalidFormat(String),
+
+    #[error("Invalid constraint '{0}'")]
+    InvalidConstraint(String),
+}
+
 /// Specifies if a vulnerability affects a component or service.
 ///
 /// Defined via the [XML schema](https://cyclonedx.org/docs/1.4/xml/#type_impactAnalysisAffectedStatusType)
@@ -342,4 +611,77 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn vers_ranges_should_contain_the_versions_they_describe() {
+        let range = VersRange::parse("vers:npm/1.2.3|>=2.0.0|<5.0.0").expect("Failed to parse");
+        assert_eq!(range.scheme(), "npm");
+        assert_eq!(range.to_string(), "vers:npm/1.2.3|>=2.0.0|<5.0.0");
+        for (version, contained) in [
+            ("1.2.3", true),
+            ("1.2.4", false),
+            ("1.9.9", false),
+            ("2.0.0", true),
+            ("2.0.0-beta.1", false),
+            ("4.10.0", true),
+            ("5.0.0", false),
+        ] {
+            assert_eq!(range.contains(version), contained, "{version}");
+        }
+
+        for (range, version, contained) in [
+            ("vers:cargo/*", "0.1.0", true),
+            ("vers:cargo/<1.0.0|>=2.0.0", "0.9.0", true),
+            ("vers:cargo/<1.0.0|>=2.0.0", "1.5.0", false),
+            ("vers:cargo/<1.0.0|>=2.0.0", "2.1.0", true),
+            ("vers:cargo/>=1.0.0|<2.0.0|!=1.5.0", "1.5.0", false),
+            ("vers:cargo/>=1.0.0|<2.0.0|!=1.5.0", "1.5.1", true),
+            ("vers:cargo/>1.0.0", "1.0", false),
+            ("vers:cargo/<= 1.0.0-rc.2", "1.0.0-rc.10", false),
+            ("vers:cargo/<= 1.0.0-rc.2", "1.0.0-RC.1", true),
+            ("vers:cargo/!=1.0.0", "1.0.1", false),
+        ] {
+            let parsed = VersRange::parse(range).expect("Failed to parse");
+            assert_eq!(parsed.contains(version), contained, "{range} {version}");
+        }
+    }
+
+    #[test]
+    fn invalid_vers_ranges_should_not_parse() {
+        for (range, error) in [
+            (
+                "npm/1.2.3",
+                VersRangeError::InvalidFormat("npm/1.2.3".to_string()),
+            ),
+            (
+                "vers:/1.2.3",
+                VersRangeError::InvalidFormat("vers:/1.2.3".to_string()),
+            ),
+            (
+                "vers:npm/1.2.3||2.0.0",
+                VersRangeError::InvalidConstraint("".to_string()),
+            ),
+            (
+                "vers:npm/>=<2.0.0",
+                VersRangeError::InvalidConstraint(">=<2.0.0".to_string()),
+            ),
+            (
+                "vers:npm/*|2.0.0",
+                VersRangeError::InvalidConstraint("*".to_string()),
+            ),
+        ] {
+            assert_eq!(VersRange::parse(range), Err(error), "{range}");
+        }
+    }
+
+    #[test]
+    fn version_ranges_should_contain_matching_versions() {
+        assert_eq!(VersionRange::new("1.0").contains("1.0.0"), Some(true));
+        assert_eq!(VersionRange::new("1.0").contains("1.1"), Some(false));
+        assert_eq!(
+            VersionRange::new("vers:npm/>=1.0.0").contains("1.1"),
+            Some(true)
+        );
+        assert_eq!(VersionRange::new("vers:npm").contains("1.1"), None);
+    }
 }
@@ -18,3 +18,5 @@
 
 pub(crate) mod v1_3;
 pub(crate) mod v1_4;
+pub(crate) mod v1_5;
+pub(crate) mod v1_6;
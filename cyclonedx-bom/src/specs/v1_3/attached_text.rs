@@ -48,7 +48,7 @@ impl From<models::attached_text::AttachedText> for AttachedText {
 impl From<AttachedText> for models::attached_text::AttachedText {
     fn from(other: AttachedText) -> Self {
         Self {
-            content_type: other.content_type.map(NormalizedString::new_unchecked),
+            content_type: other.content_type.map(NormalizedString::new),
             encoding: other
                 .encoding
                 .map(models::attached_text::Encoding::new_unchecked),
@@ -19,19 +19,24 @@
 use crate::errors::BomError;
 use crate::models::bom::SpecVersion;
 use crate::{
-    models::{self},
-    utilities::{convert_optional, try_convert_optional},
+    models::{self, foreign_extension::ForeignExtension},
+    utilities::{convert_optional, convert_vec, deserialize_lenient_u32, try_convert_optional},
     xml::{
-        expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, FromXmlDocument,
-        FromXmlType,
+        expected_namespace_or_error, optional_attribute, read_foreign_extension,
+        read_lax_validation_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        write_foreign_extension, FromXml, FromXmlDocument, FromXmlSections, FromXmlType,
+        XmlRecovery,
     },
 };
 use crate::{
     specs::v1_3::{
-        component::Components, composition::Compositions, dependency::Dependencies,
-        external_reference::ExternalReferences, metadata::Metadata, property::Properties,
-        service::Services,
+        component::{Component, Components},
+        composition::{Composition, Compositions},
+        dependency::{Dependencies, Dependency},
+        external_reference::{ExternalReference, ExternalReferences},
+        metadata::Metadata,
+        property::{Properties, Property},
+        service::{Service, Services},
     },
     xml::ToXml,
 };
@@ -44,6 +49,7 @@ use xml::{reader, writer::XmlEvent};
 pub(crate) struct Bom {
     bom_format: BomFormat,
     spec_version: SpecVersion,
+    #[serde(default, deserialize_with = "deserialize_lenient_u32")]
     version: Option<u32>,
     serial_number: Option<UrnUuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -60,6 +66,8 @@ pub(crate) struct Bom {
     compositions: Option<Compositions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     properties: Option<Properties>,
+    #[serde(skip)]
+    foreign_extensions: Vec<ForeignExtension>,
 }
 
 impl TryFrom<models::bom::Bom> for Bom {
@@ -78,6 +86,7 @@ impl TryFrom<models::bom::Bom> for Bom {
             dependencies: convert_optional(other.dependencies),
             compositions: convert_optional(other.compositions),
             properties: convert_optional(other.properties),
+            foreign_extensions: other.foreign_extensions,
         })
     }
 }
@@ -96,6 +105,7 @@ impl From<Bom> for models::bom::Bom {
             properties: convert_optional(other.properties),
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: other.foreign_extensions,
         }
     }
 }
@@ -153,6 +163,10 @@ impl ToXml for Bom {
             properties.write_xml_element(writer)?;
         }
 
+        for extension in &self.foreign_extensions {
+            write_foreign_extension(writer, extension)?;
+        }
+
         writer
             .write(XmlEvent::end_element())
             .map_err(to_xml_write_error(BOM_TAG))?;
@@ -215,6 +229,7 @@ impl FromXmlDocument for Bom {
         let mut dependencies: Option<Dependencies> = None;
         let mut compositions: Option<Compositions> = None;
         let mut properties: Option<Properties> = None;
+        let mut foreign_extensions: Vec<ForeignExtension> = Vec::new();
 
         let mut got_end_tag = false;
         while !got_end_tag {
@@ -283,7 +298,20 @@ impl FromXmlDocument for Bom {
                         &attributes,
                     )?)
                 }
-                // lax validation of any elements from a different schema
+                // elements from a different schema, kept in case they are to be preserved
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } if name.namespace.as_deref() != Some("http://cyclonedx.org/schema/bom/1.3") => {
+                    foreign_extensions.push(read_foreign_extension(
+                        event_reader,
+                        &name,
+                        &attributes,
+                        &namespace,
+                    )?)
+                }
+                // lax validation of any other elements
                 reader::XmlEvent::StartElement { name, .. } => {
                     read_lax_validation_tag(event_reader, &name)?
                 }
@@ -313,10 +341,75 @@ impl FromXmlDocument for Bom {
             dependencies,
             compositions,
             properties,
+            foreign_extensions,
         })
     }
 }
 
+const COMPONENT_TAG: &str = "component";
+const SERVICE_TAG: &str = "service";
+const REFERENCE_TAG: &str = "reference";
+const DEPENDENCY_TAG: &str = "dependency";
+const COMPOSITION_TAG: &str = "composition";
+const PROPERTY_TAG: &str = "property";
+
+impl FromXmlSections for Bom {
+    const VERSION: &'static str = "1.3";
+
+    fn read_xml_section<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+        bom: &mut models::bom::Bom,
+        recovery: &mut XmlRecovery,
+    ) -> Result<bool, crate::errors::XmlReadError> {
+        match name.local_name.as_str() {
+            METADATA_TAG => {
+                let metadata = recovery.read(event_reader, |event_reader| {
+                    Metadata::read_xml_element(event_reader, name, attributes)
+                })?;
+                bom.metadata = convert_optional(metadata);
+            }
+            COMPONENTS_TAG => {
+                let components: Vec<Component> =
+                    recovery.read_lax_validation_list(event_reader, name, COMPONENT_TAG)?;
+                bom.components = Some(models::component::Components(convert_vec(components)));
+            }
+            SERVICES_TAG => {
+                let services: Vec<Service> =
+                    recovery.read_lax_validation_list(event_reader, name, SERVICE_TAG)?;
+                bom.services = Some(models::service::Services(convert_vec(services)));
+            }
+            EXTERNAL_REFERENCES_TAG => {
+                let references: Vec<ExternalReference> =
+                    recovery.read_list(event_reader, name, REFERENCE_TAG)?;
+                bom.external_references = Some(models::external_reference::ExternalReferences(
+                    convert_vec(references),
+                ));
+            }
+            DEPENDENCIES_TAG => {
+                let dependencies: Vec<Dependency> =
+                    recovery.read_list(event_reader, name, DEPENDENCY_TAG)?;
+                bom.dependencies =
+                    Some(models::dependency::Dependencies(convert_vec(dependencies)));
+            }
+            COMPOSITIONS_TAG => {
+                let compositions: Vec<Composition> =
+                    recovery.read_lax_validation_list(event_reader, name, COMPOSITION_TAG)?;
+                bom.compositions =
+                    Some(models::composition::Compositions(convert_vec(compositions)));
+            }
+            PROPERTIES_TAG => {
+                let properties: Vec<Property> =
+                    recovery.read_lax_validation_list(event_reader, name, PROPERTY_TAG)?;
+                bom.properties = Some(models::property::Properties(convert_vec(properties)));
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 enum BomFormat {
     CycloneDX,
@@ -370,6 +463,7 @@ pub(crate) mod test {
             dependencies: None,
             compositions: None,
             properties: None,
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -386,6 +480,7 @@ pub(crate) mod test {
             dependencies: Some(example_dependencies()),
             compositions: Some(example_compositions()),
             properties: Some(example_properties()),
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -402,6 +497,7 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -779,7 +875,15 @@ pub(crate) mod test {
 </bom>
 "#.trim_start();
         let actual: Bom = read_document_from_string(input);
-        let expected = full_bom_example();
+        let expected = Bom {
+            foreign_extensions: vec![ForeignExtension {
+                name: "example:laxValidation".to_string(),
+                namespace: Some("https://example.com".to_string()),
+                attributes: Vec::new(),
+                inner_xml: r#"<example:innerElement id="test" />"#.to_string(),
+            }],
+            ..full_bom_example()
+        };
         assert_eq!(actual, expected);
     }
 }
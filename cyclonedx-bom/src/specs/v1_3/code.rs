@@ -112,11 +112,11 @@ impl From<models::code::Commit> for Commit {
 impl From<Commit> for models::code::Commit {
     fn from(other: Commit) -> Self {
         Self {
-            uid: other.uid.map(NormalizedString::new_unchecked),
+            uid: other.uid.map(NormalizedString::new),
             url: other.url.map(Uri),
             author: convert_optional(other.author),
             committer: convert_optional(other.committer),
-            message: other.message.map(NormalizedString::new_unchecked),
+            message: other.message.map(NormalizedString::new),
         }
     }
 }
@@ -256,8 +256,8 @@ impl From<IdentifiableAction> for models::code::IdentifiableAction {
     fn from(other: IdentifiableAction) -> Self {
         Self {
             timestamp: other.timestamp.map(DateTime),
-            name: other.name.map(NormalizedString::new_unchecked),
-            email: other.email.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new),
+            email: other.email.map(NormalizedString::new),
         }
     }
 }
@@ -647,9 +647,9 @@ impl From<Issue> for models::code::Issue {
     fn from(other: Issue) -> Self {
         Self {
             issue_type: models::code::IssueClassification::new_unchecked(other.issue_type),
-            id: other.id.map(NormalizedString::new_unchecked),
-            name: other.name.map(NormalizedString::new_unchecked),
-            description: other.description.map(NormalizedString::new_unchecked),
+            id: other.id.map(NormalizedString::new),
+            name: other.name.map(NormalizedString::new),
+            description: other.description.map(NormalizedString::new),
             source: convert_optional(other.source),
             references: other
                 .references
@@ -796,7 +796,7 @@ impl From<models::code::Source> for Source {
 impl From<Source> for models::code::Source {
     fn from(other: Source) -> Self {
         Self {
-            name: other.name.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new),
             url: other.url.map(Uri),
         }
     }
@@ -35,8 +35,8 @@ use crate::{
     xml::{
         attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, FromXmlType,
-        ToInnerXml, ToXml,
+        to_xml_write_error, unexpected_element_error, write_cdata, write_simple_tag, FromXml,
+        FromXmlType, ToInnerXml, ToXml,
     },
 };
 use crate::{
@@ -206,16 +206,16 @@ impl From<Component> for models::component::Component {
             mime_type: other.mime_type.map(|m| models::component::MimeType(m.0)),
             bom_ref: other.bom_ref,
             supplier: convert_optional(other.supplier),
-            author: other.author.map(NormalizedString::new_unchecked),
-            publisher: other.publisher.map(NormalizedString::new_unchecked),
-            group: other.group.map(NormalizedString::new_unchecked),
-            name: NormalizedString::new_unchecked(other.name),
-            version: Some(NormalizedString::new_unchecked(other.version)),
-            description: other.description.map(NormalizedString::new_unchecked),
+            author: other.author.map(NormalizedString::new),
+            publisher: other.publisher.map(NormalizedString::new),
+            group: other.group.map(NormalizedString::new),
+            name: NormalizedString::new(other.name),
+            version: Some(NormalizedString::new(other.version)),
+            description: other.description.map(NormalizedString::new),
             scope: other.scope.map(models::component::Scope::new_unchecked),
             hashes: convert_optional(other.hashes),
             licenses: convert_optional(other.licenses),
-            copyright: other.copyright.map(NormalizedString::new_unchecked),
+            copyright: other.copyright.map(NormalizedString::new),
             cpe: convert_optional(other.cpe),
             purl: other.purl.map(Purl),
             swid: convert_optional(other.swid),
@@ -225,7 +225,10 @@ impl From<Component> for models::component::Component {
             properties: convert_optional(other.properties),
             components: convert_optional(other.components),
             evidence: convert_optional(other.evidence),
-            signature: None, // Not supported in 1.3
+            signature: None,         // Not supported in 1.3
+            tags: None,              // Not supported in 1.3
+            omnibor_ids: Vec::new(), // Not supported in 1.3
+            swhids: Vec::new(),      // Not supported in 1.3
         }
     }
 }
@@ -523,10 +526,12 @@ impl FromXml for Component {
         let component_name = component_name.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: NAME_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
         let version = version.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: VERSION_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self {
@@ -1083,9 +1088,7 @@ impl ToXml for Copyright {
             .write(XmlEvent::start_element(TEXT_TAG))
             .map_err(to_xml_write_error(TEXT_TAG))?;
 
-        writer
-            .write(XmlEvent::cdata(&self.text))
-            .map_err(to_xml_write_error(TEXT_TAG))?;
+        write_cdata(writer, TEXT_TAG, &self.text)?;
 
         writer
             .write(XmlEvent::end_element())
@@ -1257,7 +1260,10 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             components: Some(corresponding_empty_components()),
             evidence: Some(corresponding_evidence()),
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
             signature: None,
+            tags: None,
         }
     }
 
@@ -1481,4 +1487,45 @@ pub(crate) mod test {
             Err(BomError::BomSerializationError(SpecVersion::V1_3, _))
         ));
     }
+
+    #[test]
+    fn it_should_read_children_in_any_order() {
+        let canonical = r#"
+<components>
+  <component type="library" bom-ref="bom ref">
+    <group>group</group>
+    <name>name</name>
+    <version>version</version>
+    <scope>required</scope>
+    <hashes>
+      <hash alg="SHA-256">hash value</hash>
+    </hashes>
+    <purl>purl</purl>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </component>
+</components>
+"#;
+        let reordered = r#"
+<components>
+  <component type="library" bom-ref="bom ref">
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <version>version</version>
+    <purl>purl</purl>
+    <hashes>
+      <hash alg="SHA-256">hash value</hash>
+    </hashes>
+    <name>name</name>
+    <scope>required</scope>
+    <group>group</group>
+  </component>
+</components>
+"#;
+        let expected: Components = read_element_from_string(canonical);
+        let actual: Components = read_element_from_string(reordered);
+        assert_eq!(actual, expected);
+    }
 }
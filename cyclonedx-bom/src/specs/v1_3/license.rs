@@ -178,6 +178,7 @@ impl FromXml for LicenseChoice {
             unexpected => Err(XmlReadError::UnexpectedElementReadError {
                 error: format!("Got unexpected element {:?}", unexpected),
                 element: "LicenseChoice".to_string(),
+                position: None,
             }),
         }
     }
@@ -210,6 +211,7 @@ impl From<License> for models::license::License {
             license_identifier: other.license_identifier.into(),
             text: convert_optional(other.text),
             url: other.url.map(Uri),
+            acknowledgement: None,
         }
     }
 }
@@ -281,6 +283,7 @@ impl FromXml for License {
                                 name.local_name, LICENSE_TAG
                             ),
                             element: LICENSE_TAG.to_string(),
+                            position: None,
                         });
                     }
                 }
@@ -310,6 +313,7 @@ impl FromXml for License {
             license_identifier.ok_or_else(|| XmlReadError::RequiredDataMissing {
                 required_field: format!("{} or {}", ID_TAG, NAME_TAG),
                 element: LICENSE_TAG.to_string(),
+                position: None,
             })?;
         Ok(Self {
             license_identifier,
@@ -340,7 +344,7 @@ impl From<LicenseIdentifier> for models::license::LicenseIdentifier {
     fn from(other: LicenseIdentifier) -> Self {
         match other {
             LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(SpdxIdentifier(spdx)),
-            LicenseIdentifier::Name(name) => Self::Name(NormalizedString::new_unchecked(name)),
+            LicenseIdentifier::Name(name) => Self::Name(NormalizedString::new(name)),
         }
     }
 }
@@ -405,6 +409,7 @@ impl FromXml for LicenseIdentifier {
             other => Err(XmlReadError::UnexpectedElementReadError {
                 error: format!("Got {} instead of \"name\" or \"id\"", other),
                 element: "license identifier".to_string(),
+                position: None,
             }),
         }
     }
@@ -443,6 +448,7 @@ pub(crate) mod test {
             )),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            acknowledgement: None,
         })
     }
 
@@ -462,6 +468,7 @@ pub(crate) mod test {
             ),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            acknowledgement: None,
         })
     }
 
@@ -53,9 +53,9 @@ impl From<models::organization::OrganizationalContact> for OrganizationalContact
 impl From<OrganizationalContact> for models::organization::OrganizationalContact {
     fn from(other: OrganizationalContact) -> Self {
         Self {
-            name: other.name.map(NormalizedString::new_unchecked),
-            email: other.email.map(NormalizedString::new_unchecked),
-            phone: other.phone.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new),
+            email: other.email.map(NormalizedString::new),
+            phone: other.phone.map(NormalizedString::new),
         }
     }
 }
@@ -171,7 +171,7 @@ impl From<models::organization::OrganizationalEntity> for OrganizationalEntity {
 impl From<OrganizationalEntity> for models::organization::OrganizationalEntity {
     fn from(other: OrganizationalEntity) -> Self {
         Self {
-            name: other.name.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new),
             url: other.url.map(|urls| urls.into_iter().map(Uri).collect()),
             contact: convert_optional_vec(other.contact),
         }
@@ -99,7 +99,7 @@ impl From<Property> for models::property::Property {
     fn from(other: Property) -> Self {
         Self {
             name: other.name,
-            value: NormalizedString::new_unchecked(other.value),
+            value: NormalizedString::new(other.value),
         }
     }
 }
@@ -146,10 +146,10 @@ impl From<Service> for models::service::Service {
         Self {
             bom_ref: other.bom_ref,
             provider: convert_optional(other.provider),
-            group: other.group.map(NormalizedString::new_unchecked),
-            name: NormalizedString::new_unchecked(other.name),
-            version: other.version.map(NormalizedString::new_unchecked),
-            description: other.description.map(NormalizedString::new_unchecked),
+            group: other.group.map(NormalizedString::new),
+            name: NormalizedString::new(other.name),
+            version: other.version.map(NormalizedString::new),
+            description: other.description.map(NormalizedString::new),
             endpoints: other
                 .endpoints
                 .map(|endpoints| endpoints.into_iter().map(Uri).collect()),
@@ -395,6 +395,7 @@ impl FromXml for Service {
         let name = service_name.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: NAME_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self {
@@ -436,7 +437,7 @@ impl From<DataClassification> for models::service::DataClassification {
     fn from(other: DataClassification) -> Self {
         Self {
             flow: models::service::DataFlowType::new_unchecked(&other.flow),
-            classification: NormalizedString::new_unchecked(other.classification),
+            classification: NormalizedString::new(other.classification),
         }
     }
 }
@@ -614,4 +615,43 @@ pub(crate) mod test {
         let expected = example_services();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_children_in_any_order() {
+        let canonical = r#"
+<services>
+  <service bom-ref="bom-ref">
+    <group>group</group>
+    <name>name</name>
+    <version>version</version>
+    <endpoints>
+      <endpoint>endpoint</endpoint>
+    </endpoints>
+    <authenticated>true</authenticated>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </service>
+</services>
+"#;
+        let reordered = r#"
+<services>
+  <service bom-ref="bom-ref">
+    <authenticated>true</authenticated>
+    <version>version</version>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <name>name</name>
+    <endpoints>
+      <endpoint>endpoint</endpoint>
+    </endpoints>
+    <group>group</group>
+  </service>
+</services>
+"#;
+        let expected: Services = read_element_from_string(canonical);
+        let actual: Services = read_element_from_string(reordered);
+        assert_eq!(actual, expected);
+    }
 }
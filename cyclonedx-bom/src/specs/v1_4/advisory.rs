@@ -101,7 +101,7 @@ impl From<models::advisory::Advisory> for Advisory {
 impl From<Advisory> for models::advisory::Advisory {
     fn from(other: Advisory) -> Self {
         Self {
-            title: other.title.map(NormalizedString::new_unchecked),
+            title: other.title.map(NormalizedString::new),
             url: Uri(other.url),
         }
     }
@@ -176,6 +176,7 @@ impl FromXml for Advisory {
         let url = url.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: URL_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self { title, url })
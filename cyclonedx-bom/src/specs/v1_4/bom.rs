@@ -17,19 +17,26 @@
  */
 
 use crate::{
-    models::{self, bom::SpecVersion},
-    utilities::convert_optional,
+    models::{self, bom::SpecVersion, foreign_extension::ForeignExtension},
+    utilities::{convert_optional, convert_vec, deserialize_lenient_u32},
     xml::{
-        expected_namespace_or_error, optional_attribute, read_lax_validation_tag,
-        to_xml_read_error, to_xml_write_error, unexpected_element_error, FromXml, FromXmlDocument,
-        FromXmlType,
+        expected_namespace_or_error, optional_attribute, read_foreign_extension,
+        read_lax_validation_tag, to_xml_read_error, to_xml_write_error, unexpected_element_error,
+        write_foreign_extension, FromXml, FromXmlDocument, FromXmlSections, FromXmlType,
+        XmlRecovery,
     },
 };
 use crate::{
     specs::v1_4::{
-        component::Components, composition::Compositions, dependency::Dependencies,
-        external_reference::ExternalReferences, metadata::Metadata, property::Properties,
-        service::Services, signature::Signature, vulnerability::Vulnerabilities,
+        component::{Component, Components},
+        composition::{Composition, Compositions},
+        dependency::{Dependencies, Dependency},
+        external_reference::{ExternalReferences, VulnerabilityReference},
+        metadata::Metadata,
+        property::{Properties, Property},
+        service::{Service, Services},
+        signature::Signature,
+        vulnerability::{Vulnerabilities, Vulnerability},
     },
     xml::ToXml,
 };
@@ -41,6 +48,7 @@ use xml::{reader, writer::XmlEvent};
 pub(crate) struct Bom {
     bom_format: BomFormat,
     spec_version: SpecVersion,
+    #[serde(default, deserialize_with = "deserialize_lenient_u32")]
     version: Option<u32>,
     serial_number: Option<UrnUuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,6 +69,8 @@ pub(crate) struct Bom {
     vulnerabilities: Option<Vulnerabilities>,
     #[serde(skip_serializing_if = "Option::is_none")]
     signature: Option<Signature>,
+    #[serde(skip)]
+    foreign_extensions: Vec<ForeignExtension>,
 }
 
 impl From<models::bom::Bom> for Bom {
@@ -79,6 +89,7 @@ impl From<models::bom::Bom> for Bom {
             properties: convert_optional(other.properties),
             vulnerabilities: convert_optional(other.vulnerabilities),
             signature: convert_optional(other.signature),
+            foreign_extensions: other.foreign_extensions,
         }
     }
 }
@@ -97,6 +108,7 @@ impl From<Bom> for models::bom::Bom {
             properties: convert_optional(other.properties),
             vulnerabilities: convert_optional(other.vulnerabilities),
             signature: convert_optional(other.signature),
+            foreign_extensions: other.foreign_extensions,
         }
     }
 }
@@ -158,6 +170,10 @@ impl ToXml for Bom {
             vulnerabilities.write_xml_element(writer)?;
         }
 
+        for extension in &self.foreign_extensions {
+            write_foreign_extension(writer, extension)?;
+        }
+
         writer
             .write(XmlEvent::end_element())
             .map_err(to_xml_write_error(BOM_TAG))?;
@@ -224,6 +240,7 @@ impl FromXmlDocument for Bom {
         let mut properties: Option<Properties> = None;
         let mut vulnerabilities: Option<Vulnerabilities> = None;
         let mut signature: Option<Signature> = None;
+        let mut foreign_extensions: Vec<ForeignExtension> = Vec::new();
 
         let mut got_end_tag = false;
         while !got_end_tag {
@@ -311,7 +328,20 @@ impl FromXmlDocument for Bom {
                     )?)
                 }
 
-                // lax validation of any elements from a different schema
+                // elements from a different schema, kept in case they are to be preserved
+                reader::XmlEvent::StartElement {
+                    name,
+                    attributes,
+                    namespace,
+                } if name.namespace.as_deref() != Some("http://cyclonedx.org/schema/bom/1.4") => {
+                    foreign_extensions.push(read_foreign_extension(
+                        event_reader,
+                        &name,
+                        &attributes,
+                        &namespace,
+                    )?)
+                }
+                // lax validation of any other elements
                 reader::XmlEvent::StartElement { name, .. } => {
                     read_lax_validation_tag(event_reader, &name)?
                 }
@@ -343,10 +373,89 @@ impl FromXmlDocument for Bom {
             properties,
             vulnerabilities,
             signature,
+            foreign_extensions,
         })
     }
 }
 
+const COMPONENT_TAG: &str = "component";
+const SERVICE_TAG: &str = "service";
+const REFERENCE_TAG: &str = "reference";
+const DEPENDENCY_TAG: &str = "dependency";
+const COMPOSITION_TAG: &str = "composition";
+const PROPERTY_TAG: &str = "property";
+const VULNERABILITY_TAG: &str = "vulnerability";
+
+impl FromXmlSections for Bom {
+    const VERSION: &'static str = "1.4";
+
+    fn read_xml_section<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        name: &xml::name::OwnedName,
+        attributes: &[xml::attribute::OwnedAttribute],
+        bom: &mut models::bom::Bom,
+        recovery: &mut XmlRecovery,
+    ) -> Result<bool, crate::errors::XmlReadError> {
+        match name.local_name.as_str() {
+            METADATA_TAG => {
+                let metadata = recovery.read(event_reader, |event_reader| {
+                    Metadata::read_xml_element(event_reader, name, attributes)
+                })?;
+                bom.metadata = convert_optional(metadata);
+            }
+            COMPONENTS_TAG => {
+                let components: Vec<Component> =
+                    recovery.read_lax_validation_list(event_reader, name, COMPONENT_TAG)?;
+                bom.components = Some(models::component::Components(convert_vec(components)));
+            }
+            SERVICES_TAG => {
+                let services: Vec<Service> =
+                    recovery.read_lax_validation_list(event_reader, name, SERVICE_TAG)?;
+                bom.services = Some(models::service::Services(convert_vec(services)));
+            }
+            EXTERNAL_REFERENCES_TAG => {
+                let references: Vec<VulnerabilityReference> =
+                    recovery.read_list(event_reader, name, REFERENCE_TAG)?;
+                bom.external_references = Some(models::external_reference::ExternalReferences(
+                    convert_vec(references),
+                ));
+            }
+            DEPENDENCIES_TAG => {
+                let dependencies: Vec<Dependency> =
+                    recovery.read_list(event_reader, name, DEPENDENCY_TAG)?;
+                bom.dependencies =
+                    Some(models::dependency::Dependencies(convert_vec(dependencies)));
+            }
+            COMPOSITIONS_TAG => {
+                let compositions: Vec<Composition> =
+                    recovery.read_lax_validation_list(event_reader, name, COMPOSITION_TAG)?;
+                bom.compositions =
+                    Some(models::composition::Compositions(convert_vec(compositions)));
+            }
+            PROPERTIES_TAG => {
+                let properties: Vec<Property> =
+                    recovery.read_lax_validation_list(event_reader, name, PROPERTY_TAG)?;
+                bom.properties = Some(models::property::Properties(convert_vec(properties)));
+            }
+            VULNERABILITIES_TAG => {
+                let vulnerabilities: Vec<Vulnerability> =
+                    recovery.read_lax_validation_list(event_reader, name, VULNERABILITY_TAG)?;
+                bom.vulnerabilities = Some(models::vulnerability::Vulnerabilities(convert_vec(
+                    vulnerabilities,
+                )));
+            }
+            SIGNATURE_TAG => {
+                let signature = recovery.read(event_reader, |event_reader| {
+                    Signature::read_xml_element(event_reader, name, attributes)
+                })?;
+                bom.signature = convert_optional(signature);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 enum BomFormat {
     CycloneDX,
@@ -405,6 +514,7 @@ pub(crate) mod test {
             properties: None,
             vulnerabilities: None,
             signature: None,
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -423,6 +533,7 @@ pub(crate) mod test {
             properties: Some(example_properties()),
             vulnerabilities: Some(example_vulnerabilities()),
             signature: Some(example_signature()),
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -439,6 +550,7 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             vulnerabilities: Some(corresponding_vulnerabilities()),
             signature: Some(corresponding_signature()),
+            foreign_extensions: Vec::new(),
         }
     }
 
@@ -480,6 +592,17 @@ pub(crate) mod test {
         assert_eq!(spec, full_bom_example());
     }
 
+    #[test]
+    fn it_should_find_the_vulnerabilities_affecting_a_bom_ref() {
+        let model = corresponding_internal_model();
+
+        let vulnerabilities = model.vulnerabilities_for("ref");
+        assert_eq!(vulnerabilities.len(), 1);
+        assert_eq!(vulnerabilities[0].bom_ref.as_deref(), Some("bom-ref"));
+
+        assert!(model.vulnerabilities_for("bom-ref").is_empty());
+    }
+
     #[test]
     fn it_should_deserialize_from_xml() {
         let input = r#"
@@ -938,7 +1061,15 @@ pub(crate) mod test {
 </bom>
 "#.trim_start();
         let actual: Bom = read_document_from_string(input);
-        let expected = full_bom_example();
+        let expected = Bom {
+            foreign_extensions: vec![ForeignExtension {
+                name: "example:laxValidation".to_string(),
+                namespace: Some("https://example.com".to_string()),
+                attributes: Vec::new(),
+                inner_xml: r#"<example:innerElement id="test" />"#.to_string(),
+            }],
+            ..full_bom_example()
+        };
         assert_eq!(actual, expected);
     }
 }
@@ -30,8 +30,8 @@ use crate::{
     xml::{
         attribute_or_error, optional_attribute, read_boolean_tag, read_lax_validation_list_tag,
         read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
-        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, FromXmlType,
-        ToInnerXml, ToXml,
+        to_xml_write_error, unexpected_element_error, write_cdata, write_simple_tag, FromXml,
+        FromXmlType, ToInnerXml, ToXml,
     },
 };
 use crate::{
@@ -195,16 +195,16 @@ impl From<Component> for models::component::Component {
             mime_type: other.mime_type.map(|m| models::component::MimeType(m.0)),
             bom_ref: other.bom_ref,
             supplier: convert_optional(other.supplier),
-            author: other.author.map(NormalizedString::new_unchecked),
-            publisher: other.publisher.map(NormalizedString::new_unchecked),
-            group: other.group.map(NormalizedString::new_unchecked),
-            name: NormalizedString::new_unchecked(other.name),
-            version: other.version.map(NormalizedString::new_unchecked),
-            description: other.description.map(NormalizedString::new_unchecked),
+            author: other.author.map(NormalizedString::new),
+            publisher: other.publisher.map(NormalizedString::new),
+            group: other.group.map(NormalizedString::new),
+            name: NormalizedString::new(other.name),
+            version: other.version.map(NormalizedString::new),
+            description: other.description.map(NormalizedString::new),
             scope: other.scope.map(models::component::Scope::new_unchecked),
             hashes: convert_optional(other.hashes),
             licenses: convert_optional(other.licenses),
-            copyright: other.copyright.map(NormalizedString::new_unchecked),
+            copyright: other.copyright.map(NormalizedString::new),
             cpe: convert_optional(other.cpe),
             purl: other.purl.map(Purl),
             swid: convert_optional(other.swid),
@@ -215,6 +215,9 @@ impl From<Component> for models::component::Component {
             components: convert_optional(other.components),
             evidence: convert_optional(other.evidence),
             signature: convert_optional(other.signature),
+            tags: None,              // Not supported in 1.4
+            omnibor_ids: Vec::new(), // Not supported in 1.4
+            swhids: Vec::new(),      // Not supported in 1.4
         }
     }
 }
@@ -550,6 +553,7 @@ impl FromXml for Component {
         let component_name = component_name.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: NAME_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self {
@@ -1105,9 +1109,7 @@ impl ToXml for Copyright {
             .write(XmlEvent::start_element(TEXT_TAG))
             .map_err(to_xml_write_error(TEXT_TAG))?;
 
-        writer
-            .write(XmlEvent::cdata(&self.text))
-            .map_err(to_xml_write_error(TEXT_TAG))?;
+        write_cdata(writer, TEXT_TAG, &self.text)?;
 
         writer
             .write(XmlEvent::end_element())
@@ -1280,7 +1282,10 @@ pub(crate) mod test {
             properties: Some(corresponding_properties()),
             components: Some(corresponding_empty_components()),
             evidence: Some(corresponding_evidence()),
+            omnibor_ids: Vec::new(),
+            swhids: Vec::new(),
             signature: Some(corresponding_signature()),
+            tags: None,
         }
     }
 
@@ -1496,4 +1501,106 @@ pub(crate) mod test {
         let expected = example_components();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_children_in_any_order() {
+        let canonical = r#"
+<components>
+  <component type="library" bom-ref="bom ref">
+    <group>group</group>
+    <name>name</name>
+    <version>version</version>
+    <scope>required</scope>
+    <hashes>
+      <hash alg="SHA-256">hash value</hash>
+    </hashes>
+    <purl>purl</purl>
+    <properties>
+      <property name="name">value</property>
+    </properties>
+  </component>
+</components>
+"#;
+        let reordered = r#"
+<components>
+  <component type="library" bom-ref="bom ref">
+    <properties>
+      <property name="name">value</property>
+    </properties>
+    <version>version</version>
+    <purl>purl</purl>
+    <hashes>
+      <hash alg="SHA-256">hash value</hash>
+    </hashes>
+    <name>name</name>
+    <scope>required</scope>
+    <group>group</group>
+  </component>
+</components>
+"#;
+        let expected: Components = read_element_from_string(canonical);
+        let actual: Components = read_element_from_string(reordered);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_round_trip_each_scope_through_the_model() {
+        for (scope, expected) in [
+            ("required", models::component::Scope::Required),
+            ("optional", models::component::Scope::Optional),
+            ("excluded", models::component::Scope::Excluded),
+            (
+                "sometimes",
+                models::component::Scope::UnknownScope("sometimes".to_string()),
+            ),
+        ] {
+            let xml = format!(
+                r#"<component type="library"><name>name</name><scope>{}</scope></component>"#,
+                scope
+            );
+            let component: Component = read_element_from_string(xml);
+            let model = models::component::Component::from(component);
+            assert_eq!(model.scope, Some(expected.clone()));
+            let output = write_element_to_string(Component::from(model));
+            assert!(output.contains(&format!("<scope>{}</scope>", scope)));
+
+            let json = serde_json::json!({"type": "library", "name": "name", "scope": scope});
+            let component: Component = serde_json::from_value(json.clone()).unwrap();
+            let model = models::component::Component::from(component);
+            assert_eq!(model.scope, Some(expected));
+            let output = serde_json::to_value(Component::from(model)).unwrap();
+            assert_eq!(output, json);
+        }
+    }
+
+    #[test]
+    fn it_should_round_trip_copyright_texts_with_markup_characters() {
+        let expected = CopyrightTexts(vec![Copyright {
+            text: "Copyright <c> 2024 Smith & Sons ]]> all rights reserved".to_string(),
+        }]);
+
+        let xml_output = write_element_to_string(CopyrightTexts(vec![Copyright {
+            text: expected.0[0].text.clone(),
+        }]));
+        let actual: CopyrightTexts = read_element_from_string(&xml_output);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_join_text_and_cdata_sections_of_copyright_texts() {
+        let input = r#"
+<copyright>
+  <text>Copyright &lt;c&gt;<![CDATA[ 2024 Smith & Sons]]></text>
+</copyright>
+"#;
+        let actual: CopyrightTexts = read_element_from_string(input);
+
+        assert_eq!(
+            actual,
+            CopyrightTexts(vec![Copyright {
+                text: "Copyright <c> 2024 Smith & Sons".to_string(),
+            }])
+        );
+    }
 }
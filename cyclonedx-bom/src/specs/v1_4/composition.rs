@@ -235,6 +235,7 @@ impl FromXml for Composition {
         let aggregate = aggregate.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: AGGREGATE_TAG.to_string(),
             element: COMPOSITION_TAG.to_string(),
+            position: None,
         })?;
 
         Ok(Self {
@@ -198,6 +198,7 @@ impl FromXml for VulnerabilityReference {
         let url = url.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: URL_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self {
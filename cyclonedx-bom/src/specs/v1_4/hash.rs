@@ -203,4 +203,19 @@ pub(crate) mod test {
         let expected = example_hashes();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_write_the_schema_spelling_of_the_algorithm() {
+        let input = r#"
+<hashes>
+  <hash alg="sha-256">hash value</hash>
+</hashes>
+"#;
+        let hashes: Hashes = read_element_from_string(input);
+        let model: models::hash::Hashes = hashes.into();
+        assert_eq!(model.0[0].alg, models::hash::HashAlgorithm::SHA256);
+
+        let xml_output = write_element_to_string(Hashes::from(model));
+        assert!(xml_output.contains(r#"alg="SHA-256""#));
+    }
 }
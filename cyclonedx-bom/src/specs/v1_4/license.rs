@@ -190,6 +190,7 @@ impl FromXml for LicenseChoice {
             unexpected => Err(XmlReadError::UnexpectedElementReadError {
                 error: format!("Got unexpected element {:?}", unexpected),
                 element: "LicenseChoice".to_string(),
+                position: None,
             }),
         }
     }
@@ -229,6 +230,7 @@ impl FromXml for Lic {
         let lic = lic.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: LICENSE_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(lic)
@@ -267,6 +269,7 @@ impl FromXml for Expr {
         let expr = expr.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: EXPRESSION_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(expr)
@@ -300,6 +303,7 @@ impl From<License> for models::license::License {
             license_identifier: other.license_identifier.into(),
             text: convert_optional(other.text),
             url: other.url.map(Uri),
+            acknowledgement: None,
         }
     }
 }
@@ -371,6 +375,7 @@ impl FromXml for License {
                                 name.local_name, LICENSE_TAG
                             ),
                             element: LICENSE_TAG.to_string(),
+                            position: None,
                         });
                     }
                 }
@@ -400,6 +405,7 @@ impl FromXml for License {
             license_identifier.ok_or_else(|| XmlReadError::RequiredDataMissing {
                 required_field: format!("{} or {}", ID_TAG, NAME_TAG),
                 element: LICENSE_TAG.to_string(),
+                position: None,
             })?;
         Ok(Self {
             license_identifier,
@@ -430,7 +436,7 @@ impl From<LicenseIdentifier> for models::license::LicenseIdentifier {
     fn from(other: LicenseIdentifier) -> Self {
         match other {
             LicenseIdentifier::SpdxId(spdx) => Self::SpdxId(SpdxIdentifier(spdx)),
-            LicenseIdentifier::Name(name) => Self::Name(NormalizedString::new_unchecked(name)),
+            LicenseIdentifier::Name(name) => Self::Name(NormalizedString::new(name)),
         }
     }
 }
@@ -495,6 +501,7 @@ impl FromXml for LicenseIdentifier {
             other => Err(XmlReadError::UnexpectedElementReadError {
                 error: format!("Got {} instead of \"name\" or \"id\"", other),
                 element: "license identifier".to_string(),
+                position: None,
             }),
         }
     }
@@ -535,6 +542,7 @@ pub(crate) mod test {
             )),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            acknowledgement: None,
         })
     }
 
@@ -554,6 +562,7 @@ pub(crate) mod test {
             ),
             text: Some(corresponding_attached_text()),
             url: Some(Uri("url".to_string())),
+            acknowledgement: None,
         })
     }
 
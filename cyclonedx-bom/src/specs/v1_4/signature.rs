@@ -198,10 +198,12 @@ impl FromXml for Signature {
         let algorithm = algorithm.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: ALGORITHM_TAG.to_string(),
             element: SIGNATURE_TAG.to_string(),
+            position: None,
         })?;
         let value = value.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: VALUE_TAG.to_string(),
             element: SIGNATURE_TAG.to_string(),
+            position: None,
         })?;
 
         let algorithm =
@@ -210,6 +212,7 @@ impl FromXml for Signature {
                 .map_err(|_| XmlReadError::InvalidEnumVariant {
                     value: algorithm.to_string(),
                     element: ALGORITHM_TAG.to_string(),
+                    position: None,
                 })?;
 
         Ok(Self { algorithm, value })
@@ -108,9 +108,9 @@ impl From<models::tool::Tool> for Tool {
 impl From<Tool> for models::tool::Tool {
     fn from(other: Tool) -> Self {
         Self {
-            vendor: other.vendor.map(NormalizedString::new_unchecked),
-            name: other.name.map(NormalizedString::new_unchecked),
-            version: other.version.map(NormalizedString::new_unchecked),
+            vendor: other.vendor.map(NormalizedString::new),
+            name: other.name.map(NormalizedString::new),
+            version: other.version.map(NormalizedString::new),
             hashes: convert_optional(other.hashes),
         }
     }
@@ -159,7 +159,7 @@ impl From<Vulnerability> for models::vulnerability::Vulnerability {
     fn from(other: Vulnerability) -> Self {
         Self {
             bom_ref: other.bom_ref,
-            id: other.id.map(NormalizedString::new_unchecked),
+            id: other.id.map(NormalizedString::new),
             vulnerability_source: convert_optional(other.vulnerability_source),
             vulnerability_references: convert_optional(other.vulnerability_references),
             vulnerability_ratings: convert_optional(other.vulnerability_ratings),
@@ -167,6 +167,8 @@ impl From<Vulnerability> for models::vulnerability::Vulnerability {
             description: other.description,
             detail: other.detail,
             recommendation: other.recommendation,
+            workaround: None,
+            proof_of_concept: None,
             advisories: convert_optional(other.advisories),
             created: other.created.map(DateTime),
             published: other.published.map(DateTime),
@@ -565,6 +567,8 @@ pub(crate) mod test {
             description: Some("description".to_string()),
             detail: Some("detail".to_string()),
             recommendation: Some("recommendation".to_string()),
+            workaround: None,
+            proof_of_concept: None,
             advisories: Some(corresponding_advisories()),
             created: Some(DateTime("created".to_string())),
             published: Some(DateTime("published".to_string())),
@@ -695,4 +699,44 @@ pub(crate) mod test {
         let expected = example_vulnerabilities();
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn it_should_read_cwes_as_integers() {
+        let model: models::vulnerability::Vulnerability = example_vulnerability().into();
+
+        assert_eq!(model.cwes, Some(vec![1, 2, 3]));
+        assert_eq!(
+            model.cwe_urls(),
+            vec![
+                "https://cwe.mitre.org/data/definitions/1.html".to_string(),
+                "https://cwe.mitre.org/data/definitions/2.html".to_string(),
+                "https://cwe.mitre.org/data/definitions/3.html".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_to_read_non_numeric_cwes() {
+        let input = r#"
+<?xml version="1.0" encoding="utf-8"?>
+<bom xmlns="http://cyclonedx.org/schema/bom/1.4" version="1">
+  <vulnerabilities>
+    <vulnerability>
+      <cwes>
+        <cwe>CWE-79</cwe>
+      </cwes>
+    </vulnerability>
+  </vulnerabilities>
+</bom>
+"#
+        .trim_start();
+
+        let actual = models::bom::Bom::parse_from_xml_v1_4(input.as_bytes())
+            .expect_err("Should have failed to parse a non-numeric CWE");
+
+        assert!(matches!(
+            actual,
+            crate::errors::XmlReadError::InvalidParseError { value, .. } if value == "CWE-79"
+        ));
+    }
 }
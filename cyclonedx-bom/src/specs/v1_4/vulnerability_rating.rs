@@ -123,7 +123,7 @@ impl From<VulnerabilityRating> for models::vulnerability_rating::VulnerabilityRa
             score: convert_optional(other.score),
             severity: convert_optional(other.severity),
             score_method: convert_optional(other.score_method),
-            vector: other.vector.map(NormalizedString::new_unchecked),
+            vector: other.vector.map(NormalizedString::new),
             justification: other.justification,
         }
     }
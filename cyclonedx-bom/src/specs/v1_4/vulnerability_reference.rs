@@ -105,7 +105,7 @@ impl From<models::vulnerability_reference::VulnerabilityReference> for Vulnerabi
 impl From<VulnerabilityReference> for models::vulnerability_reference::VulnerabilityReference {
     fn from(other: VulnerabilityReference) -> Self {
         Self {
-            id: NormalizedString::new_unchecked(other.id),
+            id: NormalizedString::new(other.id),
             vulnerability_source: models::vulnerability_source::VulnerabilitySource::from(
                 other.vulnerability_source,
             ),
@@ -187,12 +187,14 @@ impl FromXml for VulnerabilityReference {
         let id = reference_id.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: ID_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         let vulnerability_source =
             reference_source.ok_or_else(|| XmlReadError::RequiredDataMissing {
                 required_field: VULNERABILITY_SOURCE_TAG.to_string(),
                 element: element_name.local_name.to_string(),
+                position: None,
             })?;
 
         Ok(Self {
@@ -49,7 +49,7 @@ impl From<models::vulnerability_source::VulnerabilitySource> for VulnerabilitySo
 impl From<VulnerabilitySource> for models::vulnerability_source::VulnerabilitySource {
     fn from(other: VulnerabilitySource) -> Self {
         Self {
-            name: other.name.map(NormalizedString::new_unchecked),
+            name: other.name.map(NormalizedString::new),
             url: other.url.map(Uri),
         }
     }
@@ -191,6 +191,7 @@ impl FromXml for VulnerabilityTarget {
         let bom_ref = bom_ref.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: REF_TAG.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         Ok(Self { bom_ref, versions })
@@ -250,13 +251,44 @@ impl FromXml for Versions {
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", try_from = "VersionFields")]
 struct Version {
     #[serde(flatten)]
     version_range: VersionRange,
     status: Status,
 }
 
+/// The fields of a `Version` as they appear in JSON, where exactly one of `version`
+/// and `range` has to be present
+#[derive(Deserialize)]
+struct VersionFields {
+    version: Option<String>,
+    range: Option<String>,
+    status: Status,
+}
+
+impl TryFrom<VersionFields> for Version {
+    type Error = String;
+
+    fn try_from(other: VersionFields) -> Result<Self, Self::Error> {
+        let version_range = match (other.version, other.range) {
+            (Some(version), None) => VersionRange::Version(version),
+            (None, Some(range)) => VersionRange::Range(range),
+            (Some(_), Some(_)) => {
+                return Err("a version has either a version or a range, not both".to_string())
+            }
+            (None, None) => {
+                return Err("a version requires either a version or a range".to_string())
+            }
+        };
+
+        Ok(Self {
+            version_range,
+            status: other.status,
+        })
+    }
+}
+
 impl From<models::vulnerability_target::Version> for Version {
     fn from(other: models::vulnerability_target::Version) -> Self {
         Self {
@@ -318,6 +350,17 @@ impl FromXml for Version {
                 reader::XmlEvent::StartElement {
                     name, attributes, ..
                 } if name.local_name == VERSION_TAG || name.local_name == RANGE_TAG => {
+                    // a version has either an exact version or a range, but not both
+                    if version_range.is_some() {
+                        return Err(XmlReadError::UnexpectedElementReadError {
+                            error: format!(
+                                "Got {} in addition to a {} or {}, only one is allowed",
+                                name.local_name, VERSION_TAG, RANGE_TAG
+                            ),
+                            element: VERSION_TAG.to_string(),
+                            position: None,
+                        });
+                    }
                     version_range = Some(VersionRange::read_xml_element(
                         event_reader,
                         &name,
@@ -345,6 +388,7 @@ impl FromXml for Version {
         let version_range = version_range.ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: format!("{} || {}", VERSION_TAG, RANGE_TAG),
             element: element_name.local_name.to_string(),
+            position: None,
         })?;
 
         let status = status.unwrap_or(Status("affected".to_string()));
@@ -380,8 +424,8 @@ impl From<models::vulnerability_target::VersionRange> for VersionRange {
 impl From<VersionRange> for models::vulnerability_target::VersionRange {
     fn from(other: VersionRange) -> Self {
         match other {
-            VersionRange::Version(v) => Self::Version(NormalizedString::new_unchecked(v)),
-            VersionRange::Range(r) => Self::Range(NormalizedString::new_unchecked(r)),
+            VersionRange::Version(v) => Self::Version(NormalizedString::new(v)),
+            VersionRange::Range(r) => Self::Range(NormalizedString::new(r)),
             VersionRange::UndefinedVersionRange(u) => Self::UndefinedVersionRange(u),
         }
     }
@@ -421,6 +465,7 @@ impl FromXml for VersionRange {
             unexpected => Err(XmlReadError::UnexpectedElementReadError {
                 error: format!("Got unexpected element {:?}", unexpected),
                 element: "VersionRange".to_string(),
+                position: None,
             }),
         }
     }
@@ -677,4 +722,47 @@ pub(crate) mod test {
         let expected = example_vulnerability_targets();
         assert_eq!(actual, expected);
     }
+
+    #[track_caller]
+    fn read_version(input: &str) -> Result<Version, XmlReadError> {
+        let config = xml::ParserConfig::default().trim_whitespace(true);
+        let mut event_reader = xml::EventReader::new_with_config(input.as_bytes(), config);
+        event_reader.next().expect("Expected to start the document");
+        match event_reader
+            .next()
+            .expect("Failed to read from the XML input")
+        {
+            reader::XmlEvent::StartElement {
+                name, attributes, ..
+            } => Version::read_xml_element(&mut event_reader, &name, &attributes),
+            other => panic!("Expected to start an element, but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_should_reject_a_version_with_both_version_and_range() {
+        let xml = r#"
+<version>
+  <version>5.0.0</version>
+  <range>vers:npm/1.2.3|>=2.0.0|&lt;5.0.0</range>
+</version>
+"#;
+        assert!(read_version(xml).is_err());
+
+        let json = r#"{"version": "5.0.0", "range": "vers:npm/1.2.3|>=2.0.0|<5.0.0", "status": "affected"}"#;
+        assert!(serde_json::from_str::<Version>(json).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_a_version_with_neither_version_nor_range() {
+        let xml = r#"
+<version>
+  <status>affected</status>
+</version>
+"#;
+        assert!(read_version(xml).is_err());
+
+        let json = r#"{"status": "affected"}"#;
+        assert!(serde_json::from_str::<Version>(json).is_err());
+    }
 }
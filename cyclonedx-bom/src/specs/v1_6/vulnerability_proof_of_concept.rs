@@ -0,0 +1,207 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+use crate::{
+    errors::XmlReadError,
+    models,
+    specs::v1_6::attached_text::AttachedText,
+    utilities::convert_optional_vec,
+    xml::{
+        read_lax_validation_tag, read_list_tag, read_simple_tag, to_xml_read_error,
+        to_xml_write_error, unexpected_element_error, write_simple_tag, FromXml, ToInnerXml, ToXml,
+    },
+};
+use serde::{Deserialize, Serialize};
+use xml::{reader, writer::XmlEvent};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProofOfConcept {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reproduction_steps: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supporting_material: Option<Vec<AttachedText>>,
+}
+
+impl From<models::vulnerability_proof_of_concept::ProofOfConcept> for ProofOfConcept {
+    fn from(other: models::vulnerability_proof_of_concept::ProofOfConcept) -> Self {
+        Self {
+            reproduction_steps: other.reproduction_steps,
+            environment: other.environment,
+            supporting_material: convert_optional_vec(other.supporting_material),
+        }
+    }
+}
+
+impl From<ProofOfConcept> for models::vulnerability_proof_of_concept::ProofOfConcept {
+    fn from(other: ProofOfConcept) -> Self {
+        Self {
+            reproduction_steps: other.reproduction_steps,
+            environment: other.environment,
+            supporting_material: convert_optional_vec(other.supporting_material),
+        }
+    }
+}
+
+const PROOF_OF_CONCEPT_TAG: &str = "proofOfConcept";
+const REPRODUCTION_STEPS_TAG: &str = "reproductionSteps";
+const ENVIRONMENT_TAG: &str = "environment";
+const SUPPORTING_MATERIAL_TAG: &str = "supportingMaterial";
+const ATTACHMENT_TAG: &str = "attachment";
+
+impl ToXml for ProofOfConcept {
+    fn write_xml_element<W: std::io::Write>(
+        &self,
+        writer: &mut xml::EventWriter<W>,
+    ) -> Result<(), crate::errors::XmlWriteError> {
+        writer
+            .write(XmlEvent::start_element(PROOF_OF_CONCEPT_TAG))
+            .map_err(to_xml_write_error(PROOF_OF_CONCEPT_TAG))?;
+
+        if let Some(reproduction_steps) = &self.reproduction_steps {
+            write_simple_tag(writer, REPRODUCTION_STEPS_TAG, reproduction_steps)?;
+        }
+
+        if let Some(environment) = &self.environment {
+            write_simple_tag(writer, ENVIRONMENT_TAG, environment)?;
+        }
+
+        if let Some(supporting_material) = &self.supporting_material {
+            writer
+                .write(XmlEvent::start_element(SUPPORTING_MATERIAL_TAG))
+                .map_err(to_xml_write_error(SUPPORTING_MATERIAL_TAG))?;
+            for attachment in supporting_material {
+                attachment.write_xml_named_element(writer, ATTACHMENT_TAG)?;
+            }
+            writer
+                .write(XmlEvent::end_element())
+                .map_err(to_xml_write_error(SUPPORTING_MATERIAL_TAG))?;
+        }
+
+        writer
+            .write(XmlEvent::end_element())
+            .map_err(to_xml_write_error(PROOF_OF_CONCEPT_TAG))?;
+
+        Ok(())
+    }
+}
+
+impl FromXml for ProofOfConcept {
+    fn read_xml_element<R: std::io::Read>(
+        event_reader: &mut xml::EventReader<R>,
+        element_name: &xml::name::OwnedName,
+        _attributes: &[xml::attribute::OwnedAttribute],
+    ) -> Result<Self, XmlReadError>
+    where
+        Self: Sized,
+    {
+        let mut reproduction_steps: Option<String> = None;
+        let mut environment: Option<String> = None;
+        let mut supporting_material: Option<Vec<AttachedText>> = None;
+
+        let mut got_end_tag = false;
+        while !got_end_tag {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(PROOF_OF_CONCEPT_TAG))?;
+            match next_element {
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == REPRODUCTION_STEPS_TAG =>
+                {
+                    reproduction_steps = Some(read_simple_tag(event_reader, &name)?);
+                }
+
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == ENVIRONMENT_TAG =>
+                {
+                    environment = Some(read_simple_tag(event_reader, &name)?);
+                }
+
+                reader::XmlEvent::StartElement { name, .. }
+                    if name.local_name == SUPPORTING_MATERIAL_TAG =>
+                {
+                    supporting_material = Some(read_list_tag(event_reader, &name, ATTACHMENT_TAG)?);
+                }
+
+                // lax validation of any elements from a different schema
+                reader::XmlEvent::StartElement { name, .. } => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    got_end_tag = true;
+                }
+                unexpected => return Err(unexpected_element_error(element_name, unexpected)),
+            }
+        }
+
+        Ok(Self {
+            reproduction_steps,
+            environment,
+            supporting_material,
+        })
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use crate::{
+        specs::v1_6::attached_text::test::{corresponding_attached_text, example_attached_text},
+        xml::test::{read_element_from_string, write_element_to_string},
+    };
+
+    pub(crate) fn example_proof_of_concept() -> ProofOfConcept {
+        ProofOfConcept {
+            reproduction_steps: Some("reproduction steps".to_string()),
+            environment: Some("environment".to_string()),
+            supporting_material: Some(vec![example_attached_text()]),
+        }
+    }
+
+    pub(crate) fn corresponding_proof_of_concept(
+    ) -> models::vulnerability_proof_of_concept::ProofOfConcept {
+        models::vulnerability_proof_of_concept::ProofOfConcept {
+            reproduction_steps: Some("reproduction steps".to_string()),
+            environment: Some("environment".to_string()),
+            supporting_material: Some(vec![corresponding_attached_text()]),
+        }
+    }
+
+    #[test]
+    fn it_should_write_xml_full() {
+        let xml_output = write_element_to_string(example_proof_of_concept());
+        insta::assert_snapshot!(xml_output);
+    }
+
+    #[test]
+    fn it_should_read_xml_full() {
+        let input = r#"
+<proofOfConcept>
+  <reproductionSteps>reproduction steps</reproductionSteps>
+  <environment>environment</environment>
+  <supportingMaterial>
+    <attachment content-type="content type" encoding="encoding">content</attachment>
+  </supportingMaterial>
+</proofOfConcept>
+"#;
+        let actual: ProofOfConcept = read_element_from_string(input);
+        let expected = example_proof_of_concept();
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,358 @@
+/*
+ * This file is part of CycloneDX Rust Cargo.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Lossy, one-way export of a [`Bom`] to the [Syft](https://github.com/anchore/syft) JSON format,
+//! as consumed by tools such as Grype.
+//!
+//! The fields are mapped as follows:
+//!
+//! | CycloneDX                          | Syft                                         |
+//! |------------------------------------|----------------------------------------------|
+//! | `metadata.component`               | `source` (of type `directory`)               |
+//! | `components` (including nested)    | `artifacts`                                  |
+//! | `bom-ref`                          | `id` (`name@version` if there is no bom-ref) |
+//! | `name`, `version`                  | `name`, `version`                            |
+//! | `purl`                             | `purl`, and `type`/`language` by purl type   |
+//! | `cpe`                              | `cpes`                                       |
+//! | `licenses`                         | `licenses` (`declared`)                      |
+//! | `dependencies`                     | `artifactRelationships` (`dependency-of`)    |
+//!
+//! Everything else is dropped, notably vulnerabilities, services, hashes, scopes, properties,
+//! external references, and the dependencies of the metadata component, because Syft only
+//! relates the source to its artifacts by their location.
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use crate::errors::JsonWriteError;
+use crate::models::bom::Bom;
+use crate::models::component::Component;
+use crate::models::license::{LicenseChoice, LicenseIdentifier};
+
+/// The version of the Syft JSON schema the export conforms to
+pub const SYFT_SCHEMA_VERSION: &str = "16.0.0";
+
+/// Output the BOM as a Syft JSON document
+pub fn output_as_syft_json<W: std::io::Write>(
+    bom: &Bom,
+    writer: &mut W,
+) -> Result<(), JsonWriteError> {
+    serde_json::to_writer_pretty(writer, &Document::from(bom))?;
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Document {
+    artifacts: Vec<Artifact>,
+    artifact_relationships: Vec<Relationship>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<Source>,
+    descriptor: Descriptor,
+    schema: Schema,
+}
+
+impl From<&Bom> for Document {
+    fn from(bom: &Bom) -> Self {
+        let mut artifacts = Vec::new();
+        if let Some(components) = &bom.components {
+            collect_artifacts(&components.0, &mut artifacts);
+        }
+
+        let ids: BTreeSet<&str> = artifacts.iter().map(|a| a.id.as_str()).collect();
+        let artifact_relationships = bom
+            .dependencies
+            .iter()
+            .flat_map(|d| d.0.iter())
+            .filter(|d| ids.contains(d.dependency_ref.as_str()))
+            .flat_map(|d| {
+                d.dependencies
+                    .iter()
+                    .filter(|dependency| ids.contains(dependency.as_str()))
+                    .map(|dependency| Relationship {
+                        parent: dependency.clone(),
+                        child: d.dependency_ref.clone(),
+                        relationship_type: "dependency-of",
+                    })
+            })
+            .collect();
+
+        let source = bom
+            .metadata
+            .as_ref()
+            .and_then(|m| m.component.as_ref())
+            .map(|component| Source {
+                id: artifact_id(component),
+                name: component.name.to_string(),
+                version: version(component),
+                source_type: "directory",
+            });
+
+        Self {
+            artifacts,
+            artifact_relationships,
+            source,
+            descriptor: Descriptor {
+                name: env!("CARGO_PKG_NAME"),
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            schema: Schema {
+                version: SYFT_SCHEMA_VERSION,
+                url: format!(
+                    "https://raw.githubusercontent.com/anchore/syft/main/schema/json/schema-{}.json",
+                    SYFT_SCHEMA_VERSION
+                ),
+            },
+        }
+    }
+}
+
+fn collect_artifacts(components: &[Component], artifacts: &mut Vec<Artifact>) {
+    for component in components {
+        artifacts.push(Artifact::from(component));
+        if let Some(nested) = &component.components {
+            collect_artifacts(&nested.0, artifacts);
+        }
+    }
+}
+
+fn artifact_id(component: &Component) -> String {
+    component
+        .bom_ref
+        .clone()
+        .unwrap_or_else(|| format!("{}@{}", component.name, version(component)))
+}
+
+fn version(component: &Component) -> String {
+    component
+        .version
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Artifact {
+    id: String,
+    name: String,
+    version: String,
+    #[serde(rename = "type")]
+    package_type: &'static str,
+    found_by: &'static str,
+    locations: Vec<()>,
+    licenses: Vec<License>,
+    language: &'static str,
+    cpes: Vec<String>,
+    purl: String,
+}
+
+impl From<&Component> for Artifact {
+    fn from(component: &Component) -> Self {
+        let purl = component
+            .purl
+            .as_ref()
+            .map(|p| p.to_string())
+            .unwrap_or_default();
+        let (package_type, language) = package_type_and_language(&purl);
+        let licenses = component
+            .licenses
+            .iter()
+            .flat_map(|l| l.0.iter())
+            .map(License::from)
+            .collect();
+
+        Self {
+            id: artifact_id(component),
+            name: component.name.to_string(),
+            version: version(component),
+            package_type,
+            found_by: "cyclonedx-bom",
+            locations: Vec::new(),
+            licenses,
+            language,
+            cpes: component.cpe.iter().map(|c| c.0.clone()).collect(),
+            purl,
+        }
+    }
+}
+
+/// Maps the type of a purl to the Syft package type and language
+fn package_type_and_language(purl: &str) -> (&'static str, &'static str) {
+    let purl_type = purl
+        .strip_prefix("pkg:")
+        .and_then(|p| p.split('/').next())
+        .unwrap_or_default();
+    match purl_type {
+        "cargo" => ("rust-crate", "rust"),
+        "npm" => ("npm", "javascript"),
+        "pypi" => ("python", "python"),
+        "gem" => ("gem", "ruby"),
+        "golang" => ("go-module", "go"),
+        "maven" => ("java-archive", "java"),
+        _ => ("UnknownPackage", ""),
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct License {
+    value: String,
+    spdx_expression: String,
+    #[serde(rename = "type")]
+    license_type: &'static str,
+    urls: Vec<String>,
+    locations: Vec<()>,
+}
+
+impl From<&LicenseChoice> for License {
+    fn from(license: &LicenseChoice) -> Self {
+        let (value, spdx_expression, urls) = match license {
+            LicenseChoice::License(license) => {
+                let urls = license.url.iter().map(|u| u.to_string()).collect();
+                match &license.license_identifier {
+                    LicenseIdentifier::SpdxId(id) => (id.to_string(), id.to_string(), urls),
+                    LicenseIdentifier::Name(name) => (name.to_string(), String::new(), urls),
+                }
+            }
+            LicenseChoice::Expression(expression) => {
+                (expression.to_string(), expression.to_string(), Vec::new())
+            }
+        };
+
+        Self {
+            value,
+            spdx_expression,
+            license_type: "declared",
+            urls,
+            locations: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Relationship {
+    parent: String,
+    child: String,
+    #[serde(rename = "type")]
+    relationship_type: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Source {
+    id: String,
+    name: String,
+    version: String,
+    #[serde(rename = "type")]
+    source_type: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Descriptor {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct Schema {
+    version: &'static str,
+    url: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        external_models::uri::Purl,
+        models::{
+            component::{Classification, Components},
+            dependency::{Dependencies, Dependency},
+            license::{License as CdxLicense, Licenses},
+            metadata::Metadata,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_export_components_as_syft_packages() {
+        let mut library = Component::new(
+            Classification::Library,
+            "lib-x",
+            "0.1.0",
+            Some("lib-x".to_string()),
+        );
+        library.purl = Some(Purl::new("cargo", "lib-x", "0.1.0").expect("Invalid purl"));
+        library.licenses = Some(Licenses(vec![LicenseChoice::License(
+            CdxLicense::license_id("MIT").expect("Invalid license"),
+        )]));
+        let mut nested = Component::new(
+            Classification::Library,
+            "lib-y",
+            "0.2.0",
+            Some("lib-y".to_string()),
+        );
+        nested.purl = Some(Purl::new("cargo", "lib-y", "0.2.0").expect("Invalid purl"));
+        library.components = Some(Components(vec![nested]));
+
+        let mut metadata = Metadata::new().expect("Failed to create metadata");
+        metadata.component = Some(Component::new(
+            Classification::Application,
+            "app",
+            "1.0.0",
+            Some("app".to_string()),
+        ));
+        let bom = Bom {
+            metadata: Some(metadata),
+            components: Some(Components(vec![library])),
+            dependencies: Some(Dependencies(vec![
+                Dependency {
+                    dependency_ref: "app".to_string(),
+                    dependencies: vec!["lib-x".to_string()],
+                },
+                Dependency {
+                    dependency_ref: "lib-x".to_string(),
+                    dependencies: vec!["lib-y".to_string()],
+                },
+            ])),
+            ..Bom::default()
+        };
+
+        let mut output = Vec::new();
+        output_as_syft_json(&bom, &mut output).expect("Failed to write Syft JSON");
+        let json: serde_json::Value = serde_json::from_slice(&output).expect("Invalid JSON");
+
+        let artifacts = json["artifacts"].as_array().expect("Missing artifacts");
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(artifacts[0]["id"], "lib-x");
+        assert_eq!(artifacts[0]["name"], "lib-x");
+        assert_eq!(artifacts[0]["version"], "0.1.0");
+        assert_eq!(artifacts[0]["type"], "rust-crate");
+        assert_eq!(artifacts[0]["language"], "rust");
+        assert_eq!(artifacts[0]["purl"], "pkg:cargo/lib-x@0.1.0");
+        assert_eq!(artifacts[0]["licenses"][0]["spdxExpression"], "MIT");
+        assert_eq!(artifacts[1]["name"], "lib-y");
+
+        assert_eq!(
+            json["artifactRelationships"],
+            serde_json::json!([{"parent": "lib-y", "child": "lib-x", "type": "dependency-of"}])
+        );
+        assert_eq!(json["source"]["name"], "app");
+        assert_eq!(json["schema"]["version"], SYFT_SCHEMA_VERSION);
+    }
+}
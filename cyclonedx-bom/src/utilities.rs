@@ -1,4 +1,6 @@
 use crate::errors::BomError;
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer};
 use std::convert::TryFrom;
 
 /// Convert an optional list of a type
@@ -45,6 +47,30 @@ pub(crate) fn try_convert_vec<A, B: TryFrom<A, Error = BomError>>(
         .collect()
 }
 
+/// Deserialize an optional `u32` given either as a JSON number or as a string containing one
+///
+/// Used for the BOM `version`, which some producers write as a string
+pub(crate) fn deserialize_lenient_u32<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u32>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u32),
+        String(String),
+    }
+
+    match Option::<NumberOrString>::deserialize(deserializer)? {
+        Some(NumberOrString::Number(number)) => Ok(Some(number)),
+        Some(NumberOrString::String(string)) => {
+            string.trim().parse().map(Some).map_err(|_| {
+                D::Error::invalid_value(Unexpected::Str(&string), &"an unsigned integer")
+            })
+        }
+        None => Ok(None),
+    }
+}
+
 /*
 For cases where you return Result, it's useful to know that .collect() can take iterator of Result<T,E> and collect into Result<Vec<T>, E>, so you can then use ? on the collection:
 
@@ -51,6 +51,30 @@ impl ValidationContext {
     }
 }
 
+/// Renders the path to the invalid value, e.g. `Bom.components[0] > Component.name`
+impl std::fmt::Display for ValidationContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, component) in self.0.iter().enumerate() {
+            match component {
+                ValidationPathComponent::Struct {
+                    struct_name,
+                    field_name,
+                } => {
+                    if index > 0 {
+                        write!(f, " > ")?;
+                    }
+                    write!(f, "{}.{}", struct_name, field_name)?;
+                }
+                ValidationPathComponent::Array { index } => write!(f, "[{}]", index)?,
+                ValidationPathComponent::EnumVariant { variant_name } => {
+                    write!(f, "::{}", variant_name)?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ValidationPathComponent {
     Struct {
@@ -92,6 +116,14 @@ impl ValidationResult {
             }
         }
     }
+
+    /// Converts the result into a `Result` listing all reasons for the failure
+    pub fn into_result(self) -> Result<(), Vec<FailureReason>> {
+        match self {
+            Self::Passed => Ok(()),
+            Self::Failed { reasons } => Err(reasons),
+        }
+    }
 }
 
 impl Default for ValidationResult {
@@ -105,8 +137,26 @@ pub struct FailureReason {
     pub message: String,
     pub context: ValidationContext,
 }
+
+/// Renders the path to the invalid value followed by the message
+impl std::fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.context, self.message)
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ValidationError {
     #[error("Failed to compile regular expression: {0}")]
     InvalidRegularExpressionError(#[from] regex::Error),
+
+    /// The Package URL of a component disagrees with its `name` or `version`, which is the
+    /// `field` that is compared
+    #[error(r#"{context}: Purl {field} "{purl_value}" does not match component {field} "{component_value}""#)]
+    PurlMismatch {
+        context: ValidationContext,
+        field: String,
+        purl_value: String,
+        component_value: String,
+    },
 }
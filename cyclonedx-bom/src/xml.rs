@@ -1,12 +1,16 @@
 use crate::errors::{XmlReadError, XmlWriteError};
+use crate::models::foreign_extension::ForeignExtension;
+use std::collections::HashMap;
 use std::io::{Read, Write};
 use xml::{
     attribute::OwnedAttribute,
+    common::{Position, TextPosition},
+    escape::escape_str_attribute,
     name::OwnedName,
     namespace::{Namespace, NS_NO_PREFIX},
     reader::{self},
     writer::{self, EventWriter},
-    EventReader,
+    EmitterConfig, EventReader, ParserConfig,
 };
 
 pub(crate) trait ToXmlDocument {
@@ -57,6 +61,27 @@ pub(crate) fn write_simple_tag<W: Write>(
     Ok(())
 }
 
+/// Writes the content as CDATA, split into adjacent sections around any `]]>`
+/// so that the content cannot end the section early
+pub(crate) fn write_cdata<W: Write>(
+    writer: &mut EventWriter<W>,
+    tag: &str,
+    content: &str,
+) -> Result<(), XmlWriteError> {
+    let mut rest = content;
+    while let Some(index) = rest.find("]]>") {
+        // End the section between `]]` and `>`
+        writer
+            .write(writer::XmlEvent::cdata(&rest[..index + 2]))
+            .map_err(to_xml_write_error(tag))?;
+        rest = &rest[index + 2..];
+    }
+    writer
+        .write(writer::XmlEvent::cdata(rest))
+        .map_err(to_xml_write_error(tag))?;
+    Ok(())
+}
+
 pub(crate) fn to_xml_write_error(
     element: impl AsRef<str>,
 ) -> impl FnOnce(xml::writer::Error) -> XmlWriteError {
@@ -70,6 +95,163 @@ pub(crate) trait FromXmlDocument {
         Self: Sized;
 }
 
+/// Reads a document, recording the position the reader stopped at in an error that has none
+pub(crate) fn read_document<R: Read, X: FromXmlDocument>(
+    event_reader: &mut EventReader<R>,
+) -> Result<X, XmlReadError> {
+    X::read_xml_document(event_reader).map_err(|error| error.or_position(event_reader.position()))
+}
+
+/// A document that can be read one top-level element at a time, so that the elements that
+/// cannot be read are skipped instead of failing the whole document, see [XmlRecovery]
+pub(crate) trait FromXmlSections {
+    /// The version of the specification, as in its namespace
+    const VERSION: &'static str;
+
+    /// Reads the top-level element whose start tag was just read into `bom`. Returns `false`
+    /// for an element that is not part of this version of the specification.
+    fn read_xml_section<R: Read>(
+        event_reader: &mut EventReader<R>,
+        name: &OwnedName,
+        attributes: &[OwnedAttribute],
+        bom: &mut crate::models::bom::Bom,
+        recovery: &mut XmlRecovery,
+    ) -> Result<bool, XmlReadError>;
+}
+
+/// Reads what it can of a document, keeping the errors of the elements that cannot be read
+/// and skipping to their end tags
+pub(crate) struct XmlRecovery {
+    /// Where each element of the document ends, by where it starts
+    element_ends: HashMap<(u64, u64), TextPosition>,
+    pub(crate) errors: Vec<XmlReadError>,
+}
+
+impl XmlRecovery {
+    /// Notes where each element of the document ends, failing if it is not well-formed. The
+    /// document has to be read again with the same configuration.
+    pub(crate) fn scan<R: Read>(event_reader: &mut EventReader<R>) -> Result<Self, XmlReadError> {
+        let mut starts = Vec::new();
+        let mut element_ends = HashMap::new();
+        loop {
+            match event_reader.next().map_err(to_xml_read_error("document"))? {
+                reader::XmlEvent::StartElement { .. } => starts.push(event_reader.position()),
+                reader::XmlEvent::EndElement { .. } => {
+                    let start = starts.pop().expect("Unbalanced elements");
+                    element_ends.insert(position_key(start), event_reader.position());
+                }
+                reader::XmlEvent::EndDocument => break,
+                _ => (),
+            }
+        }
+        Ok(Self {
+            element_ends,
+            errors: Vec::new(),
+        })
+    }
+
+    /// Reads the element whose start tag was just read, or keeps the error and skips the rest
+    /// of the element
+    pub(crate) fn read<R: Read, X>(
+        &mut self,
+        event_reader: &mut EventReader<R>,
+        read: impl FnOnce(&mut EventReader<R>) -> Result<X, XmlReadError>,
+    ) -> Result<Option<X>, XmlReadError> {
+        let start = event_reader.position();
+        match read(event_reader) {
+            Ok(element) => Ok(Some(element)),
+            Err(error) => {
+                self.errors.push(error.or_position(event_reader.position()));
+                self.skip_to_end(event_reader, start)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Same as [read_list_tag], but skips the items that cannot be read
+    pub(crate) fn read_list<R: Read, X: FromXml>(
+        &mut self,
+        event_reader: &mut EventReader<R>,
+        element_name: &OwnedName,
+        inner_element_tag: &str,
+    ) -> Result<Vec<X>, XmlReadError> {
+        self.read_items(event_reader, element_name, inner_element_tag, false)
+    }
+
+    /// Same as [read_lax_validation_list_tag], but skips the items that cannot be read
+    pub(crate) fn read_lax_validation_list<R: Read, X: FromXml>(
+        &mut self,
+        event_reader: &mut EventReader<R>,
+        element_name: &OwnedName,
+        inner_element_tag: &str,
+    ) -> Result<Vec<X>, XmlReadError> {
+        self.read_items(event_reader, element_name, inner_element_tag, true)
+    }
+
+    fn read_items<R: Read, X: FromXml>(
+        &mut self,
+        event_reader: &mut EventReader<R>,
+        element_name: &OwnedName,
+        inner_element_tag: &str,
+        lax: bool,
+    ) -> Result<Vec<X>, XmlReadError> {
+        let mut items = Vec::new();
+        loop {
+            let next_element = event_reader
+                .next()
+                .map_err(to_xml_read_error(&element_name.local_name))?;
+            match next_element {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } if name.local_name == inner_element_tag => {
+                    let item = self.read(event_reader, |event_reader| {
+                        X::read_xml_element(event_reader, &name, &attributes)
+                    })?;
+                    items.extend(item);
+                }
+                reader::XmlEvent::StartElement { name, .. } if lax => {
+                    read_lax_validation_tag(event_reader, &name)?
+                }
+                reader::XmlEvent::EndElement { name } if &name == element_name => {
+                    return Ok(items);
+                }
+                unexpected => {
+                    let is_element = matches!(unexpected, reader::XmlEvent::StartElement { .. });
+                    self.errors.push(
+                        unexpected_element_error(element_name, unexpected)
+                            .or_position(event_reader.position()),
+                    );
+                    if is_element {
+                        self.skip_to_end(event_reader, event_reader.position())?;
+                    }
+                }
+            }
+        }
+    }
+
+    fn skip_to_end<R: Read>(
+        &self,
+        event_reader: &mut EventReader<R>,
+        start: TextPosition,
+    ) -> Result<(), XmlReadError> {
+        let end = self.element_ends.get(&position_key(start)).ok_or_else(|| {
+            XmlReadError::UnexpectedElementReadError {
+                error: "Could not find the end of the element".to_string(),
+                element: "document".to_string(),
+                position: Some(start.into()),
+            }
+        })?;
+        while position_key(event_reader.position()) < position_key(*end) {
+            event_reader.next().map_err(to_xml_read_error("document"))?;
+        }
+        Ok(())
+    }
+}
+
+fn position_key(position: TextPosition) -> (u64, u64) {
+    (position.row, position.column)
+}
+
 pub(crate) trait FromXml {
     fn read_xml_element<R: Read>(
         event_reader: &mut EventReader<R>,
@@ -85,6 +267,7 @@ pub(crate) fn to_xml_read_error(
 ) -> impl FnOnce(xml::reader::Error) -> XmlReadError {
     let element_name = element_name.as_ref().to_owned();
     |error| XmlReadError::ElementReadError {
+        position: Some(error.position().into()),
         error,
         element: element_name,
     }
@@ -105,6 +288,7 @@ pub(crate) fn expected_namespace_or_error(
         Err(XmlReadError::InvalidNamespaceError {
             expected_namespace,
             actual_namespace,
+            position: None,
         })
     }
 }
@@ -119,17 +303,6 @@ pub(crate) fn inner_text_or_error(
     }
 }
 
-pub(crate) fn inner_text_or_none(
-    element_name: impl AsRef<str>,
-) -> impl FnOnce(xml::reader::XmlEvent) -> Result<Option<String>, XmlReadError> {
-    let element_name = element_name.as_ref().to_owned();
-    |event| match event {
-        reader::XmlEvent::Characters(s) | reader::XmlEvent::CData(s) => Ok(Some(s)),
-        reader::XmlEvent::EndElement { name } if name.to_string() == element_name => Ok(None),
-        unexpected => Err(unexpected_element_error(element_name, unexpected)),
-    }
-}
-
 pub(crate) fn closing_tag_or_error(
     element: &OwnedName,
 ) -> impl FnOnce(xml::reader::XmlEvent) -> Result<(), XmlReadError> {
@@ -153,6 +326,7 @@ pub(crate) fn attribute_or_error(
         .ok_or_else(|| XmlReadError::RequiredDataMissing {
             required_field: expected_attribute.to_string(),
             element: element_name.local_name.to_string(),
+            position: None,
         })
 }
 
@@ -194,6 +368,7 @@ impl FromXmlType for bool {
                 value: value.to_string(),
                 data_type: Self::xml_type_display(),
                 element: element.to_string(),
+                position: None,
             }),
         }
     }
@@ -213,6 +388,7 @@ impl FromXmlType for u32 {
             value: value.to_string(),
             data_type: Self::xml_type_display(),
             element: element.to_string(),
+            position: None,
         })?;
 
         Ok(value)
@@ -233,6 +409,7 @@ impl FromXmlType for f32 {
             value: value.to_string(),
             data_type: Self::xml_type_display(),
             element: element.to_string(),
+            position: None,
         })?;
 
         Ok(value)
@@ -243,39 +420,42 @@ pub(crate) fn read_simple_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
 ) -> Result<String, XmlReadError> {
-    let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(inner_text_or_error(&element_display))?;
-
-    event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(closing_tag_or_error(element))?;
-
-    Ok(content)
+    read_inner_text(event_reader, element)?.ok_or_else(|| {
+        unexpected_element_error(
+            element,
+            reader::XmlEvent::EndElement {
+                name: element.clone(),
+            },
+        )
+    })
 }
 
 pub(crate) fn read_optional_tag<R: Read>(
     event_reader: &mut EventReader<R>,
     element: &OwnedName,
 ) -> Result<Option<String>, XmlReadError> {
-    let element_display = element.to_string();
-    let content = event_reader
-        .next()
-        .map_err(to_xml_read_error(&element_display))
-        .and_then(inner_text_or_none(&element_display))?;
+    read_inner_text(event_reader, element)
+}
 
-    // If XML tag has content, read next element
-    if content.is_some() {
-        event_reader
+/// Reads the text of an element up to its closing tag, joining adjacent text and CDATA sections
+fn read_inner_text<R: Read>(
+    event_reader: &mut EventReader<R>,
+    element: &OwnedName,
+) -> Result<Option<String>, XmlReadError> {
+    let element_display = element.to_string();
+    let mut content: Option<String> = None;
+    loop {
+        match event_reader
             .next()
-            .map_err(to_xml_read_error(&element_display))
-            .and_then(closing_tag_or_error(element))?;
+            .map_err(to_xml_read_error(&element_display))?
+        {
+            reader::XmlEvent::Characters(text) | reader::XmlEvent::CData(text) => {
+                content.get_or_insert_with(String::new).push_str(&text)
+            }
+            reader::XmlEvent::EndElement { name } if &name == element => return Ok(content),
+            unexpected => return Err(unexpected_element_error(&element_display, unexpected)),
+        }
     }
-
-    Ok(content)
 }
 
 pub(crate) fn read_u32_tag<R: Read>(
@@ -295,6 +475,7 @@ pub(crate) fn read_u32_tag<R: Read>(
                 value: content,
                 data_type: "u32".to_string(),
                 element: element_display,
+                position: None,
             })
         }
     };
@@ -324,6 +505,7 @@ pub(crate) fn read_f32_tag<R: Read>(
                 value: content,
                 data_type: "f32".to_string(),
                 element: element_display,
+                position: None,
             })
         }
     };
@@ -441,6 +623,123 @@ pub(crate) fn read_lax_validation_tag<R: Read>(
     Ok(())
 }
 
+/// Read an element from a different schema like [read_lax_validation_tag], keeping its content
+pub(crate) fn read_foreign_extension<R: Read>(
+    event_reader: &mut EventReader<R>,
+    element: &OwnedName,
+    attributes: &[OwnedAttribute],
+    namespace: &Namespace,
+) -> Result<ForeignExtension, XmlReadError> {
+    let to_read_error = |error: xml::writer::Error| XmlReadError::UnexpectedElementReadError {
+        error: error.to_string(),
+        element: element.local_name.clone(),
+        position: None,
+    };
+
+    // The content is written inside a wrapper declaring the namespaces in scope,
+    // so that they are not declared again on each of its elements
+    let mut output = Vec::new();
+    let mut writer = EmitterConfig::new()
+        .write_document_declaration(false)
+        .create_writer(&mut output);
+    writer
+        .write(writer::XmlEvent::StartElement {
+            name: xml::name::Name::local("wrapper"),
+            attributes: Vec::new().into(),
+            namespace: std::borrow::Cow::Borrowed(namespace),
+        })
+        .map_err(to_read_error)?;
+    let mut depth = 0;
+    loop {
+        let next_element = event_reader
+            .next()
+            .map_err(to_xml_read_error(&element.local_name))?;
+
+        match &next_element {
+            reader::XmlEvent::StartElement { .. } => depth += 1,
+            reader::XmlEvent::EndElement { name } if depth == 0 && name == element => break,
+            reader::XmlEvent::EndElement { .. } if depth > 0 => depth -= 1,
+            reader::XmlEvent::EndDocument | reader::XmlEvent::EndElement { .. } => {
+                return Err(unexpected_element_error(element, next_element))
+            }
+            _ => (),
+        }
+        if let Some(event) = next_element.as_writer_event() {
+            writer.write(event).map_err(to_read_error)?;
+        }
+    }
+    writer
+        .write(writer::XmlEvent::end_element())
+        .map_err(to_read_error)?;
+
+    let output = String::from_utf8_lossy(&output);
+    let inner_xml = match output.find('>') {
+        Some(end) if !output[..end].ends_with('/') => output[end + 1..]
+            .strip_suffix("</wrapper>")
+            .unwrap_or_default()
+            .to_string(),
+        _ => String::new(),
+    };
+
+    Ok(ForeignExtension {
+        name: qualified_name(element),
+        namespace: element.namespace.clone(),
+        attributes: attributes
+            .iter()
+            .map(|attribute| (qualified_name(&attribute.name), attribute.value.clone()))
+            .collect(),
+        inner_xml,
+    })
+}
+
+fn qualified_name(name: &OwnedName) -> String {
+    match &name.prefix {
+        Some(prefix) => format!("{}:{}", prefix, name.local_name),
+        None => name.local_name.clone(),
+    }
+}
+
+/// Write an element read with [read_foreign_extension] back, declaring its namespace on it
+pub(crate) fn write_foreign_extension<W: Write>(
+    writer: &mut EventWriter<W>,
+    extension: &ForeignExtension,
+) -> Result<(), XmlWriteError> {
+    let mut fragment = format!("<{}", extension.name);
+    if let Some(namespace) = &extension.namespace {
+        match extension.name.split_once(':') {
+            Some((prefix, _)) => fragment.push_str(&format!(" xmlns:{}=", prefix)),
+            None => fragment.push_str(" xmlns="),
+        }
+        fragment.push_str(&format!("\"{}\"", escape_str_attribute(namespace)));
+    }
+    for (name, value) in &extension.attributes {
+        fragment.push_str(&format!(" {}=\"{}\"", name, escape_str_attribute(value)));
+    }
+    fragment.push_str(&format!(">{}</{}>", extension.inner_xml, extension.name));
+
+    let config = ParserConfig::default().trim_whitespace(true);
+    for event in EventReader::new_with_config(fragment.as_bytes(), config) {
+        let event = event.map_err(|error| XmlWriteError::XmlElementWriteError {
+            error: xml::writer::Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                error.to_string(),
+            )),
+            element: extension.name.clone(),
+        })?;
+        match event {
+            reader::XmlEvent::StartDocument { .. } | reader::XmlEvent::EndDocument => (),
+            event => {
+                if let Some(event) = event.as_writer_event() {
+                    writer
+                        .write(event)
+                        .map_err(to_xml_write_error(&extension.name))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn read_lax_validation_list_tag<R: Read, X: FromXml>(
     event_reader: &mut EventReader<R>,
     element_name: &OwnedName,
@@ -479,9 +778,114 @@ pub(crate) fn unexpected_element_error(
     XmlReadError::UnexpectedElementReadError {
         error: format!("Got unexpected element {:?}", unexpected),
         element: element.to_string(),
+        position: None,
+    }
+}
+
+/// The elements and attributes of an XML document held in memory, so that it can be compared
+/// with the document that was written back from what was read
+#[derive(Clone, Debug)]
+pub(crate) struct XmlElement {
+    pub(crate) name: OwnedName,
+    pub(crate) attributes: Vec<OwnedAttribute>,
+    pub(crate) children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    /// Read the root element of a document, failing only if the document is not well-formed
+    pub(crate) fn read_document<R: Read>(
+        event_reader: &mut EventReader<R>,
+    ) -> Result<Self, XmlReadError> {
+        let mut stack: Vec<XmlElement> = Vec::new();
+        loop {
+            let event = event_reader.next().map_err(to_xml_read_error("document"))?;
+            match event {
+                reader::XmlEvent::StartElement {
+                    name, attributes, ..
+                } => stack.push(XmlElement {
+                    name,
+                    attributes,
+                    children: Vec::new(),
+                }),
+                reader::XmlEvent::EndElement { .. } => {
+                    let element = stack.pop().expect("Unbalanced elements");
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Ok(element),
+                    }
+                }
+                unexpected @ reader::XmlEvent::EndDocument => {
+                    return Err(unexpected_element_error("document", unexpected)
+                        .or_position(event_reader.position()))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// The paths of the elements and attributes that have no counterpart in `other`, e.g.
+    /// `bom/components/component[1]/@unknown`. Children with the same name are paired up in
+    /// order, so the order of differently named children does not matter.
+    pub(crate) fn unmatched(&self, other: &XmlElement) -> Vec<String> {
+        let mut unmatched = Vec::new();
+        self.collect_unmatched(other, &self.name.local_name, &mut unmatched);
+        unmatched
+    }
+
+    fn collect_unmatched(&self, other: &XmlElement, path: &str, unmatched: &mut Vec<String>) {
+        for attribute in &self.attributes {
+            if attribute.name.namespace.as_deref() == Some(XSI_NAMESPACE) {
+                continue;
+            }
+            if !other
+                .attributes
+                .iter()
+                .any(|a| same_name(&a.name, &attribute.name))
+            {
+                unmatched.push(format!("{path}/@{}", attribute.name.local_name));
+            }
+        }
+
+        let mut paired: Vec<(&OwnedName, usize)> = Vec::new();
+        for child in &self.children {
+            let index = match paired
+                .iter_mut()
+                .find(|(name, _)| same_name(name, &child.name))
+            {
+                Some((_, count)) => {
+                    *count += 1;
+                    *count - 1
+                }
+                None => {
+                    paired.push((&child.name, 1));
+                    0
+                }
+            };
+            let counterpart = other
+                .children
+                .iter()
+                .filter(|c| same_name(&c.name, &child.name))
+                .nth(index);
+            let child_path = if index == 0 {
+                format!("{path}/{}", child.name.local_name)
+            } else {
+                format!("{path}/{}[{index}]", child.name.local_name)
+            };
+            match counterpart {
+                Some(counterpart) => child.collect_unmatched(counterpart, &child_path, unmatched),
+                None => unmatched.push(child_path),
+            }
+        }
     }
 }
 
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Names are the same if they are in the same namespace, whatever prefix they use
+fn same_name(a: &OwnedName, b: &OwnedName) -> bool {
+    a.local_name == b.local_name && a.namespace == b.namespace
+}
+
 #[cfg(test)]
 pub(crate) mod test {
     use xml::{EmitterConfig, ParserConfig};
@@ -50,4 +50,27 @@ mod examples {
             });
         });
     }
+
+    #[test]
+    fn it_should_stream_the_same_components_as_a_full_parse() {
+        for path in [
+            "tests/examples/1.4/valid_sbom-1.4_trivy-0.36.1_alpine-3.13.1.cdx.json",
+            "tests/examples/1.4/valid_sbom-1.4_trivy-0.42.1_alpine-3.13.1.cdx.json",
+        ] {
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+            let bom = Bom::parse_from_json_v1_4(file)
+                .unwrap_or_else(|_| panic!("Failed to parse the document as an BOM: {path:?}"));
+
+            let file = std::fs::File::open(path)
+                .unwrap_or_else(|_| panic!("Failed to read file: {path:?}"));
+            let mut streamed = Vec::new();
+            Bom::stream_components_from_json(file, |component| streamed.push(component))
+                .unwrap_or_else(|_| panic!("Failed to stream the components: {path:?}"));
+
+            let components = bom.components.expect("Expected components").0;
+            assert!(!components.is_empty());
+            assert_eq!(streamed, components);
+        }
+    }
 }